@@ -2,6 +2,11 @@ use crate::Color;
 
 /// The background of some element.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde", untagged)
+)]
 pub enum Background {
     /// A solid color
     Color(Color),
@@ -100,6 +100,57 @@ impl Color {
         ]
     }
 
+    /// Parses a [`Color`] in hexadecimal notation, like `#rgb`, `#rgba`,
+    /// `#rrggbb` or `#rrggbbaa`. The leading `#` is optional.
+    pub fn parse(hex: &str) -> Option<Color> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        if !hex.is_ascii() {
+            return None;
+        }
+
+        let channel = |i: usize, digits: usize| {
+            let value =
+                u8::from_str_radix(&hex[i * digits..(i + 1) * digits], 16)
+                    .ok()?;
+
+            Some(if digits == 1 { value * 17 } else { value })
+        };
+
+        let digits = match hex.len() {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            _ => return None,
+        };
+
+        let alpha = if hex.len() / digits == 4 {
+            channel(3, digits)?
+        } else {
+            u8::MAX
+        };
+
+        Some(Color::from_rgba8(
+            channel(0, digits)?,
+            channel(1, digits)?,
+            channel(2, digits)?,
+            f32::from(alpha) / 255.0,
+        ))
+    }
+
+    /// Converts the [`Color`] into its hexadecimal notation.
+    ///
+    /// The alpha channel is only included when the [`Color`] is
+    /// translucent.
+    pub fn into_hex(self) -> String {
+        let [r, g, b, a] = self.into_rgba8();
+
+        if a == u8::MAX {
+            format!("#{r:02x}{g:02x}{b:02x}")
+        } else {
+            format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+        }
+    }
+
     /// Converts the [`Color`] into its linear values.
     pub fn into_linear(self) -> [f32; 4] {
         // As described in:
@@ -214,6 +265,71 @@ impl From<Color> for Srgb {
     }
 }
 
+#[cfg(feature = "serde")]
+/// Serializes a [`Color`] in hexadecimal notation.
+impl serde::Serialize for Color {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.into_hex())
+    }
+}
+
+#[cfg(feature = "serde")]
+/// Deserializes a [`Color`] either from its hexadecimal notation or from a
+/// map of its `r`, `g`, `b` and optional `a` components.
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        fn opaque() -> f32 {
+            1.0
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(crate = "serde", untagged)]
+        enum Repr {
+            Hex(String),
+            Components {
+                r: f32,
+                g: f32,
+                b: f32,
+                #[serde(default = "opaque")]
+                a: f32,
+            },
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Hex(hex) => Color::parse(&hex).ok_or_else(|| {
+                serde::de::Error::custom(format!("invalid color: {hex}"))
+            }),
+            Repr::Components { r, g, b, a } => Ok(Color::from_rgba(r, g, b, a)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod hex_tests {
+    use super::Color;
+
+    #[test]
+    fn hex_notation_round_trips() {
+        assert_eq!(Color::parse("#f80"), Some(Color::from_rgb8(255, 136, 0)));
+        assert_eq!(
+            Color::parse("336699"),
+            Some(Color::from_rgb8(0x33, 0x66, 0x99))
+        );
+        assert_eq!(
+            Color::parse("#33669980").map(Color::into_hex),
+            Some(String::from("#33669980"))
+        );
+        assert_eq!(Color::parse("#12345"), None);
+        assert_eq!(Color::parse("#ggg"), None);
+    }
+}
+
 #[cfg(feature = "palette")]
 #[cfg(test)]
 mod tests {
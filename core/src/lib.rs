@@ -38,6 +38,7 @@ mod padding;
 mod pixels;
 mod point;
 mod rectangle;
+mod shadow;
 mod size;
 mod vector;
 
@@ -51,5 +52,6 @@ pub use padding::Padding;
 pub use pixels::Pixels;
 pub use point::Point;
 pub use rectangle::Rectangle;
+pub use shadow::Shadow;
 pub use size::Size;
 pub use vector::Vector;
@@ -0,0 +1,29 @@
+use crate::{Color, Vector};
+
+/// A shadow cast by a graphics primitive.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Shadow {
+    /// The color of the [`Shadow`].
+    ///
+    /// A fully transparent color disables the [`Shadow`].
+    pub color: Color,
+
+    /// The offset of the [`Shadow`] from the primitive casting it.
+    pub offset: Vector,
+
+    /// The blur radius of the [`Shadow`], in logical pixels.
+    pub blur_radius: f32,
+}
+
+impl Shadow {
+    /// Returns true if the [`Shadow`] is visible.
+    pub fn is_visible(&self) -> bool {
+        self.color.a > 0.0
+    }
+}
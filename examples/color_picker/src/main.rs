@@ -0,0 +1,59 @@
+use iced::widget::{color_picker, column, container, row, text};
+use iced::{Alignment, Color, Element, Length, Sandbox, Settings};
+
+pub fn main() -> iced::Result {
+    Example::run(Settings::default())
+}
+
+struct Example {
+    color: Color,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ColorChanged(Color),
+}
+
+impl Sandbox for Example {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            color: Color::from_rgb8(0x34, 0x98, 0xdb),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Color picker - Iced")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ColorChanged(color) => {
+                self.color = color;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let picker = color_picker(self.color, Message::ColorChanged);
+
+        let preview = column![
+            text("Iced").size(60).style(self.color),
+            text(color_picker::to_hex(self.color)).size(20),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        let content = row![picker, preview]
+            .spacing(40)
+            .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,119 @@
+use iced::widget::{column, combo_box, container, text};
+use iced::{Alignment, Element, Length, Sandbox, Settings};
+
+pub fn main() -> iced::Result {
+    Example::run(Settings::default())
+}
+
+struct Example {
+    languages: Vec<String>,
+    selected_language: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    LanguageSelected(String),
+    LanguageAdded(String),
+}
+
+impl Sandbox for Example {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            languages: LANGUAGES.iter().map(ToString::to_string).collect(),
+            selected_language: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Combo box - Iced")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::LanguageSelected(language) => {
+                self.selected_language = Some(language);
+            }
+            Message::LanguageAdded(language) => {
+                if !self.languages.contains(&language) {
+                    self.languages.push(language.clone());
+                }
+
+                self.selected_language = Some(language);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let combo_box = combo_box(
+            &self.languages[..],
+            "Type a language...",
+            self.selected_language.clone(),
+            Message::LanguageSelected,
+        )
+        .on_custom(Message::LanguageAdded)
+        .width(250);
+
+        let content = column![
+            text(match &self.selected_language {
+                Some(language) => format!("Your favorite language: {language}"),
+                None => String::from("Which is your favorite language?"),
+            }),
+            combo_box,
+        ]
+        .width(Length::Fill)
+        .align_items(Alignment::Center)
+        .spacing(10);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .padding(100)
+            .into()
+    }
+}
+
+const LANGUAGES: &[&str] = &[
+    "Ada",
+    "Assembly",
+    "Bash",
+    "C",
+    "C#",
+    "C++",
+    "Clojure",
+    "COBOL",
+    "Crystal",
+    "D",
+    "Dart",
+    "Elixir",
+    "Elm",
+    "Erlang",
+    "F#",
+    "Fortran",
+    "Go",
+    "Haskell",
+    "Java",
+    "JavaScript",
+    "Julia",
+    "Kotlin",
+    "Lisp",
+    "Lua",
+    "Nim",
+    "OCaml",
+    "Pascal",
+    "Perl",
+    "PHP",
+    "Prolog",
+    "Python",
+    "R",
+    "Racket",
+    "Ruby",
+    "Rust",
+    "Scala",
+    "Scheme",
+    "Swift",
+    "TypeScript",
+    "Zig",
+];
@@ -3,7 +3,7 @@ mod quad {
     use iced_native::layout::{self, Layout};
     use iced_native::renderer;
     use iced_native::widget::{self, Widget};
-    use iced_native::{Color, Element, Length, Point, Rectangle, Size};
+    use iced_native::{Color, Element, Length, Point, Rectangle, Shadow, Size};
 
     pub struct CustomQuad {
         size: f32,
@@ -57,6 +57,7 @@ mod quad {
                     border_radius: self.radius.into(),
                     border_width: self.border_width,
                     border_color: Color::from_rgb(1.0, 0.0, 0.0),
+                    shadow: Shadow::default(),
                 },
                 Color::BLACK,
             );
@@ -12,7 +12,7 @@ mod circle {
     use iced_native::layout::{self, Layout};
     use iced_native::renderer;
     use iced_native::widget::{self, Widget};
-    use iced_native::{Color, Element, Length, Point, Rectangle, Size};
+    use iced_native::{Color, Element, Length, Point, Rectangle, Shadow, Size};
 
     pub struct Circle {
         radius: f32,
@@ -64,6 +64,7 @@ mod circle {
                     border_radius: self.radius.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
                 },
                 Color::BLACK,
             );
@@ -254,6 +254,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
                         renderer.with_primitives(|backend, primitive| {
                             backend.present(
                                 &device,
+                                &queue,
                                 &mut staging_belt,
                                 &mut encoder,
                                 &view,
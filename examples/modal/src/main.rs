@@ -180,7 +180,7 @@ mod modal {
     use iced_native::widget::{self, Tree};
     use iced_native::{
         event, layout, mouse, overlay, renderer, Clipboard, Color, Element,
-        Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+        Event, Layout, Length, Point, Rectangle, Shadow, Shell, Size, Widget,
     };
 
     /// A widget that centers a modal element over some base element
@@ -415,6 +415,7 @@ mod modal {
                     border_radius: renderer::BorderRadius::from(0.0),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
                 },
                 Color {
                     a: 0.80,
@@ -23,6 +23,7 @@ struct Styling {
 enum ThemeType {
     Light,
     Dark,
+    System,
     Custom,
 }
 
@@ -53,6 +54,7 @@ impl Sandbox for Styling {
                 self.theme = match theme {
                     ThemeType::Light => Theme::Light,
                     ThemeType::Dark => Theme::Dark,
+                    ThemeType::System => Theme::System,
                     ThemeType::Custom => Theme::custom(theme::Palette {
                         background: Color::from_rgb(1.0, 0.9, 1.0),
                         text: Color::BLACK,
@@ -71,24 +73,29 @@ impl Sandbox for Styling {
     }
 
     fn view(&self) -> Element<Message> {
-        let choose_theme =
-            [ThemeType::Light, ThemeType::Dark, ThemeType::Custom]
-                .iter()
-                .fold(
-                    column![text("Choose a theme:")].spacing(10),
-                    |column, theme| {
-                        column.push(radio(
-                            format!("{theme:?}"),
-                            *theme,
-                            Some(match self.theme {
-                                Theme::Light => ThemeType::Light,
-                                Theme::Dark => ThemeType::Dark,
-                                Theme::Custom { .. } => ThemeType::Custom,
-                            }),
-                            Message::ThemeChanged,
-                        ))
-                    },
-                );
+        let choose_theme = [
+            ThemeType::Light,
+            ThemeType::Dark,
+            ThemeType::System,
+            ThemeType::Custom,
+        ]
+        .iter()
+        .fold(
+            column![text("Choose a theme:")].spacing(10),
+            |column, theme| {
+                column.push(radio(
+                    format!("{theme:?}"),
+                    *theme,
+                    Some(match self.theme {
+                        Theme::Light => ThemeType::Light,
+                        Theme::Dark => ThemeType::Dark,
+                        Theme::System => ThemeType::System,
+                        Theme::Custom { .. } => ThemeType::Custom,
+                    }),
+                    Message::ThemeChanged,
+                ))
+            },
+        );
 
         let text_input = text_input("Type something...", &self.input_value)
             .on_input(Message::InputChanged)
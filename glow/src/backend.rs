@@ -1,7 +1,7 @@
 #[cfg(any(feature = "image", feature = "svg"))]
 use crate::image;
-use crate::quad;
 use crate::text;
+use crate::{blur, opacity, quad};
 use crate::{program, triangle};
 use crate::{Settings, Transformation, Viewport};
 
@@ -9,7 +9,7 @@ use iced_graphics::backend;
 use iced_graphics::font;
 use iced_graphics::{Layer, Primitive};
 use iced_native::alignment;
-use iced_native::{Font, Size};
+use iced_native::{Font, Rectangle, Size};
 
 /// A [`glow`] graphics backend for [`iced`].
 ///
@@ -20,6 +20,8 @@ pub struct Backend {
     #[cfg(any(feature = "image", feature = "svg"))]
     image_pipeline: image::Pipeline,
     quad_pipeline: quad::Pipeline,
+    opacity_pipeline: opacity::Pipeline,
+    blur_pipeline: blur::Pipeline,
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
     default_text_size: f32,
@@ -37,14 +39,28 @@ impl Backend {
         let shader_version = program::Version::new(gl);
 
         #[cfg(any(feature = "image", feature = "svg"))]
-        let image_pipeline = image::Pipeline::new(gl, &shader_version);
+        let image_pipeline = image::Pipeline::new(
+            gl,
+            &shader_version,
+            settings.image_cache_budget,
+        );
+
+        // Share the default font with the text of svgs
+        #[cfg(feature = "svg")]
+        if let Some(font) = settings.default_font {
+            image_pipeline.load_font(font);
+        }
         let quad_pipeline = quad::Pipeline::new(gl, &shader_version);
+        let opacity_pipeline = opacity::Pipeline::new(gl, &shader_version);
+        let blur_pipeline = blur::Pipeline::new(gl, &shader_version);
         let triangle_pipeline = triangle::Pipeline::new(gl, &shader_version);
 
         Self {
             #[cfg(any(feature = "image", feature = "svg"))]
             image_pipeline,
             quad_pipeline,
+            opacity_pipeline,
+            blur_pipeline,
             text_pipeline,
             triangle_pipeline,
             default_text_size: settings.default_text_size,
@@ -69,13 +85,41 @@ impl Backend {
         let mut layers = Layer::generate(primitives, viewport);
         layers.push(Layer::overlay(overlay_text, viewport));
 
-        for layer in layers {
-            self.flush(
+        if layers.iter().any(|layer| layer.backdrop.is_some()) {
+            // Backdrops need to read what is behind them, which is not
+            // possible with the default framebuffer; so we render offscreen
+            // first
+            let target = self.opacity_pipeline.target(gl, viewport_size);
+
+            self.draw_layers(
                 gl,
                 scale_factor,
                 projection,
-                &layer,
-                viewport_size.height,
+                &layers,
+                viewport_size,
+                Some(&target),
+            );
+
+            self.opacity_pipeline.composite(
+                gl,
+                target,
+                1.0,
+                Rectangle {
+                    x: 0,
+                    y: 0,
+                    width: viewport_size.width,
+                    height: viewport_size.height,
+                },
+                None,
+            );
+        } else {
+            self.draw_layers(
+                gl,
+                scale_factor,
+                projection,
+                &layers,
+                viewport_size,
+                None,
             );
         }
 
@@ -83,6 +127,130 @@ impl Backend {
         self.image_pipeline.trim_cache(gl);
     }
 
+    /// Draws the given layers in the given offscreen [`opacity::Target`], or
+    /// in the default framebuffer if there is none.
+    ///
+    /// Backdrops can only be drawn offscreen.
+    fn draw_layers(
+        &mut self,
+        gl: &glow::Context,
+        scale_factor: f32,
+        transformation: Transformation,
+        layers: &[Layer<'_>],
+        target_size: Size<u32>,
+        target: Option<&opacity::Target>,
+    ) {
+        let framebuffer = target.map(opacity::Target::framebuffer);
+        let mut i = 0;
+
+        while i < layers.len() {
+            let layer = &layers[i];
+
+            if let (Some(backdrop), Some(target)) = (layer.backdrop, target) {
+                let mut bounds = (layer.bounds * scale_factor).snap();
+                bounds.height = bounds.height.min(target_size.height);
+
+                self.blur_pipeline.backdrop(
+                    gl,
+                    &mut self.opacity_pipeline,
+                    target,
+                    backdrop.bounds * scale_factor,
+                    backdrop.border_radius.map(|radius| radius * scale_factor),
+                    backdrop.blur_radius * scale_factor,
+                    bounds,
+                );
+            }
+
+            let group = match layer.group {
+                Some(group) if group.is_offscreen() => group,
+                _ => {
+                    self.flush(
+                        gl,
+                        scale_factor,
+                        transformation,
+                        layer,
+                        target_size.height,
+                    );
+
+                    i += 1;
+                    continue;
+                }
+            };
+
+            let mut bounds = (layer.bounds * scale_factor).snap();
+            bounds.height = bounds.height.min(target_size.height);
+
+            if bounds.width >= 1 && bounds.height >= 1 {
+                // Translucent, blurred and masked groups are rendered
+                // offscreen first, so they can be composited as a whole
+                let group_target =
+                    self.opacity_pipeline.target(gl, target_size);
+
+                let content = group.mask.unwrap_or(group.layers);
+
+                self.flush(
+                    gl,
+                    scale_factor,
+                    transformation,
+                    layer,
+                    target_size.height,
+                );
+
+                self.draw_layers(
+                    gl,
+                    scale_factor,
+                    transformation,
+                    &layers[i + 1..i + content],
+                    target_size,
+                    Some(&group_target),
+                );
+
+                if let Some(mask) = group.mask {
+                    let mask_target =
+                        self.opacity_pipeline.target(gl, target_size);
+
+                    self.draw_layers(
+                        gl,
+                        scale_factor,
+                        transformation,
+                        &layers[i + mask..i + group.layers],
+                        target_size,
+                        Some(&mask_target),
+                    );
+
+                    self.opacity_pipeline.composite_masked(
+                        gl,
+                        group_target,
+                        mask_target,
+                        group.opacity,
+                        bounds,
+                        framebuffer,
+                    );
+                } else if group.blur_radius > 0.0 {
+                    self.blur_pipeline.composite(
+                        gl,
+                        &mut self.opacity_pipeline,
+                        group_target,
+                        group.blur_radius * scale_factor,
+                        group.opacity,
+                        bounds,
+                        framebuffer,
+                    );
+                } else {
+                    self.opacity_pipeline.composite(
+                        gl,
+                        group_target,
+                        group.opacity,
+                        bounds,
+                        framebuffer,
+                    );
+                }
+            }
+
+            i += group.layers;
+        }
+    }
+
     fn flush(
         &mut self,
         gl: &glow::Context,
@@ -99,6 +267,18 @@ impl Backend {
 
         bounds.height = bounds.height.min(target_height);
 
+        // The transformation of a layer is defined in logical coordinates
+        let transformation = if layer.transformation
+            == Transformation::identity()
+        {
+            transformation
+        } else {
+            transformation
+                * Transformation::scale(scale_factor, scale_factor)
+                * layer.transformation
+                * Transformation::scale(1.0 / scale_factor, 1.0 / scale_factor)
+        };
+
         if !layer.quads.is_empty() {
             self.quad_pipeline.draw(
                 gl,
@@ -221,6 +401,11 @@ impl iced_graphics::Backend for Backend {
     fn trim_measurements(&mut self) {
         self.text_pipeline.trim_measurement_cache()
     }
+
+    #[cfg(any(feature = "image", feature = "svg"))]
+    fn image_statistics(&self) -> iced_graphics::image::Statistics {
+        self.image_pipeline.statistics()
+    }
 }
 
 impl backend::Text for Backend {
@@ -267,6 +452,13 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn status(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> iced_native::image::Status {
+        self.image_pipeline.status(handle)
+    }
 }
 
 #[cfg(feature = "svg")]
@@ -0,0 +1,347 @@
+use crate::opacity::{self, Target};
+use crate::program::{self, Shader};
+
+use glow::HasContext;
+use iced_graphics::Rectangle;
+
+#[cfg(feature = "tracing")]
+use tracing::info_span;
+
+/// The maximum amount of samples taken on each side of a fragment.
+///
+/// It must match the one in the fragment shader. Larger blurs skip texels
+/// and rely on linear filtering.
+const MAX_SAMPLES: f32 = 32.0;
+
+const MODE_BLUR: i32 = 0;
+const MODE_ERASE: i32 = 1;
+const MODE_BACKDROP: i32 = 2;
+
+#[derive(Debug)]
+pub(crate) struct Pipeline {
+    program: <glow::Context as HasContext>::Program,
+    vertex_array: <glow::Context as HasContext>::VertexArray,
+    vertex_buffer: <glow::Context as HasContext>::Buffer,
+    uniforms: Uniforms,
+}
+
+#[derive(Debug)]
+struct Uniforms {
+    step: <glow::Context as HasContext>::UniformLocation,
+    sigma: <glow::Context as HasContext>::UniformLocation,
+    samples: <glow::Context as HasContext>::UniformLocation,
+    bounds: <glow::Context as HasContext>::UniformLocation,
+    border_radius: <glow::Context as HasContext>::UniformLocation,
+    opacity: <glow::Context as HasContext>::UniformLocation,
+    target_height: <glow::Context as HasContext>::UniformLocation,
+    mode: <glow::Context as HasContext>::UniformLocation,
+}
+
+impl Pipeline {
+    pub fn new(
+        gl: &glow::Context,
+        shader_version: &program::Version,
+    ) -> Pipeline {
+        let program = unsafe {
+            let vertex_shader = Shader::vertex(
+                gl,
+                shader_version,
+                include_str!("shader/common/opacity.vert"),
+            );
+            let fragment_shader = Shader::fragment(
+                gl,
+                shader_version,
+                include_str!("shader/common/blur.frag"),
+            );
+
+            program::create(
+                gl,
+                &[vertex_shader, fragment_shader],
+                &[(0, "i_Position")],
+            )
+        };
+
+        let location = |name| {
+            unsafe { gl.get_uniform_location(program, name) }
+                .expect("Get blur uniform location")
+        };
+
+        let uniforms = Uniforms {
+            step: location("u_Step"),
+            sigma: location("u_Sigma"),
+            samples: location("u_Samples"),
+            bounds: location("u_Bounds"),
+            border_radius: location("u_BorderRadius"),
+            opacity: location("u_Opacity"),
+            target_height: location("u_TargetHeight"),
+            mode: location("u_Mode"),
+        };
+
+        let vertex_buffer =
+            unsafe { gl.create_buffer().expect("Create vertex buffer") };
+        let vertex_array =
+            unsafe { gl.create_vertex_array().expect("Create vertex array") };
+
+        unsafe {
+            gl.bind_vertex_array(Some(vertex_array));
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(vertex_buffer));
+
+            let vertices = &[0u8, 0, 1, 0, 0, 1, 1, 1];
+            gl.buffer_data_size(
+                glow::ARRAY_BUFFER,
+                vertices.len() as i32,
+                glow::STATIC_DRAW,
+            );
+            gl.buffer_sub_data_u8_slice(glow::ARRAY_BUFFER, 0, vertices);
+
+            gl.enable_vertex_attrib_array(0);
+            gl.vertex_attrib_pointer_f32(
+                0,
+                2,
+                glow::UNSIGNED_BYTE,
+                false,
+                0,
+                0,
+            );
+
+            gl.bind_buffer(glow::ARRAY_BUFFER, None);
+            gl.bind_vertex_array(None);
+        }
+
+        Pipeline {
+            program,
+            vertex_array,
+            vertex_buffer,
+            uniforms,
+        }
+    }
+
+    /// Blurs the contents of the given [`Target`] and blends them into the
+    /// `framebuffer` with the given opacity.
+    ///
+    /// The `radius` is in physical pixels.
+    pub fn composite(
+        &mut self,
+        gl: &glow::Context,
+        opacity_pipeline: &mut opacity::Pipeline,
+        target: Target,
+        radius: f32,
+        opacity: f32,
+        bounds: Rectangle<u32>,
+        framebuffer: Option<<glow::Context as HasContext>::Framebuffer>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Glow::Blur", "COMPOSITE").entered();
+
+        let size = target.size();
+        let scratch = opacity_pipeline.target(gl, size);
+
+        self.horizontal(gl, &target, radius, bounds);
+
+        unsafe {
+            gl.bind_framebuffer(glow::FRAMEBUFFER, framebuffer);
+
+            gl.use_program(Some(self.program));
+            gl.uniform_1_f32(Some(&self.uniforms.opacity), opacity);
+            gl.uniform_1_i32(Some(&self.uniforms.mode), MODE_BLUR);
+
+            self.pass(
+                gl,
+                &scratch,
+                Direction::Vertical,
+                radius,
+                bounds,
+                (glow::ONE, glow::ONE_MINUS_SRC_ALPHA),
+            );
+
+            gl.use_program(None);
+        }
+
+        opacity_pipeline.recycle(scratch);
+        opacity_pipeline.recycle(target);
+    }
+
+    /// Blurs the contents of the given [`Target`] inside the given rounded
+    /// region.
+    ///
+    /// The `region`, `border_radius` and `radius` are in physical pixels.
+    /// The framebuffer of the [`Target`] is left bound.
+    pub fn backdrop(
+        &mut self,
+        gl: &glow::Context,
+        opacity_pipeline: &mut opacity::Pipeline,
+        target: &Target,
+        region: Rectangle,
+        border_radius: [f32; 4],
+        radius: f32,
+        bounds: Rectangle<u32>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Glow::Blur", "BACKDROP").entered();
+
+        let scratch = opacity_pipeline.target(gl, target.size());
+
+        self.horizontal(gl, target, radius, bounds);
+
+        unsafe {
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(target.framebuffer()));
+
+            gl.use_program(Some(self.program));
+            gl.uniform_4_f32(
+                Some(&self.uniforms.bounds),
+                region.x,
+                region.y,
+                region.width,
+                region.height,
+            );
+            gl.uniform_4_f32_slice(
+                Some(&self.uniforms.border_radius),
+                &border_radius,
+            );
+            gl.uniform_1_f32(
+                Some(&self.uniforms.target_height),
+                target.size().height as f32,
+            );
+
+            // Scale the backdrop down by its coverage, and then add the
+            // blurred backdrop in its place
+            gl.uniform_1_i32(Some(&self.uniforms.mode), MODE_ERASE);
+
+            self.pass(
+                gl,
+                &scratch,
+                Direction::Vertical,
+                radius,
+                bounds,
+                (glow::ZERO, glow::ONE_MINUS_SRC_ALPHA),
+            );
+
+            gl.uniform_1_i32(Some(&self.uniforms.mode), MODE_BACKDROP);
+
+            self.pass(
+                gl,
+                &scratch,
+                Direction::Vertical,
+                radius,
+                bounds,
+                (glow::ONE, glow::ONE),
+            );
+
+            gl.use_program(None);
+        }
+
+        opacity_pipeline.recycle(scratch);
+    }
+
+    /// Blurs the given [`Target`] horizontally into the bound scratch
+    /// framebuffer.
+    fn horizontal(
+        &self,
+        gl: &glow::Context,
+        source: &Target,
+        radius: f32,
+        bounds: Rectangle<u32>,
+    ) {
+        let size = source.size();
+
+        // The vertical pass samples this region of the horizontal one
+        let spread = radius.ceil() as u32;
+        let y = bounds.y.saturating_sub(spread);
+        let bottom = (bounds.y + bounds.height + spread).min(size.height);
+
+        let bounds = Rectangle {
+            y,
+            height: bottom.saturating_sub(y),
+            ..bounds
+        };
+
+        unsafe {
+            gl.use_program(Some(self.program));
+            gl.uniform_1_f32(Some(&self.uniforms.opacity), 1.0);
+            gl.uniform_1_i32(Some(&self.uniforms.mode), MODE_BLUR);
+
+            self.pass(
+                gl,
+                source,
+                Direction::Horizontal,
+                radius,
+                bounds,
+                (glow::ONE, glow::ONE_MINUS_SRC_ALPHA),
+            );
+
+            gl.use_program(None);
+        }
+    }
+
+    /// Draws the given [`Target`] blurred in the given direction into the
+    /// bound framebuffer, with the given blend function.
+    ///
+    /// The program must be in use.
+    unsafe fn pass(
+        &self,
+        gl: &glow::Context,
+        source: &Target,
+        direction: Direction,
+        radius: f32,
+        bounds: Rectangle<u32>,
+        (source_factor, destination_factor): (u32, u32),
+    ) {
+        let size = source.size();
+
+        if bounds.width < 1 || bounds.height < 1 {
+            return;
+        }
+
+        let samples = radius.ceil().clamp(1.0, MAX_SAMPLES);
+        let spacing = radius.max(1.0) / samples;
+
+        let (x, y) = match direction {
+            Direction::Horizontal => (spacing / size.width as f32, 0.0),
+            Direction::Vertical => (0.0, spacing / size.height as f32),
+        };
+
+        gl.uniform_2_f32(Some(&self.uniforms.step), x, y);
+        gl.uniform_1_f32(Some(&self.uniforms.samples), samples);
+        // The blur radius covers two standard deviations
+        gl.uniform_1_f32(Some(&self.uniforms.sigma), samples / 2.0);
+
+        gl.enable(glow::SCISSOR_TEST);
+        gl.scissor(
+            bounds.x as i32,
+            (size.height - (bounds.y + bounds.height)) as i32,
+            bounds.width as i32,
+            bounds.height as i32,
+        );
+
+        gl.bind_vertex_array(Some(self.vertex_array));
+        gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.vertex_buffer));
+        gl.bind_texture(glow::TEXTURE_2D, Some(source.texture()));
+
+        gl.blend_func_separate(
+            source_factor,
+            destination_factor,
+            source_factor,
+            destination_factor,
+        );
+        gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+
+        gl.bind_texture(glow::TEXTURE_2D, None);
+        gl.bind_buffer(glow::ARRAY_BUFFER, None);
+        gl.bind_vertex_array(None);
+
+        gl.blend_func_separate(
+            glow::SRC_ALPHA,
+            glow::ONE_MINUS_SRC_ALPHA,
+            glow::ONE,
+            glow::ONE_MINUS_SRC_ALPHA,
+        );
+
+        gl.disable(glow::SCISSOR_TEST);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Horizontal,
+    Vertical,
+}
@@ -13,9 +13,11 @@ use iced_graphics::image::raster;
 #[cfg(feature = "svg")]
 use iced_graphics::image::vector;
 
+use iced_graphics::image::storage::Entry as _;
+use iced_graphics::image::{nine_slice, Budget, Statistics};
 use iced_graphics::layer;
-use iced_graphics::Rectangle;
-use iced_graphics::Size;
+use iced_graphics::{Point, Rectangle, Size};
+use iced_native::image::FilterMethod;
 
 use glow::HasContext;
 
@@ -30,6 +32,8 @@ pub(crate) struct Pipeline {
     vertex_array: <glow::Context as HasContext>::VertexArray,
     vertex_buffer: <glow::Context as HasContext>::Buffer,
     transform_location: <glow::Context as HasContext>::UniformLocation,
+    opacity_location: <glow::Context as HasContext>::UniformLocation,
+    texture_region_location: <glow::Context as HasContext>::UniformLocation,
     storage: Storage,
     #[cfg(feature = "image")]
     raster_cache: RefCell<raster::Cache<Storage>>,
@@ -41,6 +45,7 @@ impl Pipeline {
     pub fn new(
         gl: &glow::Context,
         shader_version: &program::Version,
+        budget: Budget,
     ) -> Pipeline {
         let program = unsafe {
             let vertex_shader = Shader::vertex(
@@ -65,6 +70,14 @@ impl Pipeline {
             unsafe { gl.get_uniform_location(program, "u_Transform") }
                 .expect("Get transform location");
 
+        let opacity_location =
+            unsafe { gl.get_uniform_location(program, "u_Opacity") }
+                .expect("Get opacity location");
+
+        let texture_region_location =
+            unsafe { gl.get_uniform_location(program, "u_TextureRegion") }
+                .expect("Get texture region location");
+
         unsafe {
             gl.use_program(Some(program));
 
@@ -75,6 +88,15 @@ impl Pipeline {
                 &transform,
             );
 
+            gl.uniform_1_f32(Some(&opacity_location), 1.0);
+            gl.uniform_4_f32(
+                Some(&texture_region_location),
+                0.0,
+                0.0,
+                1.0,
+                1.0,
+            );
+
             gl.use_program(None);
         }
 
@@ -118,11 +140,13 @@ impl Pipeline {
             vertex_array,
             vertex_buffer,
             transform_location,
+            opacity_location,
+            texture_region_location,
             storage: Storage::default(),
             #[cfg(feature = "image")]
-            raster_cache: RefCell::new(raster::Cache::default()),
+            raster_cache: RefCell::new(raster::Cache::new(budget)),
             #[cfg(feature = "svg")]
-            vector_cache: RefCell::new(vector::Cache::default()),
+            vector_cache: RefCell::new(vector::Cache::new(budget)),
         }
     }
 
@@ -131,6 +155,19 @@ impl Pipeline {
         self.raster_cache.borrow_mut().load(handle).dimensions()
     }
 
+    #[cfg(feature = "image")]
+    pub fn status(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> iced_native::image::Status {
+        self.raster_cache.borrow_mut().load(handle).status()
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn load_font(&self, bytes: &'static [u8]) {
+        self.vector_cache.borrow_mut().load_font(bytes);
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(
         &self,
@@ -142,6 +179,18 @@ impl Pipeline {
         svg.viewport_dimensions()
     }
 
+    pub fn statistics(&self) -> Statistics {
+        let statistics = Statistics::default();
+
+        #[cfg(feature = "image")]
+        let statistics = statistics + self.raster_cache.borrow().statistics();
+
+        #[cfg(feature = "svg")]
+        let statistics = statistics + self.vector_cache.borrow().statistics();
+
+        statistics
+    }
+
     pub fn draw(
         &mut self,
         mut gl: &glow::Context,
@@ -168,38 +217,81 @@ impl Pipeline {
         let mut vector_cache = self.vector_cache.borrow_mut();
 
         for image in images {
-            let (entry, bounds) = match &image {
-                #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => (
-                    raster_cache.upload(handle, &mut gl, &mut self.storage),
-                    bounds,
-                ),
-                #[cfg(not(feature = "image"))]
-                layer::Image::Raster { handle: _, bounds } => (None, bounds),
-
-                #[cfg(feature = "svg")]
-                layer::Image::Vector {
-                    handle,
-                    color,
-                    bounds,
-                } => {
-                    let size = [bounds.width, bounds.height];
-                    (
-                        vector_cache.upload(
+            let (entry, filter_method, opacity, rotation, insets, bounds) =
+                match &image {
+                    #[cfg(feature = "image")]
+                    layer::Image::Raster {
+                        handle,
+                        filter_method,
+                        opacity,
+                        rotation,
+                        bounds,
+                    } => (
+                        raster_cache.upload(handle, &mut gl, &mut self.storage),
+                        *filter_method,
+                        *opacity,
+                        *rotation,
+                        None,
+                        *bounds,
+                    ),
+                    #[cfg(not(feature = "image"))]
+                    layer::Image::Raster { bounds, .. } => {
+                        (None, FilterMethod::Linear, 1.0, 0.0, None, *bounds)
+                    }
+
+                    #[cfg(feature = "image")]
+                    layer::Image::NineSlice {
+                        handle,
+                        insets,
+                        bounds,
+                    } => (
+                        raster_cache.upload(handle, &mut gl, &mut self.storage),
+                        FilterMethod::Linear,
+                        1.0,
+                        0.0,
+                        Some(*insets),
+                        *bounds,
+                    ),
+                    #[cfg(not(feature = "image"))]
+                    layer::Image::NineSlice { bounds, .. } => {
+                        (None, FilterMethod::Linear, 1.0, 0.0, None, *bounds)
+                    }
+
+                    #[cfg(feature = "svg")]
+                    layer::Image::Vector {
+                        handle,
+                        color,
+                        stylesheet,
+                        bounds,
+                    } => {
+                        let size = [bounds.width, bounds.height];
+
+                        let entry = vector_cache.upload(
                             handle,
                             *color,
+                            stylesheet.as_ref(),
                             size,
                             _scale_factor,
                             &mut gl,
                             &mut self.storage,
-                        ),
-                        bounds,
-                    )
-                }
+                        );
+
+                        let bounds = entry.map_or(*bounds, |entry| {
+                            vector::pixel_bounds(
+                                *bounds,
+                                entry.size(),
+                                _scale_factor,
+                            )
+                        });
 
-                #[cfg(not(feature = "svg"))]
-                layer::Image::Vector { bounds, .. } => (None, bounds),
-            };
+                        (entry, FilterMethod::Linear, 1.0, 0.0, None, bounds)
+                    }
+
+                    #[cfg(not(feature = "svg"))]
+                    layer::Image::Vector { bounds, .. } => {
+                        (None, FilterMethod::Linear, 1.0, 0.0, None, *bounds)
+                    }
+                };
 
             unsafe {
                 gl.scissor(
@@ -210,23 +302,74 @@ impl Pipeline {
                     layer_bounds.height as i32,
                 );
 
-                if let Some(storage::Entry { texture, .. }) = entry {
-                    gl.bind_texture(glow::TEXTURE_2D, Some(*texture))
-                } else {
+                let Some(entry) = entry else {
                     continue;
-                }
+                };
 
-                let translate = Transformation::translate(bounds.x, bounds.y);
-                let scale = Transformation::scale(bounds.width, bounds.height);
-                let transformation = transformation * translate * scale;
-                let matrix: [f32; 16] = transformation.into();
-                gl.uniform_matrix_4_f32_slice(
-                    Some(&self.transform_location),
-                    false,
-                    &matrix,
+                gl.bind_texture(glow::TEXTURE_2D, Some(entry.texture));
+
+                let filter = match filter_method {
+                    FilterMethod::Linear => glow::LINEAR,
+                    FilterMethod::Nearest => glow::NEAREST,
+                };
+
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_MIN_FILTER,
+                    filter as _,
+                );
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_MAG_FILTER,
+                    filter as _,
                 );
 
-                gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+                let center = bounds.center();
+
+                // Rotate clockwise around the center of the image
+                let rotate = Transformation::translate(center.x, center.y)
+                    * Transformation::rotate(rotation)
+                    * Transformation::translate(-center.x, -center.y);
+
+                gl.uniform_1_f32(Some(&self.opacity_location), opacity);
+
+                let size = entry.size();
+                let whole = Rectangle::new(
+                    Point::ORIGIN,
+                    Size::new(size.width as f32, size.height as f32),
+                );
+
+                let slices = match insets {
+                    Some(insets) => nine_slice(size, insets, bounds),
+                    None => vec![(whole, bounds)],
+                };
+
+                for (source, destination) in slices {
+                    gl.uniform_4_f32(
+                        Some(&self.texture_region_location),
+                        source.x / whole.width,
+                        source.y / whole.height,
+                        source.width / whole.width,
+                        source.height / whole.height,
+                    );
+
+                    let translate =
+                        Transformation::translate(destination.x, destination.y);
+                    let scale = Transformation::scale(
+                        destination.width,
+                        destination.height,
+                    );
+                    let transformation =
+                        transformation * rotate * translate * scale;
+                    let matrix: [f32; 16] = transformation.into();
+                    gl.uniform_matrix_4_f32_slice(
+                        Some(&self.transform_location),
+                        false,
+                        &matrix,
+                    );
+
+                    gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+                }
 
                 gl.bind_texture(glow::TEXTURE_2D, None);
             }
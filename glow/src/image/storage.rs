@@ -60,6 +60,24 @@ impl image::Storage for Storage {
         }
     }
 
+    fn update(&mut self, entry: &Entry, data: &[u8], gl: &mut &glow::Context) {
+        unsafe {
+            gl.bind_texture(glow::TEXTURE_2D, Some(entry.texture));
+            gl.tex_sub_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                0,
+                0,
+                entry.size.width as i32,
+                entry.size.height as i32,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelUnpackData::Slice(data),
+            );
+            gl.bind_texture(glow::TEXTURE_2D, None);
+        }
+    }
+
     fn remove(&mut self, entry: &Entry, gl: &mut &glow::Context) {
         unsafe { gl.delete_texture(entry.texture) }
     }
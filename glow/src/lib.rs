@@ -24,8 +24,10 @@
 pub use glow;
 
 mod backend;
+mod blur;
 #[cfg(any(feature = "image", feature = "svg"))]
 mod image;
+mod opacity;
 mod program;
 mod quad;
 mod text;
@@ -0,0 +1,326 @@
+use crate::program::{self, Shader};
+
+use glow::HasContext;
+use iced_graphics::{Rectangle, Size};
+
+#[cfg(feature = "tracing")]
+use tracing::info_span;
+
+#[derive(Debug)]
+pub(crate) struct Pipeline {
+    program: <glow::Context as HasContext>::Program,
+    vertex_array: <glow::Context as HasContext>::VertexArray,
+    vertex_buffer: <glow::Context as HasContext>::Buffer,
+    opacity_location: <glow::Context as HasContext>::UniformLocation,
+    masked_location: <glow::Context as HasContext>::UniformLocation,
+    targets: Vec<Target>,
+}
+
+impl Pipeline {
+    pub fn new(
+        gl: &glow::Context,
+        shader_version: &program::Version,
+    ) -> Pipeline {
+        let program = unsafe {
+            let vertex_shader = Shader::vertex(
+                gl,
+                shader_version,
+                include_str!("shader/common/opacity.vert"),
+            );
+            let fragment_shader = Shader::fragment(
+                gl,
+                shader_version,
+                include_str!("shader/common/opacity.frag"),
+            );
+
+            program::create(
+                gl,
+                &[vertex_shader, fragment_shader],
+                &[(0, "i_Position")],
+            )
+        };
+
+        let opacity_location =
+            unsafe { gl.get_uniform_location(program, "u_Opacity") }
+                .expect("Get opacity location");
+
+        let masked_location =
+            unsafe { gl.get_uniform_location(program, "u_Masked") }
+                .expect("Get masked location");
+
+        unsafe {
+            let mask_location = gl
+                .get_uniform_location(program, "u_Mask")
+                .expect("Get mask location");
+
+            gl.use_program(Some(program));
+            gl.uniform_1_i32(Some(&mask_location), 1);
+            gl.use_program(None);
+        }
+
+        let vertex_buffer =
+            unsafe { gl.create_buffer().expect("Create vertex buffer") };
+        let vertex_array =
+            unsafe { gl.create_vertex_array().expect("Create vertex array") };
+
+        unsafe {
+            gl.bind_vertex_array(Some(vertex_array));
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(vertex_buffer));
+
+            let vertices = &[0u8, 0, 1, 0, 0, 1, 1, 1];
+            gl.buffer_data_size(
+                glow::ARRAY_BUFFER,
+                vertices.len() as i32,
+                glow::STATIC_DRAW,
+            );
+            gl.buffer_sub_data_u8_slice(glow::ARRAY_BUFFER, 0, vertices);
+
+            gl.enable_vertex_attrib_array(0);
+            gl.vertex_attrib_pointer_f32(
+                0,
+                2,
+                glow::UNSIGNED_BYTE,
+                false,
+                0,
+                0,
+            );
+
+            gl.bind_buffer(glow::ARRAY_BUFFER, None);
+            gl.bind_vertex_array(None);
+        }
+
+        Pipeline {
+            program,
+            vertex_array,
+            vertex_buffer,
+            opacity_location,
+            masked_location,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Binds and clears a [`Target`] of the given size to render a group
+    /// into.
+    ///
+    /// The [`Target`] must be given back with [`Pipeline::composite`],
+    /// [`Pipeline::composite_masked`] or [`Pipeline::recycle`].
+    pub fn target(&mut self, gl: &glow::Context, size: Size<u32>) -> Target {
+        let target = match self.targets.pop() {
+            Some(target) if target.size == size => target,
+            Some(target) => {
+                target.destroy(gl);
+
+                Target::new(gl, size)
+            }
+            None => Target::new(gl, size),
+        };
+
+        unsafe {
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(target.framebuffer));
+            gl.clear_color(0.0, 0.0, 0.0, 0.0);
+            gl.clear(glow::COLOR_BUFFER_BIT);
+        }
+
+        target
+    }
+
+    /// Blends the contents of the given [`Target`] into the `framebuffer`
+    /// with the given opacity.
+    pub fn composite(
+        &mut self,
+        gl: &glow::Context,
+        target: Target,
+        opacity: f32,
+        bounds: Rectangle<u32>,
+        framebuffer: Option<<glow::Context as HasContext>::Framebuffer>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Glow::Opacity", "COMPOSITE").entered();
+
+        self.draw(gl, &target, None, opacity, bounds, framebuffer);
+        self.recycle(target);
+    }
+
+    /// Blends the contents of the given [`Target`] into the `framebuffer`
+    /// with the given opacity, multiplied by the alpha of the `mask`.
+    pub fn composite_masked(
+        &mut self,
+        gl: &glow::Context,
+        target: Target,
+        mask: Target,
+        opacity: f32,
+        bounds: Rectangle<u32>,
+        framebuffer: Option<<glow::Context as HasContext>::Framebuffer>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Glow::Opacity", "COMPOSITE_MASKED").entered();
+
+        self.draw(gl, &target, Some(&mask), opacity, bounds, framebuffer);
+        self.recycle(mask);
+        self.recycle(target);
+    }
+
+    fn draw(
+        &self,
+        gl: &glow::Context,
+        target: &Target,
+        mask: Option<&Target>,
+        opacity: f32,
+        bounds: Rectangle<u32>,
+        framebuffer: Option<<glow::Context as HasContext>::Framebuffer>,
+    ) {
+        unsafe {
+            gl.bind_framebuffer(glow::FRAMEBUFFER, framebuffer);
+
+            gl.enable(glow::SCISSOR_TEST);
+            gl.scissor(
+                bounds.x as i32,
+                (target.size.height - (bounds.y + bounds.height)) as i32,
+                bounds.width as i32,
+                bounds.height as i32,
+            );
+
+            // The group is rendered with premultiplied alpha
+            gl.blend_func_separate(
+                glow::ONE,
+                glow::ONE_MINUS_SRC_ALPHA,
+                glow::ONE,
+                glow::ONE_MINUS_SRC_ALPHA,
+            );
+
+            gl.use_program(Some(self.program));
+            gl.uniform_1_f32(Some(&self.opacity_location), opacity);
+            gl.uniform_1_f32(
+                Some(&self.masked_location),
+                if mask.is_some() { 1.0 } else { 0.0 },
+            );
+            gl.bind_vertex_array(Some(self.vertex_array));
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.vertex_buffer));
+
+            if let Some(mask) = mask {
+                gl.active_texture(glow::TEXTURE1);
+                gl.bind_texture(glow::TEXTURE_2D, Some(mask.texture));
+                gl.active_texture(glow::TEXTURE0);
+            }
+
+            gl.bind_texture(glow::TEXTURE_2D, Some(target.texture));
+
+            gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+
+            gl.bind_texture(glow::TEXTURE_2D, None);
+
+            if mask.is_some() {
+                gl.active_texture(glow::TEXTURE1);
+                gl.bind_texture(glow::TEXTURE_2D, None);
+                gl.active_texture(glow::TEXTURE0);
+            }
+
+            gl.bind_buffer(glow::ARRAY_BUFFER, None);
+            gl.bind_vertex_array(None);
+            gl.use_program(None);
+
+            gl.blend_func_separate(
+                glow::SRC_ALPHA,
+                glow::ONE_MINUS_SRC_ALPHA,
+                glow::ONE,
+                glow::ONE_MINUS_SRC_ALPHA,
+            );
+
+            gl.disable(glow::SCISSOR_TEST);
+        }
+    }
+
+    /// Gives back a [`Target`] that is no longer needed, so it can be reused.
+    pub fn recycle(&mut self, target: Target) {
+        self.targets.push(target);
+    }
+}
+
+/// An offscreen framebuffer where a group of layers is rendered.
+#[derive(Debug)]
+pub(crate) struct Target {
+    framebuffer: <glow::Context as HasContext>::Framebuffer,
+    texture: <glow::Context as HasContext>::Texture,
+    size: Size<u32>,
+}
+
+impl Target {
+    fn new(gl: &glow::Context, size: Size<u32>) -> Target {
+        unsafe {
+            let texture = gl.create_texture().expect("Create texture");
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                glow::SRGB8_ALPHA8 as i32,
+                size.width as i32,
+                size.height as i32,
+                0,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                None,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_S,
+                glow::CLAMP_TO_EDGE as _,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_T,
+                glow::CLAMP_TO_EDGE as _,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MIN_FILTER,
+                glow::LINEAR as _,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MAG_FILTER,
+                glow::LINEAR as _,
+            );
+            gl.bind_texture(glow::TEXTURE_2D, None);
+
+            let framebuffer =
+                gl.create_framebuffer().expect("Create framebuffer");
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+            gl.framebuffer_texture_2d(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::TEXTURE_2D,
+                Some(texture),
+                0,
+            );
+            gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+
+            Target {
+                framebuffer,
+                texture,
+                size,
+            }
+        }
+    }
+
+    /// Returns the framebuffer to render into.
+    pub fn framebuffer(&self) -> <glow::Context as HasContext>::Framebuffer {
+        self.framebuffer
+    }
+
+    /// Returns the texture with the contents of the [`Target`].
+    pub fn texture(&self) -> <glow::Context as HasContext>::Texture {
+        self.texture
+    }
+
+    /// Returns the size of the [`Target`].
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    fn destroy(self, gl: &glow::Context) {
+        unsafe {
+            gl.delete_framebuffer(self.framebuffer);
+            gl.delete_texture(self.texture);
+        }
+    }
+}
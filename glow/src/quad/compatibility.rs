@@ -18,10 +18,8 @@ pub struct Pipeline {
     index_buffer: <glow::Context as HasContext>::Buffer,
     transform_location: <glow::Context as HasContext>::UniformLocation,
     scale_location: <glow::Context as HasContext>::UniformLocation,
-    screen_height_location: <glow::Context as HasContext>::UniformLocation,
     current_transform: Transformation,
     current_scale: f32,
-    current_target_height: u32,
 }
 
 impl Pipeline {
@@ -51,6 +49,10 @@ impl Pipeline {
                     (3, "i_BorderColor"),
                     (4, "i_BorderRadius"),
                     (5, "i_BorderWidth"),
+                    (6, "q_Pos"),
+                    (7, "i_ShadowColor"),
+                    (8, "i_ShadowOffset"),
+                    (9, "i_ShadowBlurRadius"),
                 ],
             )
         };
@@ -63,10 +65,6 @@ impl Pipeline {
             unsafe { gl.get_uniform_location(program, "u_Scale") }
                 .expect("Get scale location");
 
-        let screen_height_location =
-            unsafe { gl.get_uniform_location(program, "u_ScreenHeight") }
-                .expect("Get target height location");
-
         unsafe {
             gl.use_program(Some(program));
 
@@ -77,7 +75,6 @@ impl Pipeline {
             );
 
             gl.uniform_1_f32(Some(&scale_location), 1.0);
-            gl.uniform_1_f32(Some(&screen_height_location), 0.0);
 
             gl.use_program(None);
         }
@@ -92,10 +89,8 @@ impl Pipeline {
             index_buffer,
             transform_location,
             scale_location,
-            screen_height_location,
             current_transform: Transformation::identity(),
             current_scale: 1.0,
-            current_target_height: 0,
         }
     }
 
@@ -156,17 +151,6 @@ impl Pipeline {
             self.current_scale = scale;
         }
 
-        if target_height != self.current_target_height {
-            unsafe {
-                gl.uniform_1_f32(
-                    Some(&self.screen_height_location),
-                    target_height as f32,
-                );
-            }
-
-            self.current_target_height = target_height;
-        }
-
         let passes = vertices
             .chunks(MAX_VERTICES)
             .zip(indices.chunks(MAX_INDICES));
@@ -281,6 +265,36 @@ unsafe fn create_buffers(
         4 * (2 + 2 + 4 + 4 + 4 + 1),
     );
 
+    gl.enable_vertex_attrib_array(7);
+    gl.vertex_attrib_pointer_f32(
+        7,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 2),
+    );
+
+    gl.enable_vertex_attrib_array(8);
+    gl.vertex_attrib_pointer_f32(
+        8,
+        2,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 2 + 4),
+    );
+
+    gl.enable_vertex_attrib_array(9);
+    gl.vertex_attrib_pointer_f32(
+        9,
+        1,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 2 + 4 + 2),
+    );
+
     gl.bind_vertex_array(None);
     gl.bind_buffer(glow::ARRAY_BUFFER, None);
     gl.bind_buffer(glow::ELEMENT_ARRAY_BUFFER, None);
@@ -314,6 +328,15 @@ pub struct Vertex {
 
     /// The __quad__ position of the [`Vertex`].
     pub q_position: [f32; 2],
+
+    /// The shadow color of the [`Vertex`], in __linear RGB__.
+    pub shadow_color: [f32; 4],
+
+    /// The shadow offset of the [`Vertex`].
+    pub shadow_offset: [f32; 2],
+
+    /// The shadow blur radius of the [`Vertex`].
+    pub shadow_blur_radius: f32,
 }
 
 impl Vertex {
@@ -328,6 +351,9 @@ impl Vertex {
             border_radius: quad.border_radius,
             border_width: quad.border_width,
             q_position: [0.0, 0.0],
+            shadow_color: quad.shadow_color,
+            shadow_offset: quad.shadow_offset,
+            shadow_blur_radius: quad.shadow_blur_radius,
         };
 
         [
@@ -13,10 +13,8 @@ pub struct Pipeline {
     instances: <glow::Context as HasContext>::Buffer,
     transform_location: <glow::Context as HasContext>::UniformLocation,
     scale_location: <glow::Context as HasContext>::UniformLocation,
-    screen_height_location: <glow::Context as HasContext>::UniformLocation,
     current_transform: Transformation,
     current_scale: f32,
-    current_target_height: u32,
 }
 
 impl Pipeline {
@@ -46,6 +44,9 @@ impl Pipeline {
                     (3, "i_BorderColor"),
                     (4, "i_BorderRadius"),
                     (5, "i_BorderWidth"),
+                    (6, "i_ShadowColor"),
+                    (7, "i_ShadowOffset"),
+                    (8, "i_ShadowBlurRadius"),
                 ],
             )
         };
@@ -58,10 +59,6 @@ impl Pipeline {
             unsafe { gl.get_uniform_location(program, "u_Scale") }
                 .expect("Get scale location");
 
-        let screen_height_location =
-            unsafe { gl.get_uniform_location(program, "u_ScreenHeight") }
-                .expect("Get target height location");
-
         unsafe {
             gl.use_program(Some(program));
 
@@ -72,7 +69,6 @@ impl Pipeline {
             );
 
             gl.uniform_1_f32(Some(&scale_location), 1.0);
-            gl.uniform_1_f32(Some(&screen_height_location), 0.0);
 
             gl.use_program(None);
         }
@@ -86,10 +82,8 @@ impl Pipeline {
             instances,
             transform_location,
             scale_location,
-            screen_height_location,
             current_transform: Transformation::identity(),
             current_scale: 1.0,
-            current_target_height: 0,
         }
     }
 
@@ -136,17 +130,6 @@ impl Pipeline {
             self.current_scale = scale;
         }
 
-        if target_height != self.current_target_height {
-            unsafe {
-                gl.uniform_1_f32(
-                    Some(&self.screen_height_location),
-                    target_height as f32,
-                );
-            }
-
-            self.current_target_height = target_height;
-        }
-
         for instances in instances.chunks(MAX_INSTANCES) {
             unsafe {
                 gl.buffer_sub_data_u8_slice(
@@ -237,6 +220,39 @@ unsafe fn create_instance_buffer(
     );
     gl.vertex_attrib_divisor(5, 1);
 
+    gl.enable_vertex_attrib_array(6);
+    gl.vertex_attrib_pointer_f32(
+        6,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1),
+    );
+    gl.vertex_attrib_divisor(6, 1);
+
+    gl.enable_vertex_attrib_array(7);
+    gl.vertex_attrib_pointer_f32(
+        7,
+        2,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 4),
+    );
+    gl.vertex_attrib_divisor(7, 1);
+
+    gl.enable_vertex_attrib_array(8);
+    gl.vertex_attrib_pointer_f32(
+        8,
+        1,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 4 + 2),
+    );
+    gl.vertex_attrib_divisor(8, 1);
+
     gl.bind_vertex_array(None);
     gl.bind_buffer(glow::ARRAY_BUFFER, None);
 
@@ -0,0 +1,93 @@
+#ifdef GL_ES
+#ifdef GL_FRAGMENT_PRECISION_HIGH
+precision highp float;
+#else
+precision mediump float;
+#endif
+#endif
+
+// The maximum amount of samples taken on each side of a fragment
+#define MAX_SAMPLES 32
+
+uniform sampler2D tex;
+uniform vec2 u_Step;
+uniform float u_Sigma;
+uniform float u_Samples;
+uniform vec4 u_Bounds;
+uniform vec4 u_BorderRadius;
+uniform float u_Opacity;
+uniform float u_TargetHeight;
+uniform int u_Mode;
+in vec2 tex_pos;
+
+#ifdef HIGHER_THAN_300
+out vec4 fragColor;
+#define gl_FragColor fragColor
+#endif
+#ifdef GL_ES
+#define texture texture2D
+#endif
+
+// A one-dimensional gaussian blur along u_Step
+vec4 blur(vec2 uv)
+{
+    vec4 color = vec4(0.0);
+    float total = 0.0;
+
+    for(int i = -MAX_SAMPLES; i <= MAX_SAMPLES; i++) {
+        float offset = float(i);
+
+        if(abs(offset) > u_Samples) {
+            continue;
+        }
+
+        float weight = exp(-(offset * offset) / (2.0 * u_Sigma * u_Sigma));
+
+        color += texture(tex, uv + u_Step * offset) * weight;
+        total += weight;
+    }
+
+    return color / total;
+}
+
+float selectBorderRadius(vec4 radi, vec2 position, vec2 center)
+{
+    float rx = radi.x;
+    float ry = radi.y;
+    rx = position.x > center.x ? radi.y : radi.x;
+    ry = position.x > center.x ? radi.z : radi.w;
+    rx = position.y > center.y ? ry : rx;
+    return rx;
+}
+
+// The coverage of the fragment by the rounded region of the backdrop
+float mask()
+{
+    vec2 position = vec2(gl_FragCoord.x, u_TargetHeight - gl_FragCoord.y);
+
+    vec2 half_size = u_Bounds.zw * 0.5;
+    vec2 center = u_Bounds.xy + half_size;
+
+    float radius = min(
+        selectBorderRadius(u_BorderRadius, position, center),
+        min(half_size.x, half_size.y)
+    );
+
+    vec2 q = abs(position - center) - half_size + vec2(radius);
+    float distance = length(max(q, vec2(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+
+    return 1.0 - smoothstep(-0.5, 0.5, distance);
+}
+
+void main() {
+    if(u_Mode == 1) {
+        // Erase the backdrop
+        gl_FragColor = vec4(0.0, 0.0, 0.0, mask());
+    } else if(u_Mode == 2) {
+        // Draw the blurred backdrop
+        gl_FragColor = blur(tex_pos) * mask();
+    } else {
+        // The group is rendered with premultiplied alpha
+        gl_FragColor = blur(tex_pos) * u_Opacity;
+    }
+}
@@ -0,0 +1,28 @@
+#ifdef GL_ES
+#ifdef GL_FRAGMENT_PRECISION_HIGH
+precision highp float;
+#else
+precision mediump float;
+#endif
+#endif
+
+uniform sampler2D tex;
+uniform sampler2D u_Mask;
+uniform float u_Opacity;
+uniform float u_Masked;
+in vec2 tex_pos;
+
+#ifdef HIGHER_THAN_300
+out vec4 fragColor;
+#define gl_FragColor fragColor
+#endif
+#ifdef GL_ES
+#define texture texture2D
+#endif
+
+void main() {
+    float mask = mix(1.0, texture(u_Mask, tex_pos).a, u_Masked);
+
+    // The group is rendered with premultiplied alpha
+    gl_FragColor = texture(tex, tex_pos) * u_Opacity * mask;
+}
@@ -0,0 +1,7 @@
+in vec2 i_Position;
+out vec2 tex_pos;
+
+void main() {
+    gl_Position = vec4(i_Position * 2.0 - 1.0, 0.0, 1.0);
+    tex_pos = i_Position;
+}
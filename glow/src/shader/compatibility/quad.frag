@@ -6,14 +6,16 @@ precision mediump float;
 #endif
 #endif
 
-uniform float u_ScreenHeight;
-
 varying vec4 v_Color;
 varying vec4 v_BorderColor;
 varying vec2 v_Pos;
 varying vec2 v_Scale;
 varying vec4 v_BorderRadius;
 varying float v_BorderWidth;
+varying vec4 v_ShadowColor;
+varying vec2 v_ShadowOffset;
+varying float v_ShadowBlurRadius;
+varying vec2 v_FragPos;
 
 float _distance(vec2 frag_coord, vec2 position, vec2 size, float radius)
 {
@@ -43,8 +45,16 @@ float selectBorderRadius(vec4 radi, vec2 position, vec2 center)
     return rx;
 }
 
+// Signed distance from a point to a rounded box centered at the origin
+float roundedBoxSdf(vec2 to_center, vec2 half_size, float radius)
+{
+    vec2 q = abs(to_center) - half_size + vec2(radius);
+
+    return length(max(q, vec2(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+}
+
 void main() {
-    vec2 fragCoord = vec2(gl_FragCoord.x, u_ScreenHeight - gl_FragCoord.y);
+    vec2 fragCoord = v_FragPos;
 
     float border_radius = selectBorderRadius(
         v_BorderRadius,
@@ -79,5 +89,42 @@ void main() {
     float radius_alpha =
         1.0 - smoothstep(max(border_radius - 0.5, 0.0), border_radius + 0.5, d);
 
-    gl_FragColor = vec4(mixed_color.xyz, mixed_color.w * radius_alpha);
+    float quad_alpha = mixed_color.w * radius_alpha;
+
+    if(v_ShadowColor.a > 0.0) {
+        vec2 shadow_center = v_Pos + v_ShadowOffset + v_Scale * 0.5;
+
+        float shadow_radius = selectBorderRadius(
+            v_BorderRadius,
+            fragCoord,
+            shadow_center
+        );
+
+        float shadow_distance = roundedBoxSdf(
+            fragCoord - shadow_center,
+            v_Scale * 0.5,
+            shadow_radius
+        );
+
+        float shadow_edge = max(v_ShadowBlurRadius * 0.5, 0.5);
+
+        // The shadow is only visible outside of the quad
+        float shadow_alpha = v_ShadowColor.a
+            * (1.0 - smoothstep(-shadow_edge, shadow_edge, shadow_distance))
+            * (1.0 - radius_alpha);
+
+        float alpha = quad_alpha + shadow_alpha;
+
+        if(alpha > 0.0) {
+            vec3 color = (
+                mixed_color.xyz * quad_alpha + v_ShadowColor.xyz * shadow_alpha
+            ) / alpha;
+
+            gl_FragColor = vec4(color, alpha);
+        } else {
+            gl_FragColor = vec4(0.0);
+        }
+    } else {
+        gl_FragColor = vec4(mixed_color.xyz, quad_alpha);
+    }
 }
@@ -7,6 +7,9 @@ attribute vec4 i_Color;
 attribute vec4 i_BorderColor;
 attribute vec4 i_BorderRadius;
 attribute float i_BorderWidth;
+attribute vec4 i_ShadowColor;
+attribute vec2 i_ShadowOffset;
+attribute float i_ShadowBlurRadius;
 attribute vec2 q_Pos;
 
 varying vec4 v_Color;
@@ -15,6 +18,10 @@ varying vec2 v_Pos;
 varying vec2 v_Scale;
 varying vec4 v_BorderRadius;
 varying float v_BorderWidth;
+varying vec4 v_ShadowColor;
+varying vec2 v_ShadowOffset;
+varying float v_ShadowBlurRadius;
+varying vec2 v_FragPos;
 
 
 void main() {
@@ -28,11 +35,25 @@ void main() {
         min(i_BorderRadius.w, min(i_Scale.x, i_Scale.y) / 2.0)
     );
 
+    vec2 p_ShadowOffset = i_ShadowOffset * u_Scale;
+    float p_ShadowBlurRadius = i_ShadowBlurRadius * u_Scale;
+
+    // Grow the quad to make room for its shadow, if any
+    vec2 p_Min = p_Pos;
+    vec2 p_Max = p_Pos + p_Scale;
+
+    if(i_ShadowColor.a > 0.0) {
+        p_Min = min(p_Min, p_Pos + p_ShadowOffset - vec2(p_ShadowBlurRadius));
+        p_Max = max(p_Max, p_Pos + p_Scale + p_ShadowOffset + vec2(p_ShadowBlurRadius));
+    }
+
+    vec2 p_Size = p_Max - p_Min;
+
     mat4 i_Transform = mat4(
-        vec4(p_Scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4(0.0, p_Scale.y + 1.0, 0.0, 0.0),
+        vec4(p_Size.x + 1.0, 0.0, 0.0, 0.0),
+        vec4(0.0, p_Size.y + 1.0, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(p_Pos - vec2(0.5, 0.5), 0.0, 1.0)
+        vec4(p_Min - vec2(0.5, 0.5), 0.0, 1.0)
     );
 
     v_Color = i_Color;
@@ -41,6 +62,15 @@ void main() {
     v_Scale = p_Scale;
     v_BorderRadius = i_BorderRadius * u_Scale;
     v_BorderWidth = i_BorderWidth * u_Scale;
+    v_ShadowColor = i_ShadowColor;
+    v_ShadowOffset = p_ShadowOffset;
+    v_ShadowBlurRadius = p_ShadowBlurRadius;
+
+    // Keep track of the untransformed position, so the shape of the quad
+    // is computed in its own space
+    vec4 p_FragPos = i_Transform * vec4(q_Pos, 0.0, 1.0);
+
+    v_FragPos = p_FragPos.xy;
 
-    gl_Position = u_Transform * i_Transform * vec4(q_Pos, 0.0, 1.0);
+    gl_Position = u_Transform * p_FragPos;
 }
@@ -7,6 +7,9 @@ in vec4 i_Color;
 in vec4 i_BorderColor;
 in vec4 i_BorderRadius;
 in float i_BorderWidth;
+in vec4 i_ShadowColor;
+in vec2 i_ShadowOffset;
+in float i_ShadowBlurRadius;
 
 out vec4 v_Color;
 out vec4 v_BorderColor;
@@ -14,6 +17,10 @@ out vec2 v_Pos;
 out vec2 v_Scale;
 out vec4 v_BorderRadius;
 out float v_BorderWidth;
+out vec4 v_ShadowColor;
+out vec2 v_ShadowOffset;
+out float v_ShadowBlurRadius;
+out vec2 v_FragPos;
 
 vec2 positions[4] = vec2[](
     vec2(0.0, 0.0),
@@ -34,11 +41,25 @@ void main() {
         min(i_BorderRadius.w, min(i_Scale.x, i_Scale.y) / 2.0)
     );
 
+    vec2 p_ShadowOffset = i_ShadowOffset * u_Scale;
+    float p_ShadowBlurRadius = i_ShadowBlurRadius * u_Scale;
+
+    // Grow the quad to make room for its shadow, if any
+    vec2 p_Min = p_Pos;
+    vec2 p_Max = p_Pos + p_Scale;
+
+    if(i_ShadowColor.a > 0.0) {
+        p_Min = min(p_Min, p_Pos + p_ShadowOffset - vec2(p_ShadowBlurRadius));
+        p_Max = max(p_Max, p_Pos + p_Scale + p_ShadowOffset + vec2(p_ShadowBlurRadius));
+    }
+
+    vec2 p_Size = p_Max - p_Min;
+
     mat4 i_Transform = mat4(
-        vec4(p_Scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4(0.0, p_Scale.y + 1.0, 0.0, 0.0),
+        vec4(p_Size.x + 1.0, 0.0, 0.0, 0.0),
+        vec4(0.0, p_Size.y + 1.0, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(p_Pos - vec2(0.5, 0.5), 0.0, 1.0)
+        vec4(p_Min - vec2(0.5, 0.5), 0.0, 1.0)
     );
 
     v_Color = i_Color;
@@ -47,6 +68,15 @@ void main() {
     v_Scale = p_Scale;
     v_BorderRadius = i_BorderRadius * u_Scale;
     v_BorderWidth = i_BorderWidth * u_Scale;
+    v_ShadowColor = i_ShadowColor;
+    v_ShadowOffset = p_ShadowOffset;
+    v_ShadowBlurRadius = p_ShadowBlurRadius;
+
+    // Keep track of the untransformed position, so the shape of the quad
+    // is computed in its own space
+    vec4 p_FragPos = i_Transform * vec4(q_Pos, 0.0, 1.0);
+
+    v_FragPos = p_FragPos.xy;
 
-    gl_Position = u_Transform * i_Transform * vec4(q_Pos, 0.0, 1.0);
+    gl_Position = u_Transform * p_FragPos;
 }
@@ -88,6 +88,13 @@ impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
         }
     }
 
+    fn image_statistics(
+        &self,
+        renderer: &Self::Renderer,
+    ) -> iced_graphics::image::Statistics {
+        iced_graphics::Backend::image_statistics(renderer.backend())
+    }
+
     fn present<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -261,6 +261,7 @@ async fn run_instance<A, E, C>(
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut redraw_pending = false;
+    let mut control_flow = ControlFlow::Wait;
 
     debug.startup_finished();
 
@@ -365,6 +366,14 @@ async fn run_instance<A, E, C>(
                 );
                 debug.draw_finished();
 
+                if debug.is_inspecting() {
+                    debug.log_inspection(user_interface.inspect(
+                        &mut renderer,
+                        state.cursor_position(),
+                        debug.shows_outlines(),
+                    ));
+                }
+
                 if new_mouse_interaction != mouse_interaction {
                     context.window().set_cursor_icon(
                         conversion::mouse_interaction(new_mouse_interaction),
@@ -377,7 +386,7 @@ async fn run_instance<A, E, C>(
                 runtime
                     .broadcast((redraw_event, crate::event::Status::Ignored));
 
-                let _ = control_sender.start_send(match interface_state {
+                control_flow = match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
                     } => match redraw_request {
@@ -389,7 +398,9 @@ async fn run_instance<A, E, C>(
                         }
                     },
                     _ => ControlFlow::Wait,
-                });
+                };
+
+                let _ = control_sender.start_send(control_flow);
 
                 redraw_pending = false;
             }
@@ -445,6 +456,14 @@ async fn run_instance<A, E, C>(
                     );
                     debug.draw_finished();
 
+                    if debug.is_inspecting() {
+                        debug.log_inspection(user_interface.inspect(
+                            &mut renderer,
+                            state.cursor_position(),
+                            debug.shows_outlines(),
+                        ));
+                    }
+
                     if new_mouse_interaction != mouse_interaction {
                         context.window().set_cursor_icon(
                             conversion::mouse_interaction(
@@ -465,6 +484,10 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
+                debug.log_image_statistics(
+                    compositor.image_statistics(&renderer),
+                );
+
                 compositor.present(
                     &mut renderer,
                     state.viewport(),
@@ -476,6 +499,12 @@ async fn run_instance<A, E, C>(
 
                 debug.render_finished();
 
+                let _ =
+                    control_sender.start_send(application::wait_for_images(
+                        control_flow,
+                        compositor.image_statistics(&renderer),
+                    ));
+
                 // TODO: Handle animations!
                 // Maybe we can use `ControlFlow::WaitUntil` for this.
             }
@@ -491,6 +520,12 @@ async fn run_instance<A, E, C>(
 
                 state.update(context.window(), &window_event, &mut debug);
 
+                if let glutin::event::WindowEvent::ThemeChanged(_) =
+                    &window_event
+                {
+                    state.synchronize(&application, context.window());
+                }
+
                 if let Some(event) = conversion::window_event(
                     &window_event,
                     state.scale_factor(),
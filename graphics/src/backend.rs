@@ -14,6 +14,13 @@ pub trait Backend {
     /// `iced_wgpu` and `iced_glow` because of limitations in the text rendering
     /// pipeline. It will be removed in the future.
     fn trim_measurements(&mut self) {}
+
+    /// Returns the [`image::Statistics`] of the image caches of the backend.
+    ///
+    /// By default, it returns empty statistics.
+    fn image_statistics(&self) -> image::Statistics {
+        image::Statistics::default()
+    }
 }
 
 /// A graphics backend that supports text rendering.
@@ -67,6 +74,9 @@ pub trait Text {
 pub trait Image {
     /// Returns the dimensions of the provided image.
     fn dimensions(&self, handle: &image::Handle) -> Size<u32>;
+
+    /// Returns the loading status of the provided image.
+    fn status(&self, handle: &image::Handle) -> image::Status;
 }
 
 /// A graphics backend that supports SVG rendering.
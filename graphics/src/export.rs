@@ -0,0 +1,881 @@
+//! Export primitives to vector documents.
+//!
+//! The exporter walks a tree of [`Primitive`] and emits an equivalent SVG
+//! document, which can be printed or converted to other formats by external
+//! tools.
+//!
+//! SVG is the only output format. There is no PDF backend; convert the SVG
+//! document with a tool like `rsvg-convert` when a PDF is needed.
+//!
+//! Some primitives have no exact counterpart in SVG:
+//!
+//! - Meshes are filled with the color of the first vertex of each triangle.
+//! - Backdrop blurs only draw their content.
+//! - Custom primitives are skipped.
+use crate::alignment;
+use crate::backend::Backend;
+use crate::gradient::Gradient;
+use crate::triangle;
+use crate::{
+    Background, Color, Font, Padding, Point, Primitive, Rectangle, Renderer,
+    Size,
+};
+
+use iced_native::image;
+use iced_native::layout::{self, Layout};
+use iced_native::renderer;
+use iced_native::svg;
+use iced_native::widget::Tree;
+use iced_native::{Element, Shadow};
+
+use std::mem;
+
+/// Produces an SVG document of the given size that draws the given
+/// primitives.
+pub fn to_svg(primitives: &[Primitive], size: Size) -> String {
+    let mut document = Document::default();
+
+    for primitive in primitives {
+        document.draw(primitive);
+    }
+
+    document.finish(size)
+}
+
+/// Lays out the given [`Element`] with the given size, draws it and produces
+/// an SVG document of the result.
+///
+/// The [`Renderer`] is used to measure text and images; any primitives it
+/// had recorded are discarded.
+pub fn element_to_svg<B, T, Message>(
+    renderer: &mut Renderer<B, T>,
+    element: &Element<'_, Message, Renderer<B, T>>,
+    theme: &T,
+    style: &renderer::Style,
+    size: Size,
+) -> String
+where
+    B: Backend,
+{
+    use iced_native::Renderer as _;
+
+    let tree = Tree::new(element);
+    let node = renderer.layout(element, &layout::Limits::new(Size::ZERO, size));
+
+    renderer.clear();
+
+    element.as_widget().draw(
+        &tree,
+        renderer,
+        theme,
+        style,
+        Layout::new(&node),
+        Point::new(-1.0, -1.0),
+        &Rectangle::with_size(size),
+    );
+
+    let mut document = String::new();
+
+    renderer.with_primitives(|_, primitives| {
+        document = to_svg(primitives, size);
+    });
+
+    renderer.clear();
+
+    document
+}
+
+#[derive(Debug, Default)]
+struct Document {
+    definitions: String,
+    body: String,
+    ids: usize,
+}
+
+impl Document {
+    fn draw(&mut self, primitive: &Primitive) {
+        match primitive {
+            Primitive::None | Primitive::Custom { .. } => {}
+            Primitive::Group { primitives } => {
+                for primitive in primitives {
+                    self.draw(primitive);
+                }
+            }
+            Primitive::Text {
+                content,
+                bounds,
+                color,
+                size,
+                font,
+                horizontal_alignment,
+                vertical_alignment,
+            } => {
+                self.text(
+                    content,
+                    *bounds,
+                    *color,
+                    *size,
+                    *font,
+                    *horizontal_alignment,
+                    *vertical_alignment,
+                );
+            }
+            Primitive::Quad {
+                bounds,
+                background,
+                border_radius,
+                border_width,
+                border_color,
+                shadow,
+            } => {
+                self.quad(
+                    *bounds,
+                    *background,
+                    *border_radius,
+                    *border_width,
+                    *border_color,
+                    *shadow,
+                );
+            }
+            Primitive::Image {
+                handle,
+                filter_method,
+                opacity,
+                rotation,
+                bounds,
+            } => {
+                if let Some(href) = image_href(handle) {
+                    let mut attributes = String::new();
+
+                    if *filter_method == image::FilterMethod::Nearest {
+                        attributes.push_str(r#"image-rendering="pixelated" "#);
+                    }
+
+                    if *opacity < 1.0 {
+                        attributes.push_str(&format!(
+                            r#"opacity="{}" "#,
+                            opacity.max(0.0)
+                        ));
+                    }
+
+                    if *rotation != 0.0 {
+                        let center = bounds.center();
+
+                        attributes.push_str(&format!(
+                            r#"transform="rotate({} {} {})" "#,
+                            rotation.to_degrees(),
+                            center.x,
+                            center.y
+                        ));
+                    }
+
+                    self.image(&href, *bounds, None, &attributes);
+                }
+            }
+            Primitive::NineSlice {
+                handle,
+                insets,
+                bounds,
+            } => {
+                if let Some(href) = image_href(handle) {
+                    match image_size(handle) {
+                        Some(size) => {
+                            self.nine_slice(&href, size, *insets, *bounds);
+                        }
+                        None => self.image(&href, *bounds, None, ""),
+                    }
+                }
+            }
+            Primitive::Svg {
+                handle,
+                color,
+                stylesheet,
+                bounds,
+            } => {
+                self.image(
+                    &svg_href(handle, stylesheet.as_ref()),
+                    *bounds,
+                    *color,
+                    "",
+                );
+            }
+            Primitive::Clip { bounds, content } => {
+                let id = self.clip_path(*bounds);
+
+                self.group(&format!(r#"clip-path="url(#{id})""#), content);
+            }
+            Primitive::Translate {
+                translation,
+                content,
+            } => {
+                self.group(
+                    &format!(
+                        r#"transform="translate({} {})""#,
+                        translation.x, translation.y
+                    ),
+                    content,
+                );
+            }
+            Primitive::Transform {
+                transformation,
+                content,
+            } => {
+                let matrix = transformation.as_ref();
+
+                self.group(
+                    &format!(
+                        r#"transform="matrix({} {} {} {} {} {})""#,
+                        matrix[0],
+                        matrix[1],
+                        matrix[4],
+                        matrix[5],
+                        matrix[12],
+                        matrix[13]
+                    ),
+                    content,
+                );
+            }
+            Primitive::Opacity { opacity, content } => {
+                self.group(
+                    &format!(r#"opacity="{}""#, opacity.clamp(0.0, 1.0)),
+                    content,
+                );
+            }
+            Primitive::Mask {
+                bounds,
+                mask,
+                content,
+            } => {
+                let id = self.id("mask");
+
+                let body = mem::take(&mut self.body);
+                self.draw(mask);
+                let mask = mem::replace(&mut self.body, body);
+
+                self.definitions.push_str(&format!(
+                    r#"<mask id="{id}" mask-type="alpha" maskUnits="userSpaceOnUse" {}>{mask}</mask>"#,
+                    region(*bounds)
+                ));
+
+                self.group(&format!(r#"mask="url(#{id})""#), content);
+            }
+            Primitive::Blur {
+                bounds,
+                radius,
+                content,
+            } => {
+                let id = self.id("blur");
+
+                // The blur radius covers two standard deviations
+                self.definitions.push_str(&format!(
+                    r#"<filter id="{id}" filterUnits="userSpaceOnUse" {}><feGaussianBlur stdDeviation="{}"/></filter>"#,
+                    region(*bounds),
+                    radius.max(0.0) / 2.0
+                ));
+
+                self.group(&format!(r#"filter="url(#{id})""#), content);
+            }
+            Primitive::BackdropBlur { content, .. } => {
+                // SVG cannot blur what is drawn behind an element
+                self.draw(content);
+            }
+            Primitive::SolidMesh { buffers, size } => {
+                self.solid_mesh(buffers, *size);
+            }
+            Primitive::GradientMesh {
+                buffers,
+                size,
+                gradient,
+            } => {
+                self.gradient_mesh(buffers, *size, gradient);
+            }
+            Primitive::Cached { cache } => {
+                self.draw(cache);
+            }
+        }
+    }
+
+    fn id(&mut self, prefix: &str) -> String {
+        self.ids += 1;
+
+        format!("{prefix}{}", self.ids)
+    }
+
+    fn group(&mut self, attributes: &str, content: &Primitive) {
+        self.body.push_str(&format!("<g {attributes}>"));
+        self.draw(content);
+        self.body.push_str("</g>");
+    }
+
+    fn clip_path(&mut self, bounds: Rectangle) -> String {
+        let id = self.id("clip");
+
+        self.definitions.push_str(&format!(
+            r#"<clipPath id="{id}"><rect {}/></clipPath>"#,
+            region(bounds)
+        ));
+
+        id
+    }
+
+    fn text(
+        &mut self,
+        content: &str,
+        bounds: Rectangle,
+        color: Color,
+        size: f32,
+        font: Font,
+        horizontal_alignment: alignment::Horizontal,
+        vertical_alignment: alignment::Vertical,
+    ) {
+        let anchor = match horizontal_alignment {
+            alignment::Horizontal::Left => "start",
+            alignment::Horizontal::Center => "middle",
+            alignment::Horizontal::Right => "end",
+        };
+
+        let lines: Vec<&str> = content.lines().collect();
+        let extra_lines = lines.len().saturating_sub(1) as f32;
+
+        let (baseline, offset) = match vertical_alignment {
+            alignment::Vertical::Top => ("text-before-edge", 0.0),
+            alignment::Vertical::Center => {
+                ("central", -extra_lines * size / 2.0)
+            }
+            alignment::Vertical::Bottom => {
+                ("text-after-edge", -extra_lines * size)
+            }
+        };
+
+        let family = match font {
+            Font::Default => String::from("sans-serif"),
+            Font::External { name, .. } => {
+                format!("{}, sans-serif", escape(name))
+            }
+        };
+
+        self.body.push_str(&format!(
+            r#"<text font-size="{size}" font-family="{family}" text-anchor="{anchor}" dominant-baseline="{baseline}" xml:space="preserve" {}>"#,
+            paint("fill", color)
+        ));
+
+        for (i, line) in lines.iter().enumerate() {
+            self.body.push_str(&format!(
+                r#"<tspan x="{}" y="{}">{}</tspan>"#,
+                bounds.x,
+                bounds.y + offset + i as f32 * size,
+                escape(line)
+            ));
+        }
+
+        self.body.push_str("</text>");
+    }
+
+    fn quad(
+        &mut self,
+        bounds: Rectangle,
+        background: Background,
+        border_radius: [f32; 4],
+        border_width: f32,
+        border_color: Color,
+        shadow: Shadow,
+    ) {
+        if shadow.is_visible() {
+            let filter = if shadow.blur_radius > 0.0 {
+                let id = self.id("shadow");
+
+                self.definitions.push_str(&format!(
+                    r#"<filter id="{id}" x="-50%" y="-50%" width="200%" height="200%"><feGaussianBlur stdDeviation="{}"/></filter>"#,
+                    shadow.blur_radius / 2.0
+                ));
+
+                format!(r#"filter="url(#{id})""#)
+            } else {
+                String::new()
+            };
+
+            self.body.push_str(&rounded_rectangle(
+                bounds + shadow.offset,
+                border_radius,
+                &format!("{} {filter}", paint("fill", shadow.color)),
+            ));
+        }
+
+        let Background::Color(color) = background;
+
+        self.body.push_str(&rounded_rectangle(
+            bounds,
+            border_radius,
+            &paint("fill", color),
+        ));
+
+        if border_width > 0.0 && border_color.a > 0.0 {
+            // Borders are drawn inside the bounds, while SVG strokes are
+            // centered on the outline
+            let inset = border_width / 2.0;
+
+            self.body.push_str(&rounded_rectangle(
+                Rectangle {
+                    x: bounds.x + inset,
+                    y: bounds.y + inset,
+                    width: (bounds.width - border_width).max(0.0),
+                    height: (bounds.height - border_width).max(0.0),
+                },
+                border_radius.map(|radius| (radius - inset).max(0.0)),
+                &format!(
+                    r#"fill="none" stroke-width="{border_width}" {}"#,
+                    paint("stroke", border_color)
+                ),
+            ));
+        }
+    }
+
+    fn image(
+        &mut self,
+        href: &str,
+        bounds: Rectangle,
+        color: Option<Color>,
+        attributes: &str,
+    ) {
+        let filter = if let Some(color) = color {
+            let id = self.id("color");
+
+            self.definitions.push_str(&format!(
+                r#"<filter id="{id}"><feFlood {}/><feComposite in2="SourceGraphic" operator="in"/></filter>"#,
+                paint("flood-color", color)
+            ));
+
+            format!(r#"filter="url(#{id})""#)
+        } else {
+            String::new()
+        };
+
+        self.body.push_str(&format!(
+            r#"<image {} preserveAspectRatio="none" href="{}" {attributes}{filter}/>"#,
+            region(bounds),
+            escape(href)
+        ));
+    }
+
+    fn nine_slice(
+        &mut self,
+        href: &str,
+        size: Size<u32>,
+        insets: Padding,
+        bounds: Rectangle,
+    ) {
+        let id = self.id("image");
+
+        self.definitions.push_str(&format!(
+            r#"<image id="{id}" width="{}" height="{}" preserveAspectRatio="none" href="{}"/>"#,
+            size.width,
+            size.height,
+            escape(href)
+        ));
+
+        for (source, destination) in
+            crate::image::nine_slice(size, insets, bounds)
+        {
+            self.body.push_str(&format!(
+                r##"<svg {} viewBox="{} {} {} {}" preserveAspectRatio="none"><use href="#{id}"/></svg>"##,
+                region(destination),
+                source.x,
+                source.y,
+                source.width,
+                source.height
+            ));
+        }
+    }
+
+    fn solid_mesh(
+        &mut self,
+        mesh: &triangle::Mesh2D<triangle::ColoredVertex2D>,
+        size: Size,
+    ) {
+        // Meshes are clipped to their drawable region
+        let id = self.clip_path(Rectangle::with_size(size));
+        self.body
+            .push_str(&format!(r#"<g clip-path="url(#{id})">"#));
+
+        // Consecutive triangles with the same color are merged in a single
+        // path, so no seams appear between them
+        let mut current: Option<([f32; 4], String)> = None;
+
+        for triangle in mesh.indices.chunks_exact(3) {
+            let vertices = [
+                mesh.vertices[triangle[0] as usize],
+                mesh.vertices[triangle[1] as usize],
+                mesh.vertices[triangle[2] as usize],
+            ];
+
+            let color = vertices[0].color;
+            let outline = outline(vertices.map(|vertex| vertex.position));
+
+            match &mut current {
+                Some((current_color, data)) if *current_color == color => {
+                    data.push_str(&outline);
+                }
+                _ => {
+                    if let Some((color, data)) = current.take() {
+                        self.path(&data, &paint("fill", from_linear(color)));
+                    }
+
+                    current = Some((color, outline));
+                }
+            }
+        }
+
+        if let Some((color, data)) = current {
+            self.path(&data, &paint("fill", from_linear(color)));
+        }
+
+        self.body.push_str("</g>");
+    }
+
+    fn gradient_mesh(
+        &mut self,
+        mesh: &triangle::Mesh2D<triangle::Vertex2D>,
+        size: Size,
+        gradient: &Gradient,
+    ) {
+        let Gradient::Linear(linear) = gradient;
+
+        let id = self.id("gradient");
+
+        self.definitions.push_str(&format!(
+            r#"<linearGradient id="{id}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}">"#,
+            linear.start.x, linear.start.y, linear.end.x, linear.end.y
+        ));
+
+        for stop in &linear.color_stops {
+            self.definitions.push_str(&format!(
+                r#"<stop offset="{}" {}/>"#,
+                stop.offset,
+                paint("stop-color", stop.color)
+            ));
+        }
+
+        self.definitions.push_str("</linearGradient>");
+
+        let data: String = mesh
+            .indices
+            .chunks_exact(3)
+            .map(|triangle| {
+                outline([
+                    mesh.vertices[triangle[0] as usize].position,
+                    mesh.vertices[triangle[1] as usize].position,
+                    mesh.vertices[triangle[2] as usize].position,
+                ])
+            })
+            .collect();
+
+        let clip = self.clip_path(Rectangle::with_size(size));
+
+        self.body
+            .push_str(&format!(r#"<g clip-path="url(#{clip})">"#));
+        self.path(&data, &format!(r#"fill="url(#{id})""#));
+        self.body.push_str("</g>");
+    }
+
+    fn path(&mut self, data: &str, attributes: &str) {
+        self.body
+            .push_str(&format!(r#"<path d="{data}" {attributes}/>"#));
+    }
+
+    fn finish(self, size: Size) -> String {
+        let mut document = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#,
+            width = size.width,
+            height = size.height,
+        );
+
+        if !self.definitions.is_empty() {
+            document.push_str(&format!("<defs>{}</defs>", self.definitions));
+        }
+
+        document.push_str(&self.body);
+        document.push_str("</svg>\n");
+
+        document
+    }
+}
+
+fn region(bounds: Rectangle) -> String {
+    format!(
+        r#"x="{}" y="{}" width="{}" height="{}""#,
+        bounds.x, bounds.y, bounds.width, bounds.height
+    )
+}
+
+fn paint(attribute: &str, color: Color) -> String {
+    let hex = Color { a: 1.0, ..color }.into_hex();
+
+    if color.a < 1.0 {
+        // `flood-color` and `stop-color` pair with `flood-opacity` and
+        // `stop-opacity`
+        let opacity = attribute.trim_end_matches("-color");
+
+        format!(
+            r#"{attribute}="{hex}" {opacity}-opacity="{}""#,
+            color.a.max(0.0)
+        )
+    } else {
+        format!(r#"{attribute}="{hex}""#)
+    }
+}
+
+fn outline([a, b, c]: [[f32; 2]; 3]) -> String {
+    format!("M{} {}L{} {}L{} {}Z", a[0], a[1], b[0], b[1], c[0], c[1])
+}
+
+fn rounded_rectangle(
+    bounds: Rectangle,
+    border_radius: [f32; 4],
+    attributes: &str,
+) -> String {
+    let max_radius = bounds.width.min(bounds.height) / 2.0;
+    let [top_left, top_right, bottom_right, bottom_left] =
+        border_radius.map(|radius| radius.clamp(0.0, max_radius));
+
+    if top_left == top_right
+        && top_left == bottom_right
+        && top_left == bottom_left
+    {
+        return format!(
+            r#"<rect {} rx="{top_left}" {attributes}/>"#,
+            region(bounds)
+        );
+    }
+
+    let Rectangle {
+        x,
+        y,
+        width,
+        height,
+    } = bounds;
+
+    format!(
+        r#"<path d="M{} {y}H{}A{top_right} {top_right} 0 0 1 {} {}V{}A{bottom_right} {bottom_right} 0 0 1 {} {}H{}A{bottom_left} {bottom_left} 0 0 1 {x} {}V{}A{top_left} {top_left} 0 0 1 {} {y}Z" {attributes}/>"#,
+        x + top_left,
+        x + width - top_right,
+        x + width,
+        y + top_right,
+        y + height - bottom_right,
+        x + width - bottom_right,
+        y + height,
+        x + bottom_left,
+        y + height - bottom_left,
+        y + top_left,
+        x + top_left,
+    )
+}
+
+fn from_linear([r, g, b, a]: [f32; 4]) -> Color {
+    // As described in:
+    // https://en.wikipedia.org/wiki/SRGB#The_forward_transformation_(CIE_XYZ_to_sRGB)
+    fn gamma_component(u: f32) -> f32 {
+        if u < 0.0031308 {
+            u * 12.92
+        } else {
+            1.055 * u.powf(1.0 / 2.4) - 0.055
+        }
+    }
+
+    Color::new(
+        gamma_component(r),
+        gamma_component(g),
+        gamma_component(b),
+        a,
+    )
+}
+
+fn image_href(handle: &image::Handle) -> Option<String> {
+    match handle.data() {
+        image::Data::Path(path) => Some(path.to_string_lossy().into_owned()),
+        image::Data::Bytes(bytes) => {
+            Some(data_url(image_mime_type(bytes), bytes))
+        }
+        #[cfg(feature = "png")]
+        image::Data::Rgba {
+            width,
+            height,
+            pixels,
+        } => {
+            let buffer = image_rs::RgbaImage::from_raw(
+                *width,
+                *height,
+                pixels.to_vec(),
+            )?;
+
+            let mut png = std::io::Cursor::new(Vec::new());
+
+            buffer
+                .write_to(&mut png, image_rs::ImageOutputFormat::Png)
+                .ok()?;
+
+            Some(data_url("image/png", png.get_ref()))
+        }
+        #[cfg(not(feature = "png"))]
+        image::Data::Rgba { .. } => {
+            log::warn!("Exporting decoded images requires the `png` feature");
+
+            None
+        }
+    }
+}
+
+fn image_size(handle: &image::Handle) -> Option<Size<u32>> {
+    match handle.data() {
+        image::Data::Rgba { width, height, .. } => {
+            Some(Size::new(*width, *height))
+        }
+        #[cfg(feature = "image_rs")]
+        image::Data::Path(path) => image_rs::image_dimensions(path)
+            .ok()
+            .map(|(width, height)| Size::new(width, height)),
+        #[cfg(feature = "image_rs")]
+        image::Data::Bytes(bytes) => {
+            image_rs::io::Reader::new(std::io::Cursor::new(&bytes[..]))
+                .with_guessed_format()
+                .ok()?
+                .into_dimensions()
+                .ok()
+                .map(|(width, height)| Size::new(width, height))
+        }
+        #[cfg(not(feature = "image_rs"))]
+        _ => None,
+    }
+}
+
+fn svg_href(
+    handle: &svg::Handle,
+    stylesheet: Option<&svg::Stylesheet>,
+) -> String {
+    let styled = |bytes: &[u8]| {
+        let document = std::str::from_utf8(bytes).ok()?;
+        let styled = stylesheet?.apply(document);
+
+        Some(data_url("image/svg+xml", styled.as_bytes()))
+    };
+
+    match handle.data() {
+        svg::Data::Path(path) => std::fs::read(path)
+            .ok()
+            .and_then(|bytes| styled(&bytes))
+            .unwrap_or_else(|| path.to_string_lossy().into_owned()),
+        svg::Data::Bytes(bytes) => {
+            styled(bytes).unwrap_or_else(|| data_url("image/svg+xml", bytes))
+        }
+    }
+}
+
+fn image_mime_type(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(b"\x89PNG") {
+        "image/png"
+    } else if bytes.starts_with(b"\xFF\xD8") {
+        "image/jpeg"
+    } else if bytes.starts_with(b"GIF8") {
+        "image/gif"
+    } else if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+        "image/webp"
+    } else if bytes.starts_with(b"BM") {
+        "image/bmp"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+fn data_url(mime_type: &str, bytes: &[u8]) -> String {
+    format!("data:{mime_type};base64,{}", base64(bytes))
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+            group | u32::from(*byte) << (16 - 8 * i)
+        });
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (group >> (18 - 6 * i)) & 0x3F;
+                encoded.push(ALPHABET[index as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn exports_quads_and_text() {
+        let document = to_svg(
+            &[
+                Primitive::Quad {
+                    bounds: Rectangle::new(
+                        Point::new(10.0, 20.0),
+                        Size::new(30.0, 40.0),
+                    ),
+                    background: Background::Color(Color::BLACK),
+                    border_radius: [5.0; 4],
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
+                },
+                Primitive::Text {
+                    content: String::from("a < b"),
+                    bounds: Rectangle::new(Point::ORIGIN, Size::new(1.0, 1.0)),
+                    color: Color::from_rgba(1.0, 0.0, 0.0, 0.5),
+                    size: 16.0,
+                    font: Font::Default,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                },
+            ],
+            Size::new(100.0, 100.0),
+        );
+
+        assert!(document.starts_with("<svg"));
+        assert!(document.contains(
+            r##"<rect x="10" y="20" width="30" height="40" rx="5" fill="#000000"/>"##
+        ));
+        assert!(document.contains(r##"fill="#ff0000" fill-opacity="0.5""##));
+        assert!(document.contains("a &lt; b"));
+    }
+}
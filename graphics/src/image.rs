@@ -7,4 +7,214 @@ pub mod vector;
 
 pub mod storage;
 
+pub use iced_native::image::Statistics;
 pub use storage::Storage;
+
+use crate::{Padding, Rectangle, Size};
+
+/// The memory budget of an image cache.
+///
+/// Images that were not drawn in the last frame stay cached until the cache
+/// exceeds its budget, in which case the least recently used ones are evicted
+/// first. Images drawn in the current frame are never evicted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    /// The maximum amount of bytes of decoded image data kept in host memory.
+    ///
+    /// By default, it is 128 MiB.
+    pub host: usize,
+
+    /// The maximum amount of bytes of image data kept in device memory.
+    ///
+    /// By default, it is 256 MiB.
+    pub device: usize,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self {
+            host: 128 * 1024 * 1024,
+            device: 256 * 1024 * 1024,
+        }
+    }
+}
+
+/// Returns the keys of the least recently used entries that need to be
+/// evicted for the given entries to fit in the `budget`.
+///
+/// Each entry is a key, its size in bytes and the last frame it was used in.
+/// Entries used in the current `frame` are never evicted.
+#[cfg(any(feature = "image_rs", feature = "svg"))]
+pub(crate) fn overflow<K>(
+    entries: impl IntoIterator<Item = (K, usize, u64)>,
+    budget: usize,
+    frame: u64,
+) -> Vec<K> {
+    let mut total = 0;
+    let mut candidates = Vec::new();
+
+    for (key, bytes, last_used) in entries {
+        total += bytes;
+
+        if last_used != frame {
+            candidates.push((key, bytes, last_used));
+        }
+    }
+
+    if total <= budget {
+        return Vec::new();
+    }
+
+    candidates.sort_by_key(|(_, _, last_used)| *last_used);
+
+    let mut evicted = Vec::new();
+
+    for (key, bytes, _) in candidates {
+        if total <= budget {
+            break;
+        }
+
+        total -= bytes;
+        evicted.push(key);
+    }
+
+    evicted
+}
+
+/// Splits the `bounds` of an image of the given size in nine slices,
+/// delimited by the given `insets` in pixels.
+///
+/// Returns the region of the image, in pixels, and the destination of each
+/// non-empty slice. The corners keep their size unless the `bounds` are too
+/// small to fit them, in which case they shrink proportionally.
+pub fn nine_slice(
+    size: Size<u32>,
+    insets: Padding,
+    bounds: Rectangle,
+) -> Vec<(Rectangle, Rectangle)> {
+    let width = size.width as f32;
+    let height = size.height as f32;
+
+    let left = insets.left.clamp(0.0, width);
+    let right = insets.right.clamp(0.0, width - left);
+    let top = insets.top.clamp(0.0, height);
+    let bottom = insets.bottom.clamp(0.0, height - top);
+
+    let scale_x = fit(left + right, bounds.width);
+    let scale_y = fit(top + bottom, bounds.height);
+
+    let source_x = [0.0, left, width - right, width];
+    let source_y = [0.0, top, height - bottom, height];
+
+    let destination_x = [
+        bounds.x,
+        bounds.x + left * scale_x,
+        bounds.x + bounds.width - right * scale_x,
+        bounds.x + bounds.width,
+    ];
+
+    let destination_y = [
+        bounds.y,
+        bounds.y + top * scale_y,
+        bounds.y + bounds.height - bottom * scale_y,
+        bounds.y + bounds.height,
+    ];
+
+    let region =
+        |xs: &[f32; 4], ys: &[f32; 4], column: usize, row: usize| Rectangle {
+            x: xs[column],
+            y: ys[row],
+            width: xs[column + 1] - xs[column],
+            height: ys[row + 1] - ys[row],
+        };
+
+    let mut slices = Vec::with_capacity(9);
+
+    for row in 0..3 {
+        for column in 0..3 {
+            let source = region(&source_x, &source_y, column, row);
+            let destination =
+                region(&destination_x, &destination_y, column, row);
+
+            if source.width > 0.0
+                && source.height > 0.0
+                && destination.width > 0.0
+                && destination.height > 0.0
+            {
+                slices.push((source, destination));
+            }
+        }
+    }
+
+    slices
+}
+
+fn fit(insets: f32, available: f32) -> f32 {
+    if insets > available && insets > 0.0 {
+        available.max(0.0) / insets
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    #[test]
+    #[cfg(any(feature = "image_rs", feature = "svg"))]
+    fn overflow_evicts_least_recently_used() {
+        let entries = [("a", 10, 3), ("b", 10, 1), ("c", 10, 2), ("d", 10, 4)];
+
+        assert_eq!(overflow(entries, 40, 4), Vec::<&str>::new());
+        assert_eq!(overflow(entries, 25, 4), vec!["b", "c"]);
+        assert_eq!(overflow(entries, 0, 4), vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn nine_slice_keeps_corners() {
+        let slices = nine_slice(
+            Size::new(30, 30),
+            Padding::new(10.0),
+            Rectangle::new(Point::ORIGIN, Size::new(100.0, 50.0)),
+        );
+
+        assert_eq!(slices.len(), 9);
+
+        let (source, destination) = slices[0];
+        assert_eq!(
+            source,
+            Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0))
+        );
+        assert_eq!(source, destination);
+
+        let (source, destination) = slices[4];
+        assert_eq!(
+            source,
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(10.0, 10.0))
+        );
+        assert_eq!(
+            destination,
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(80.0, 30.0))
+        );
+    }
+
+    #[test]
+    fn nine_slice_shrinks_corners_to_fit() {
+        let slices = nine_slice(
+            Size::new(30, 30),
+            Padding::new(10.0),
+            Rectangle::new(Point::ORIGIN, Size::new(10.0, 40.0)),
+        );
+
+        // The center column collapses
+        assert_eq!(slices.len(), 6);
+
+        let (_, destination) = slices[0];
+        assert_eq!(
+            destination,
+            Rectangle::new(Point::ORIGIN, Size::new(5.0, 10.0))
+        );
+    }
+}
@@ -1,11 +1,22 @@
 //! Raster image loading and caching.
-use crate::image::Storage;
+mod decoder;
+
+use crate::image::storage::{self, Entry as _};
+use crate::image::{overflow, Budget, Statistics, Storage};
 use crate::Size;
 
+use iced_native::futures::future;
 use iced_native::image;
+use iced_native::Command;
 
 use bitflags::bitflags;
-use std::collections::{HashMap, HashSet};
+#[cfg(any(feature = "gif", feature = "webp", feature = "png"))]
+use image_rs::AnimationDecoder;
+use std::borrow::Cow;
+use std::collections::{hash_map, HashMap};
+use std::mem;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Entry in cache corresponding to an image handle
 #[derive(Debug)]
@@ -14,17 +25,15 @@ pub enum Memory<T: Storage> {
     Host(::image_rs::ImageBuffer<::image_rs::Rgba<u8>, Vec<u8>>),
     /// Storage entry
     Device(T::Entry),
-    /// Image not found
-    NotFound,
-    /// Invalid image data
-    Invalid,
+    /// Image being decoded in the background
+    Loading,
+    /// Image that could not be loaded
+    Error(image::Error),
 }
 
 impl<T: Storage> Memory<T> {
     /// Width and height of image
     pub fn dimensions(&self) -> Size<u32> {
-        use crate::image::storage::Entry;
-
         match self {
             Memory::Host(image) => {
                 let (width, height) = image.dimensions();
@@ -32,142 +41,510 @@ impl<T: Storage> Memory<T> {
                 Size::new(width, height)
             }
             Memory::Device(entry) => entry.size(),
-            Memory::NotFound => Size::new(1, 1),
-            Memory::Invalid => Size::new(1, 1),
+            Memory::Loading => Size::new(1, 1),
+            Memory::Error(_) => Size::new(1, 1),
+        }
+    }
+
+    /// Loading status of image
+    pub fn status(&self) -> image::Status {
+        match self {
+            Memory::Host(_) | Memory::Device(_) => {
+                image::Status::Loaded(self.dimensions())
+            }
+            Memory::Loading => image::Status::Loading,
+            Memory::Error(error) => image::Status::Failed(error.clone()),
         }
     }
+
+    fn new(handle: &image::Handle) -> Self {
+        match handle.data() {
+            // Raw pixels are cheap to load, so we skip the decoder
+            image::Data::Rgba { .. } => match decode(handle) {
+                Ok(image) => Memory::Host(image),
+                Err(error) => Memory::Error(error),
+            },
+            _ => Memory::Loading,
+        }
+    }
+
+    fn is_loaded(&self) -> bool {
+        matches!(self, Memory::Host(_) | Memory::Device(_))
+    }
 }
 
 /// Caches image raster data
+///
+/// Images are decoded in the background. Until an image is ready, its
+/// [`Memory`] stays [`Memory::Loading`].
+///
+/// Loaded images are kept across frames within the [`Budget`] of the cache,
+/// evicting the least recently used ones first.
 #[derive(Debug)]
 pub struct Cache<T: Storage> {
-    map: HashMap<u64, Memory<T>>,
-    hits: HashSet<u64>,
+    map: HashMap<image::Id, Cached<T>>,
+    budget: Budget,
+    frame: u64,
+    hits: u64,
+    misses: u64,
 }
 
 impl<T: Storage> Cache<T> {
+    /// Creates a new [`Cache`] with the given [`Budget`].
+    pub fn new(budget: Budget) -> Self {
+        Self {
+            map: HashMap::new(),
+            budget,
+            frame: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
     /// Load image
+    ///
+    /// If the pixels of the handle were updated, the new version is loaded
+    /// and the [`Storage`] entry of the old one is kept to be reused.
     pub fn load(&mut self, handle: &image::Handle) -> &mut Memory<T> {
-        if self.contains(handle) {
-            return self.get(handle).unwrap();
-        }
-
-        let memory = match handle.data() {
-            image::Data::Path(path) => {
-                if let Ok(image) = image_rs::open(path) {
-                    let operation = std::fs::File::open(path)
-                        .ok()
-                        .map(std::io::BufReader::new)
-                        .and_then(|mut reader| {
-                            Operation::from_exif(&mut reader).ok()
-                        })
-                        .unwrap_or_else(Operation::empty);
-
-                    Memory::Host(operation.perform(image.to_rgba8()))
-                } else {
-                    Memory::NotFound
-                }
-            }
-            image::Data::Bytes(bytes) => {
-                if let Ok(image) = image_rs::load_from_memory(bytes) {
-                    let operation =
-                        Operation::from_exif(&mut std::io::Cursor::new(bytes))
-                            .ok()
-                            .unwrap_or_else(Operation::empty);
-
-                    Memory::Host(operation.perform(image.to_rgba8()))
-                } else {
-                    Memory::Invalid
+        let id = handle.id();
+
+        let cached = match self.map.entry(id) {
+            hash_map::Entry::Occupied(entry) => {
+                let cached = entry.into_mut();
+
+                if handle.version() > cached.version {
+                    self.misses += 1;
+
+                    let memory = Memory::new(handle);
+
+                    // A device entry is only ever kept along an outdated one
+                    // until it is uploaded, so nothing is overwritten here
+                    if let Memory::Device(entry) =
+                        mem::replace(&mut cached.memory, memory)
+                    {
+                        cached.outdated = Some(entry);
+                    }
+
+                    cached.version = handle.version();
+                } else if cached.memory.is_loaded() {
+                    self.hits += 1;
                 }
+
+                cached
             }
-            image::Data::Rgba {
-                width,
-                height,
-                pixels,
-            } => {
-                if let Some(image) = image_rs::ImageBuffer::from_vec(
-                    *width,
-                    *height,
-                    pixels.to_vec(),
-                ) {
-                    Memory::Host(image)
-                } else {
-                    Memory::Invalid
-                }
+            hash_map::Entry::Vacant(entry) => {
+                self.misses += 1;
+
+                entry.insert(Cached {
+                    memory: Memory::new(handle),
+                    version: handle.version(),
+                    last_used: self.frame,
+                    outdated: None,
+                })
             }
         };
 
-        self.insert(handle, memory);
-        self.get(handle).unwrap()
+        cached.last_used = self.frame;
+
+        let memory = &mut cached.memory;
+
+        if let Memory::Loading = memory {
+            match decoder::request(handle).as_deref() {
+                Some(Ok(image)) => *memory = Memory::Host(image.clone()),
+                Some(Err(error)) => *memory = Memory::Error(error.clone()),
+                None => {}
+            }
+        }
+
+        memory
     }
 
     /// Load image and upload raster data
+    ///
+    /// The pixels of an updated image are uploaded in place of its previous
+    /// version when their dimensions match.
+    ///
+    /// If the [`Storage`] is full, the least recently used images that were
+    /// not drawn in the current frame are evicted to make room.
     pub fn upload(
         &mut self,
         handle: &image::Handle,
         state: &mut T::State<'_>,
         storage: &mut T,
     ) -> Option<&T::Entry> {
-        let memory = self.load(handle);
+        let id = handle.id();
+
+        if let Memory::Host(_) = self.load(handle) {
+            loop {
+                let cached = self.map.get_mut(&id)?;
 
-        if let Memory::Host(image) = memory {
-            let (width, height) = image.dimensions();
+                let Memory::Host(image) = &cached.memory else {
+                    break;
+                };
 
-            let entry = storage.upload(width, height, image, state)?;
+                let (width, height) = image.dimensions();
+
+                if let Some(entry) = cached.outdated.take() {
+                    if entry.size() == Size::new(width, height) {
+                        storage.update(&entry, image, state);
+
+                        cached.memory = Memory::Device(entry);
+                        break;
+                    }
+
+                    storage.remove(&entry, state);
+                }
+
+                if let Some(entry) = storage.upload(width, height, image, state)
+                {
+                    cached.memory = Memory::Device(entry);
+                    break;
+                }
 
-            *memory = Memory::Device(entry);
+                if !self.evict_device_entry(storage, state) {
+                    return None;
+                }
+            }
         }
 
-        if let Memory::Device(allocation) = memory {
+        if let Some(Cached {
+            memory: Memory::Device(allocation),
+            ..
+        }) = self.map.get(&id)
+        {
             Some(allocation)
         } else {
             None
         }
     }
 
-    /// Trim cache misses from cache
+    /// Returns the [`Statistics`] of the [`Cache`].
+    pub fn statistics(&self) -> Statistics {
+        Statistics {
+            entries: self.map.len(),
+            host_bytes: self.map.values().map(Cached::host_bytes).sum(),
+            device_bytes: self.map.values().map(Cached::device_bytes).sum(),
+            hits: self.hits,
+            misses: self.misses,
+            loading: self
+                .map
+                .values()
+                .filter(|cached| matches!(cached.memory, Memory::Loading))
+                .count(),
+            ..Statistics::default()
+        }
+    }
+
+    /// Trim cache to fit in its [`Budget`]
+    ///
+    /// Images that are still loading or that failed to load are dropped as
+    /// soon as they stop being used. Loaded images are evicted in least
+    /// recently used order once the cache exceeds its [`Budget`].
     pub fn trim(&mut self, storage: &mut T, state: &mut T::State<'_>) {
-        let hits = &self.hits;
+        let frame = self.frame;
+        let mut abandoned = Vec::new();
 
-        self.map.retain(|k, memory| {
-            let retain = hits.contains(k);
+        self.map.retain(|id, cached| {
+            let retain = cached.last_used == frame || cached.memory.is_loaded();
 
             if !retain {
-                if let Memory::Device(entry) = memory {
-                    storage.remove(entry, state);
+                if let Memory::Loading = cached.memory {
+                    abandoned.push(*id);
                 }
+
+                cached.release(storage, state);
             }
 
             retain
         });
 
-        self.hits.clear();
+        let evicted = overflow(
+            self.map.iter().map(|(id, cached)| {
+                (*id, cached.host_bytes(), cached.last_used)
+            }),
+            self.budget.host,
+            frame,
+        )
+        .into_iter()
+        .chain(overflow(
+            self.map.iter().map(|(id, cached)| {
+                (*id, cached.device_bytes(), cached.last_used)
+            }),
+            self.budget.device,
+            frame,
+        ))
+        .collect::<Vec<_>>();
+
+        for id in evicted {
+            if let Some(cached) = self.map.remove(&id) {
+                cached.release(storage, state);
+            }
+        }
+
+        decoder::discard(|id| abandoned.contains(&id));
+
+        self.frame += 1;
     }
 
-    fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory<T>> {
-        let _ = self.hits.insert(handle.id());
+    fn evict_device_entry(
+        &mut self,
+        storage: &mut T,
+        state: &mut T::State<'_>,
+    ) -> bool {
+        let frame = self.frame;
+
+        let least_recently_used = self
+            .map
+            .iter()
+            .filter(|(_, cached)| {
+                cached.last_used != frame && cached.device_bytes() > 0
+            })
+            .min_by_key(|(_, cached)| cached.last_used)
+            .map(|(id, _)| *id);
+
+        match least_recently_used.and_then(|id| self.map.remove(&id)) {
+            Some(cached) => {
+                cached.release(storage, state);
+
+                true
+            }
+            None => false,
+        }
+    }
+}
 
-        self.map.get_mut(&handle.id())
+impl<T: Storage> Default for Cache<T> {
+    fn default() -> Self {
+        Self::new(Budget::default())
+    }
+}
+
+#[derive(Debug)]
+struct Cached<T: Storage> {
+    memory: Memory<T>,
+    version: u64,
+    last_used: u64,
+    /// The storage entry of a previous version of the image
+    outdated: Option<T::Entry>,
+}
+
+impl<T: Storage> Cached<T> {
+    fn host_bytes(&self) -> usize {
+        match &self.memory {
+            Memory::Host(image) => image.as_raw().len(),
+            _ => 0,
+        }
     }
 
-    fn insert(&mut self, handle: &image::Handle, memory: Memory<T>) {
-        let _ = self.map.insert(handle.id(), memory);
+    fn device_bytes(&self) -> usize {
+        let current = match &self.memory {
+            Memory::Device(entry) => storage::bytes(entry),
+            _ => 0,
+        };
+
+        current + self.outdated.as_ref().map_or(0, storage::bytes)
     }
 
-    fn contains(&self, handle: &image::Handle) -> bool {
-        self.map.contains_key(&handle.id())
+    fn release(&self, storage: &mut T, state: &mut T::State<'_>) {
+        if let Memory::Device(entry) = &self.memory {
+            storage.remove(entry, state);
+        }
+
+        if let Some(entry) = &self.outdated {
+            storage.remove(entry, state);
+        }
     }
 }
 
-impl<T: Storage> Default for Cache<T> {
-    fn default() -> Self {
-        Self {
-            map: HashMap::new(),
-            hits: HashSet::new(),
+/// Decodes the images of the given handles in the background, so they are
+/// ready by the time they are drawn.
+///
+/// The [`Command`] produces the dimensions of each image, or the [`Error`]
+/// that prevented it from loading, in the same order as the handles.
+///
+/// Preloaded images are kept until they are drawn, unless the decoder runs
+/// out of room for them first; in which case they are decoded again when
+/// drawn.
+///
+/// [`Error`]: image::Error
+pub fn preload(
+    handles: impl IntoIterator<Item = image::Handle>,
+) -> Command<Vec<Result<Size<u32>, image::Error>>> {
+    let receivers: Vec<_> = handles
+        .into_iter()
+        .map(|handle| decoder::preload(&handle))
+        .collect();
+
+    Command::perform(future::join_all(receivers), |results| {
+        results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or_else(|_| {
+                    Err(image::Error::Invalid(String::from(
+                        "the image decoder stopped unexpectedly",
+                    )))
+                })
+            })
+            .collect()
+    })
+}
+
+/// Decodes the image of the given handle, applying its EXIF orientation.
+///
+/// This may take a while, so it should be done in the background.
+pub fn decode(
+    handle: &image::Handle,
+) -> Result<image_rs::ImageBuffer<image_rs::Rgba<u8>, Vec<u8>>, image::Error> {
+    match handle.data() {
+        image::Data::Path(path) => {
+            let image = image_rs::open(path).map_err(to_error)?;
+
+            let operation = std::fs::File::open(path)
+                .ok()
+                .map(std::io::BufReader::new)
+                .and_then(|mut reader| Operation::from_exif(&mut reader).ok())
+                .unwrap_or_else(Operation::empty);
+
+            Ok(operation.perform(image.to_rgba8()))
         }
+        image::Data::Bytes(bytes) => {
+            let image = image_rs::load_from_memory(bytes).map_err(to_error)?;
+
+            let operation =
+                Operation::from_exif(&mut std::io::Cursor::new(bytes))
+                    .ok()
+                    .unwrap_or_else(Operation::empty);
+
+            Ok(operation.perform(image.to_rgba8()))
+        }
+        image::Data::Rgba {
+            width,
+            height,
+            pixels,
+        } => image_rs::ImageBuffer::from_vec(*width, *height, pixels.to_vec())
+            .ok_or_else(|| {
+                image::Error::Invalid(format!(
+                    "the pixels do not match the dimensions \
+                    ({width}x{height})"
+                ))
+            }),
     }
 }
 
+fn to_error(error: image_rs::ImageError) -> image::Error {
+    match error {
+        image_rs::ImageError::IoError(error) => {
+            image::Error::Io(Arc::new(error))
+        }
+        error => image::Error::Invalid(error.to_string()),
+    }
+}
+
+/// Decodes all the frames of the animated image of the given handle, with
+/// their delays.
+///
+/// GIF, WebP and APNG animations are supported, as long as the respective
+/// features are enabled. Any other image is decoded as a single frame.
+///
+/// Like in browsers, frames with delays shorter than 20 milliseconds are
+/// displayed for 100 milliseconds.
+///
+/// Decoding may take a while, so it should be done in the background; for
+/// instance, in a `Command`.
+pub fn load_animation(handle: &image::Handle) -> Option<image::Animation> {
+    let bytes: Cow<'_, [u8]> = match handle.data() {
+        image::Data::Path(path) => Cow::Owned(std::fs::read(path).ok()?),
+        image::Data::Bytes(bytes) => Cow::Borrowed(bytes),
+        image::Data::Rgba { .. } => {
+            return Some(still(handle));
+        }
+    };
+
+    decode_animation(handle, &bytes)
+}
+
+#[cfg(any(feature = "gif", feature = "webp", feature = "png"))]
+fn decode_animation(
+    handle: &image::Handle,
+    bytes: &[u8],
+) -> Option<image::Animation> {
+    let format = image_rs::guess_format(bytes).ok()?;
+
+    let reader = std::io::Cursor::new(bytes);
+
+    let frames: image_rs::Frames<'_> = match format {
+        #[cfg(feature = "gif")]
+        image_rs::ImageFormat::Gif => {
+            image_rs::codecs::gif::GifDecoder::new(reader)
+                .ok()?
+                .into_frames()
+        }
+        #[cfg(feature = "webp")]
+        image_rs::ImageFormat::WebP => {
+            image_rs::codecs::webp::WebPDecoder::new(reader)
+                .ok()?
+                .into_frames()
+        }
+        #[cfg(feature = "png")]
+        image_rs::ImageFormat::Png => {
+            let decoder =
+                image_rs::codecs::png::PngDecoder::new(reader).ok()?;
+
+            if !decoder.is_apng() {
+                return Some(still(handle));
+            }
+
+            decoder.apng().into_frames()
+        }
+        _ => {
+            return Some(still(handle));
+        }
+    };
+
+    let frames = frames
+        .map(|frame| {
+            let frame = frame?;
+
+            let delay = match Duration::from(frame.delay()) {
+                delay if delay < Duration::from_millis(20) => {
+                    Duration::from_millis(100)
+                }
+                delay => delay,
+            };
+
+            let buffer = frame.into_buffer();
+            let (width, height) = buffer.dimensions();
+
+            Ok(image::Frame {
+                handle: image::Handle::from_pixels(
+                    width,
+                    height,
+                    buffer.into_raw(),
+                ),
+                delay,
+            })
+        })
+        .collect::<Result<Vec<_>, image_rs::ImageError>>()
+        .ok()?;
+
+    Some(image::Animation::new(frames))
+}
+
+#[cfg(not(any(feature = "gif", feature = "webp", feature = "png")))]
+fn decode_animation(
+    handle: &image::Handle,
+    _bytes: &[u8],
+) -> Option<image::Animation> {
+    Some(still(handle))
+}
+
+fn still(handle: &image::Handle) -> image::Animation {
+    image::Animation::new([image::Frame {
+        handle: handle.clone(),
+        delay: Duration::ZERO,
+    }])
+}
+
 bitflags! {
     struct Operation: u8 {
         const FLIP_HORIZONTALLY = 0b001;
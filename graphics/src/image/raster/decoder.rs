@@ -0,0 +1,384 @@
+//! Decode images in a pool of background threads.
+use super::decode;
+use crate::image::overflow;
+use crate::Size;
+
+use iced_native::futures::channel::oneshot;
+use iced_native::image;
+
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Condvar, Mutex};
+
+type Image = image_rs::ImageBuffer<image_rs::Rgba<u8>, Vec<u8>>;
+type Decoded = Arc<Result<Image, image::Error>>;
+type Listener = oneshot::Sender<Result<Size<u32>, image::Error>>;
+
+/// The maximum amount of bytes of decoded images kept until they are drawn.
+const BUDGET: usize = 64 * 1024 * 1024;
+
+static DECODER: Lazy<Decoder> = Lazy::new(Decoder::new);
+
+/// Returns the decoded image of the given handle, if ready.
+///
+/// Otherwise, the handle is queued for decoding, unless it already is.
+///
+/// Decoded images are shared by every renderer requesting them, and they
+/// are kept until they are discarded or they exceed the budget of the
+/// decoder, in least recently used order.
+pub fn request(handle: &image::Handle) -> Option<Decoded> {
+    if cfg!(target_arch = "wasm32") {
+        return Some(Arc::new(decode(handle)));
+    }
+
+    let mut queue = DECODER.queue.lock().expect("Lock decoder queue");
+
+    if let Some(result) = queue.get(handle.id()) {
+        return Some(result);
+    }
+
+    if queue.enqueue(handle) {
+        DECODER.condvar.notify_one();
+    }
+
+    None
+}
+
+/// Queues the given handle for decoding and returns a receiver that is
+/// notified once it is done.
+pub fn preload(
+    handle: &image::Handle,
+) -> oneshot::Receiver<Result<Size<u32>, image::Error>> {
+    let (sender, receiver) = oneshot::channel();
+
+    let mut queue = DECODER.queue.lock().expect("Lock decoder queue");
+
+    if let Some(result) = queue.get(handle.id()) {
+        let _ = sender.send(dimensions(&result));
+
+        return receiver;
+    }
+
+    queue.listeners.entry(handle.id()).or_default().push(sender);
+
+    if cfg!(target_arch = "wasm32") {
+        queue.finish(handle.id(), decode(handle));
+
+        return receiver;
+    }
+
+    if queue.enqueue(handle) {
+        DECODER.condvar.notify_one();
+    }
+
+    receiver
+}
+
+/// Drops the pending and finished work of the image ids matching the given
+/// predicate.
+///
+/// Images with listeners waiting on them are never dropped.
+pub fn discard(f: impl Fn(image::Id) -> bool) {
+    DECODER.queue.lock().expect("Lock decoder queue").discard(f);
+}
+
+struct Decoder {
+    queue: Mutex<Queue>,
+    condvar: Condvar,
+}
+
+struct Queue {
+    pending: VecDeque<image::Handle>,
+    queued: HashSet<image::Id>,
+    finished: HashMap<image::Id, Finished>,
+    listeners: HashMap<image::Id, Vec<Listener>>,
+    abandoned: HashSet<image::Id>,
+    budget: usize,
+    clock: u64,
+}
+
+struct Finished {
+    result: Decoded,
+    last_used: u64,
+}
+
+impl Queue {
+    fn get(&mut self, id: image::Id) -> Option<Decoded> {
+        self.clock += 1;
+
+        let finished = self.finished.get_mut(&id)?;
+        finished.last_used = self.clock;
+
+        Some(finished.result.clone())
+    }
+
+    fn enqueue(&mut self, handle: &image::Handle) -> bool {
+        let _ = self.abandoned.remove(&handle.id());
+        let is_new = self.queued.insert(handle.id());
+
+        if is_new {
+            self.pending.push_back(handle.clone());
+        }
+
+        is_new
+    }
+
+    fn discard(&mut self, f: impl Fn(image::Id) -> bool) {
+        let Self {
+            pending,
+            queued,
+            finished,
+            listeners,
+            abandoned,
+            ..
+        } = self;
+
+        if queued.is_empty() && finished.is_empty() {
+            return;
+        }
+
+        pending.retain(|handle| {
+            let id = handle.id();
+            let retain = listeners.contains_key(&id) || !f(id);
+
+            if !retain {
+                let _ = queued.remove(&id);
+            }
+
+            retain
+        });
+
+        // Images being decoded cannot be stopped, so their results are
+        // dropped once they are done instead
+        abandoned.extend(
+            queued
+                .iter()
+                .copied()
+                .filter(|id| !listeners.contains_key(id) && f(*id)),
+        );
+
+        finished.retain(|id, _| !f(*id));
+    }
+
+    fn finish(&mut self, id: image::Id, result: Result<Image, image::Error>) {
+        for listener in self.listeners.remove(&id).into_iter().flatten() {
+            let _ = listener.send(dimensions(&result));
+        }
+
+        let _ = self.queued.remove(&id);
+
+        if self.abandoned.remove(&id) {
+            return;
+        }
+
+        self.clock += 1;
+
+        let _ = self.finished.insert(
+            id,
+            Finished {
+                result: Arc::new(result),
+                last_used: self.clock,
+            },
+        );
+
+        let evicted = overflow(
+            self.finished.iter().map(|(id, finished)| {
+                (*id, finished.bytes(), finished.last_used)
+            }),
+            self.budget,
+            self.clock,
+        );
+
+        for id in evicted {
+            let _ = self.finished.remove(&id);
+        }
+    }
+}
+
+impl Default for Queue {
+    fn default() -> Self {
+        Self {
+            pending: VecDeque::new(),
+            queued: HashSet::new(),
+            finished: HashMap::new(),
+            listeners: HashMap::new(),
+            abandoned: HashSet::new(),
+            budget: BUDGET,
+            clock: 0,
+        }
+    }
+}
+
+impl Finished {
+    fn bytes(&self) -> usize {
+        self.result
+            .as_ref()
+            .as_ref()
+            .map_or(0, |image| image.as_raw().len())
+    }
+}
+
+impl Decoder {
+    fn new() -> Self {
+        let workers = std::thread::available_parallelism()
+            .map(|amount| amount.get().min(4))
+            .unwrap_or(1);
+
+        for i in 0..workers {
+            let _ = std::thread::Builder::new()
+                .name(format!("iced_graphics image decoder {i}"))
+                .spawn(work)
+                .expect("Spawn image decoder thread");
+        }
+
+        Self {
+            queue: Mutex::new(Queue::default()),
+            condvar: Condvar::new(),
+        }
+    }
+}
+
+fn work() {
+    loop {
+        let handle = {
+            let mut queue = DECODER.queue.lock().expect("Lock decoder queue");
+
+            loop {
+                if let Some(handle) = queue.pending.pop_front() {
+                    break handle;
+                }
+
+                queue =
+                    DECODER.condvar.wait(queue).expect("Wait for decoder jobs");
+            }
+        };
+
+        let result = decode(&handle);
+
+        DECODER
+            .queue
+            .lock()
+            .expect("Lock decoder queue")
+            .finish(handle.id(), result);
+    }
+}
+
+fn dimensions(
+    result: &Result<Image, image::Error>,
+) -> Result<Size<u32>, image::Error> {
+    match result {
+        Ok(image) => {
+            let (width, height) = image.dimensions();
+
+            Ok(Size::new(width, height))
+        }
+        Err(error) => Err(error.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_next(queue: &mut Queue) -> image::Id {
+        queue.pending.pop_front().expect("Pending image").id()
+    }
+
+    #[test]
+    fn abandoned_images_are_dropped_once_decoded() {
+        let mut queue = Queue::default();
+        let handle = image::Handle::from_pixels(1, 1, vec![0; 4]);
+
+        assert!(queue.enqueue(&handle));
+
+        let id = decode_next(&mut queue);
+        queue.discard(|_| true);
+        queue.finish(id, Ok(Image::new(1, 1)));
+
+        assert!(queue.finished.is_empty());
+        assert!(queue.queued.is_empty());
+        assert!(queue.abandoned.is_empty());
+    }
+
+    #[test]
+    fn images_requested_again_keep_their_result() {
+        let mut queue = Queue::default();
+        let handle = image::Handle::from_pixels(1, 1, vec![0; 4]);
+
+        assert!(queue.enqueue(&handle));
+
+        let id = decode_next(&mut queue);
+        queue.discard(|_| true);
+
+        assert!(!queue.enqueue(&handle));
+
+        queue.finish(id, Ok(Image::new(1, 1)));
+
+        assert!(queue.finished.contains_key(&id));
+    }
+
+    #[test]
+    fn images_with_listeners_are_never_abandoned() {
+        let mut queue = Queue::default();
+        let handle = image::Handle::from_pixels(1, 1, vec![0; 4]);
+        let (sender, mut receiver) = oneshot::channel();
+
+        let _ = queue.listeners.insert(handle.id(), vec![sender]);
+        assert!(queue.enqueue(&handle));
+
+        queue.discard(|_| true);
+        let id = decode_next(&mut queue);
+        queue.finish(id, Ok(Image::new(1, 1)));
+
+        assert!(queue.finished.contains_key(&id));
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(Some(Ok(size))) if size == Size::new(1, 1)
+        ));
+    }
+
+    #[test]
+    fn finished_images_are_shared_by_every_request() {
+        let mut queue = Queue::default();
+        let handle = image::Handle::from_pixels(1, 1, vec![0; 4]);
+
+        assert!(queue.enqueue(&handle));
+
+        let id = decode_next(&mut queue);
+        queue.finish(id, Ok(Image::new(1, 1)));
+
+        let first = queue.get(id).expect("Finished image");
+        let second = queue.get(id).expect("Finished image");
+
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn least_recently_used_images_are_dropped_past_the_budget() {
+        let mut queue = Queue {
+            budget: 8,
+            ..Queue::default()
+        };
+
+        let handles: Vec<_> = (0..3)
+            .map(|i| image::Handle::from_pixels(1, 1, vec![i; 4]))
+            .collect();
+
+        for handle in &handles[..2] {
+            assert!(queue.enqueue(handle));
+
+            let id = decode_next(&mut queue);
+            queue.finish(id, Ok(Image::new(1, 1)));
+        }
+
+        assert!(queue.get(handles[0].id()).is_some());
+
+        assert!(queue.enqueue(&handles[2]));
+        let id = decode_next(&mut queue);
+        queue.finish(id, Ok(Image::new(1, 1)));
+
+        assert!(queue.finished.contains_key(&handles[0].id()));
+        assert!(!queue.finished.contains_key(&handles[1].id()));
+        assert!(queue.finished.contains_key(&handles[2].id()));
+    }
+}
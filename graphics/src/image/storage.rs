@@ -20,6 +20,16 @@ pub trait Storage {
         state: &mut Self::State<'_>,
     ) -> Option<Self::Entry>;
 
+    /// Replace the image data of a [`Self::Entry`] in place.
+    ///
+    /// The data must have the same dimensions as the [`Self::Entry`].
+    fn update(
+        &mut self,
+        entry: &Self::Entry,
+        data: &[u8],
+        state: &mut Self::State<'_>,
+    );
+
     /// Remove a [`Self::Entry`] from the [`Storage`].
     fn remove(&mut self, entry: &Self::Entry, state: &mut Self::State<'_>);
 }
@@ -29,3 +39,11 @@ pub trait Entry: Debug {
     /// The [`Size`] of the [`Entry`].
     fn size(&self) -> Size<u32>;
 }
+
+/// Returns the amount of bytes of the RGBA data of an [`Entry`].
+#[cfg(any(feature = "image_rs", feature = "svg"))]
+pub(crate) fn bytes(entry: &impl Entry) -> usize {
+    let size = entry.size();
+
+    size.width as usize * size.height as usize * 4
+}
@@ -1,14 +1,17 @@
 //! Vector image loading and caching
-use crate::image::Storage;
-use crate::Color;
+use crate::image::storage::bytes;
+use crate::image::{overflow, Budget, Statistics, Storage};
+use crate::{Color, Rectangle};
 
 use iced_native::svg;
 use iced_native::Size;
 
 use resvg::tiny_skia;
 use resvg::usvg;
-use std::collections::{HashMap, HashSet};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fs;
+use usvg_text_layout::{fontdb, TreeTextToPath};
 
 /// Entry in cache corresponding to an svg handle
 pub enum Svg {
@@ -33,55 +36,125 @@ impl Svg {
 }
 
 /// Caches svg vector and raster data
-#[derive(Debug)]
+///
+/// Rasterizations are kept across frames within the [`Budget`] of the cache,
+/// evicting the least recently used ones first. Parsed svgs are kept as long
+/// as any of their rasterizations is.
+///
+/// Text is rendered with the system fonts and any font loaded with
+/// [`Cache::load_font`].
 pub struct Cache<T: Storage> {
-    svgs: HashMap<u64, Svg>,
-    rasterized: HashMap<(u64, u32, u32, ColorFilter), T::Entry>,
-    svg_hits: HashSet<u64>,
-    rasterized_hits: HashSet<(u64, u32, u32, ColorFilter)>,
+    svgs: HashMap<(u64, u64), (Svg, u64)>,
+    rasterized: HashMap<Key, (T::Entry, u64)>,
+    fonts: Vec<Cow<'static, [u8]>>,
+    font_database: Option<fontdb::Database>,
+    budget: Budget,
+    frame: u64,
+    hits: u64,
+    misses: u64,
 }
 
 type ColorFilter = Option<[u8; 4]>;
 
+/// The handle id, stylesheet id, physical size and color filter of a
+/// rasterization.
+type Key = (u64, u64, u32, u32, ColorFilter);
+
 impl<T: Storage> Cache<T> {
+    /// Creates a new [`Cache`] with the given [`Budget`].
+    pub fn new(budget: Budget) -> Self {
+        Self {
+            svgs: HashMap::new(),
+            rasterized: HashMap::new(),
+            fonts: Vec::new(),
+            font_database: None,
+            budget,
+            frame: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Loads the given font, making it available to the text of svgs.
+    pub fn load_font(&mut self, bytes: impl Into<Cow<'static, [u8]>>) {
+        let bytes = bytes.into();
+
+        if let Some(database) = &mut self.font_database {
+            database.load_font_data(bytes.to_vec());
+        }
+
+        self.fonts.push(bytes);
+    }
+
     /// Load svg
     pub fn load(&mut self, handle: &svg::Handle) -> &Svg {
-        if self.svgs.contains_key(&handle.id()) {
-            return self.svgs.get(&handle.id()).unwrap();
+        self.load_styled(handle, None)
+    }
+
+    /// Load svg with the given [`svg::Stylesheet`] applied
+    pub fn load_styled(
+        &mut self,
+        handle: &svg::Handle,
+        stylesheet: Option<&svg::Stylesheet>,
+    ) -> &Svg {
+        let frame = self.frame;
+        let key = (handle.id(), stylesheet.map_or(0, svg::Stylesheet::id));
+
+        if let Some((_, last_used)) = self.svgs.get_mut(&key) {
+            *last_used = frame;
+
+            return &self.svgs[&key].0;
         }
 
-        let svg = match handle.data() {
-            svg::Data::Path(path) => {
-                let tree = fs::read_to_string(path).ok().and_then(|contents| {
-                    usvg::Tree::from_str(&contents, &usvg::Options::default())
-                        .ok()
-                });
+        let data = match handle.data() {
+            svg::Data::Path(path) => fs::read(path).ok().map(Cow::Owned),
+            svg::Data::Bytes(bytes) => Some(Cow::Borrowed(bytes.as_ref())),
+        };
+
+        let tree = data.and_then(|data| {
+            let options = usvg::Options::default();
 
-                tree.map(Svg::Loaded).unwrap_or(Svg::NotFound)
+            // Compressed data cannot be styled
+            match (stylesheet, std::str::from_utf8(&data)) {
+                (Some(stylesheet), Ok(document)) => {
+                    usvg::Tree::from_str(&stylesheet.apply(document), &options)
+                        .ok()
+                }
+                _ => usvg::Tree::from_data(&data, &options).ok(),
             }
-            svg::Data::Bytes(bytes) => {
-                match usvg::Tree::from_data(bytes, &usvg::Options::default()) {
-                    Ok(tree) => Svg::Loaded(tree),
-                    Err(_) => Svg::NotFound,
+        });
+
+        let svg = match tree {
+            Some(mut tree) => {
+                if has_text(&tree) {
+                    tree.convert_text(self.font_database(), false);
                 }
+
+                Svg::Loaded(tree)
             }
+            None => Svg::NotFound,
         };
 
-        let _ = self.svgs.insert(handle.id(), svg);
-        self.svgs.get(&handle.id()).unwrap()
+        let _ = self.svgs.insert(key, (svg, frame));
+        &self.svgs.get(&key).unwrap().0
     }
 
     /// Load svg and upload raster data
+    ///
+    /// Rasterizations are keyed by their size in physical pixels, so an svg
+    /// is rasterized again when the scale factor changes.
     pub fn upload(
         &mut self,
         handle: &svg::Handle,
         color: Option<Color>,
+        stylesheet: Option<&svg::Stylesheet>,
         [width, height]: [f32; 2],
         scale: f32,
         state: &mut T::State<'_>,
         storage: &mut T,
     ) -> Option<&T::Entry> {
         let id = handle.id();
+        let style = stylesheet.map_or(0, svg::Stylesheet::id);
 
         let (width, height) = (
             (scale * width).ceil() as u32,
@@ -89,20 +162,28 @@ impl<T: Storage> Cache<T> {
         );
 
         let color = color.map(Color::into_rgba8);
-        let key = (id, width, height, color);
+        let key = (id, style, width, height, color);
 
         // TODO: Optimize!
         // We currently rerasterize the SVG when its size changes. This is slow
         // as heck. A GPU rasterizer like `pathfinder` may perform better.
         // It would be cool to be able to smooth resize the `svg` example.
         if self.rasterized.contains_key(&key) {
-            let _ = self.svg_hits.insert(id);
-            let _ = self.rasterized_hits.insert(key);
+            self.hits += 1;
 
-            return self.rasterized.get(&key);
+            if let Some((_, last_used)) = self.svgs.get_mut(&(id, style)) {
+                *last_used = self.frame;
+            }
+
+            let (entry, last_used) = self.rasterized.get_mut(&key)?;
+            *last_used = self.frame;
+
+            return Some(&*entry);
         }
 
-        match self.load(handle) {
+        self.misses += 1;
+
+        match self.load_styled(handle, stylesheet) {
             Svg::Loaded(tree) => {
                 if width == 0 || height == 0 {
                     return None;
@@ -137,47 +218,155 @@ impl<T: Storage> Cache<T> {
                     });
                 }
 
-                let allocation = storage.upload(width, height, &rgba, state)?;
+                let allocation = loop {
+                    if let Some(allocation) =
+                        storage.upload(width, height, &rgba, state)
+                    {
+                        break allocation;
+                    }
+
+                    if !self.evict_rasterization(storage, state) {
+                        return None;
+                    }
+                };
+
                 log::debug!("allocating {} {}x{}", id, width, height);
 
-                let _ = self.svg_hits.insert(id);
-                let _ = self.rasterized_hits.insert(key);
-                let _ = self.rasterized.insert(key, allocation);
+                let _ = self.rasterized.insert(key, (allocation, self.frame));
 
-                self.rasterized.get(&key)
+                self.rasterized.get(&key).map(|(entry, _)| entry)
             }
             Svg::NotFound => None,
         }
     }
 
-    /// Load svg and upload raster data
+    /// Returns the [`Statistics`] of the [`Cache`].
+    pub fn statistics(&self) -> Statistics {
+        Statistics {
+            entries: self.rasterized.len(),
+            device_bytes: self
+                .rasterized
+                .values()
+                .map(|(entry, _)| bytes(entry))
+                .sum(),
+            hits: self.hits,
+            misses: self.misses,
+            ..Statistics::default()
+        }
+    }
+
+    /// Trim cache to fit in its [`Budget`]
     pub fn trim(&mut self, storage: &mut T, state: &mut T::State<'_>) {
-        let svg_hits = &self.svg_hits;
-        let rasterized_hits = &self.rasterized_hits;
+        let frame = self.frame;
 
-        self.svgs.retain(|k, _| svg_hits.contains(k));
-        self.rasterized.retain(|k, entry| {
-            let retain = rasterized_hits.contains(k);
+        let evicted = overflow(
+            self.rasterized.iter().map(|(key, (entry, last_used))| {
+                (*key, bytes(entry), *last_used)
+            }),
+            self.budget.device,
+            frame,
+        );
 
-            if !retain {
-                storage.remove(entry, state);
+        for key in evicted {
+            if let Some((entry, _)) = self.rasterized.remove(&key) {
+                storage.remove(&entry, state);
             }
+        }
 
-            retain
+        let rasterized = &self.rasterized;
+
+        self.svgs.retain(|(id, style), (_, last_used)| {
+            *last_used == frame
+                || rasterized
+                    .keys()
+                    .any(|(svg, svg_style, ..)| svg == id && svg_style == style)
         });
-        self.svg_hits.clear();
-        self.rasterized_hits.clear();
+
+        self.frame += 1;
+    }
+
+    fn font_database(&mut self) -> &fontdb::Database {
+        let fonts = &self.fonts;
+
+        self.font_database.get_or_insert_with(|| {
+            let mut database = fontdb::Database::new();
+            database.load_system_fonts();
+
+            for font in fonts {
+                database.load_font_data(font.to_vec());
+            }
+
+            database
+        })
+    }
+
+    fn evict_rasterization(
+        &mut self,
+        storage: &mut T,
+        state: &mut T::State<'_>,
+    ) -> bool {
+        let frame = self.frame;
+
+        let least_recently_used = self
+            .rasterized
+            .iter()
+            .filter(|(_, (_, last_used))| *last_used != frame)
+            .min_by_key(|(_, (_, last_used))| *last_used)
+            .map(|(key, _)| *key);
+
+        match least_recently_used.and_then(|key| self.rasterized.remove(&key)) {
+            Some((entry, _)) => {
+                storage.remove(&entry, state);
+
+                true
+            }
+            None => false,
+        }
     }
 }
 
 impl<T: Storage> Default for Cache<T> {
     fn default() -> Self {
-        Self {
-            svgs: HashMap::new(),
-            rasterized: HashMap::new(),
-            svg_hits: HashSet::new(),
-            rasterized_hits: HashSet::new(),
-        }
+        Self::new(Budget::default())
+    }
+}
+
+/// Returns the bounds where a rasterization of the given physical size should
+/// be drawn for the given bounds.
+///
+/// The bounds are snapped to the physical pixel grid of the given scale
+/// factor, so each pixel of the rasterization maps to exactly one physical
+/// pixel. This keeps svgs crisp on any scale factor.
+pub fn pixel_bounds(
+    bounds: Rectangle,
+    size: Size<u32>,
+    scale: f32,
+) -> Rectangle {
+    Rectangle {
+        x: (bounds.x * scale).round() / scale,
+        y: (bounds.y * scale).round() / scale,
+        width: size.width as f32 / scale,
+        height: size.height as f32 / scale,
+    }
+}
+
+fn has_text(tree: &usvg::Tree) -> bool {
+    tree.root
+        .descendants()
+        .any(|node| matches!(*node.borrow(), usvg::NodeKind::Text(_)))
+}
+
+impl<T: Storage> std::fmt::Debug for Cache<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cache")
+            .field("svgs", &self.svgs)
+            .field("rasterized", &self.rasterized)
+            .field("fonts", &self.fonts.len())
+            .field("budget", &self.budget)
+            .field("frame", &self.frame)
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
+            .finish()
     }
 }
 
@@ -1,10 +1,16 @@
 //! Organize rendering primitives into a flattened list of layers.
+mod backdrop;
+mod custom;
+mod group;
 mod image;
 mod quad;
 mod text;
 
 pub mod mesh;
 
+pub use backdrop::Backdrop;
+pub use custom::Custom;
+pub use group::Group;
 pub use image::Image;
 pub use mesh::Mesh;
 pub use quad::Quad;
@@ -12,7 +18,8 @@ pub use text::Text;
 
 use crate::alignment;
 use crate::{
-    Background, Font, Point, Primitive, Rectangle, Size, Vector, Viewport,
+    Background, Font, Point, Primitive, Rectangle, Size, Transformation,
+    Vector, Viewport,
 };
 
 /// A group of primitives that should be clipped together.
@@ -21,6 +28,11 @@ pub struct Layer<'a> {
     /// The clipping bounds of the [`Layer`].
     pub bounds: Rectangle,
 
+    /// The [`Transformation`] applied to the primitives of the [`Layer`].
+    ///
+    /// The clipping bounds are not affected by it.
+    pub transformation: Transformation,
+
     /// The quads of the [`Layer`].
     pub quads: Vec<Quad>,
 
@@ -32,6 +44,15 @@ pub struct Layer<'a> {
 
     /// The images of the [`Layer`].
     pub images: Vec<Image>,
+
+    /// The custom primitives of the [`Layer`].
+    pub custom: Vec<Custom>,
+
+    /// The [`Group`] that starts at this [`Layer`], if any.
+    pub group: Option<Group>,
+
+    /// The [`Backdrop`] of the [`Layer`], if any.
+    pub backdrop: Option<Backdrop>,
 }
 
 impl<'a> Layer<'a> {
@@ -39,10 +60,14 @@ impl<'a> Layer<'a> {
     pub fn new(bounds: Rectangle) -> Self {
         Self {
             bounds,
+            transformation: Transformation::identity(),
             quads: Vec::new(),
             meshes: Vec::new(),
             text: Vec::new(),
             images: Vec::new(),
+            custom: Vec::new(),
+            group: None,
+            backdrop: None,
         }
     }
 
@@ -148,6 +173,7 @@ impl<'a> Layer<'a> {
                 border_radius,
                 border_width,
                 border_color,
+                shadow,
             } => {
                 let layer = &mut layers[current_layer];
 
@@ -164,15 +190,19 @@ impl<'a> Layer<'a> {
                     border_radius: *border_radius,
                     border_width: *border_width,
                     border_color: border_color.into_linear(),
+                    shadow_color: shadow.color.into_linear(),
+                    shadow_offset: shadow.offset.into(),
+                    shadow_blur_radius: shadow.blur_radius,
                 });
             }
             Primitive::SolidMesh { buffers, size } => {
                 let layer = &mut layers[current_layer];
 
-                let bounds = Rectangle::new(
-                    Point::new(translation.x, translation.y),
-                    *size,
-                );
+                let bounds =
+                    layer.transformation.transform_rectangle(Rectangle::new(
+                        Point::new(translation.x, translation.y),
+                        *size,
+                    ));
 
                 // Only draw visible content
                 if let Some(clip_bounds) = layer.bounds.intersection(&bounds) {
@@ -190,10 +220,11 @@ impl<'a> Layer<'a> {
             } => {
                 let layer = &mut layers[current_layer];
 
-                let bounds = Rectangle::new(
-                    Point::new(translation.x, translation.y),
-                    *size,
-                );
+                let bounds =
+                    layer.transformation.transform_rectangle(Rectangle::new(
+                        Point::new(translation.x, translation.y),
+                        *size,
+                    ));
 
                 // Only draw visible content
                 if let Some(clip_bounds) = layer.bounds.intersection(&bounds) {
@@ -207,13 +238,18 @@ impl<'a> Layer<'a> {
             }
             Primitive::Clip { bounds, content } => {
                 let layer = &mut layers[current_layer];
-                let translated_bounds = *bounds + translation;
+                let translated_bounds = layer
+                    .transformation
+                    .transform_rectangle(*bounds + translation);
 
                 // Only draw visible content
                 if let Some(clip_bounds) =
                     layer.bounds.intersection(&translated_bounds)
                 {
-                    let clip_layer = Layer::new(clip_bounds);
+                    let clip_layer = Layer {
+                        transformation: layer.transformation,
+                        ..Layer::new(clip_bounds)
+                    };
                     layers.push(clip_layer);
 
                     Self::process_primitive(
@@ -224,6 +260,177 @@ impl<'a> Layer<'a> {
                     );
                 }
             }
+            Primitive::Opacity { opacity, content } => {
+                // Fully transparent content is never visible
+                if *opacity <= 0.0 {
+                    return;
+                }
+
+                let layer = &layers[current_layer];
+
+                layers.push(Layer {
+                    transformation: layer.transformation,
+                    ..Layer::new(layer.bounds)
+                });
+
+                let start = layers.len() - 1;
+
+                Self::process_primitive(layers, translation, content, start);
+
+                layers[start].group = Some(Group {
+                    opacity: opacity.min(1.0),
+                    blur_radius: 0.0,
+                    layers: layers.len() - start,
+                    mask: None,
+                });
+            }
+            Primitive::Mask {
+                bounds,
+                mask,
+                content,
+            } => {
+                let layer = &layers[current_layer];
+                let translated_bounds = layer
+                    .transformation
+                    .transform_rectangle(*bounds + translation);
+
+                // Only draw visible content
+                if let Some(clip_bounds) =
+                    layer.bounds.intersection(&translated_bounds)
+                {
+                    let transformation = layer.transformation;
+
+                    layers.push(Layer {
+                        transformation,
+                        ..Layer::new(clip_bounds)
+                    });
+
+                    let start = layers.len() - 1;
+
+                    Self::process_primitive(
+                        layers,
+                        translation,
+                        content,
+                        start,
+                    );
+
+                    // The mask is drawn in its own layers, after the content
+                    layers.push(Layer {
+                        transformation,
+                        ..Layer::new(clip_bounds)
+                    });
+
+                    let mask_start = layers.len() - 1;
+
+                    Self::process_primitive(
+                        layers,
+                        translation,
+                        mask,
+                        mask_start,
+                    );
+
+                    layers[start].group = Some(Group {
+                        opacity: 1.0,
+                        blur_radius: 0.0,
+                        layers: layers.len() - start,
+                        mask: Some(mask_start - start),
+                    });
+                }
+            }
+            Primitive::Blur {
+                bounds,
+                radius,
+                content,
+            } => {
+                let layer = &layers[current_layer];
+                let translated_bounds = layer
+                    .transformation
+                    .transform_rectangle(*bounds + translation);
+
+                // Only draw visible content
+                if let Some(clip_bounds) =
+                    layer.bounds.intersection(&translated_bounds)
+                {
+                    layers.push(Layer {
+                        transformation: layer.transformation,
+                        ..Layer::new(clip_bounds)
+                    });
+
+                    let start = layers.len() - 1;
+
+                    Self::process_primitive(
+                        layers,
+                        translation,
+                        content,
+                        start,
+                    );
+
+                    layers[start].group = Some(Group {
+                        opacity: 1.0,
+                        blur_radius: radius.max(0.0),
+                        layers: layers.len() - start,
+                        mask: None,
+                    });
+                }
+            }
+            Primitive::BackdropBlur {
+                bounds,
+                radius,
+                border_radius,
+                content,
+            } => {
+                let layer = &layers[current_layer];
+                let translated_bounds = layer
+                    .transformation
+                    .transform_rectangle(*bounds + translation);
+
+                // Only draw visible content
+                if let Some(clip_bounds) =
+                    layer.bounds.intersection(&translated_bounds)
+                {
+                    layers.push(Layer {
+                        transformation: layer.transformation,
+                        backdrop: (*radius > 0.0).then_some(Backdrop {
+                            bounds: translated_bounds,
+                            border_radius: *border_radius,
+                            blur_radius: *radius,
+                        }),
+                        ..Layer::new(clip_bounds)
+                    });
+
+                    Self::process_primitive(
+                        layers,
+                        translation,
+                        content,
+                        layers.len() - 1,
+                    );
+                }
+            }
+            Primitive::Transform {
+                transformation,
+                content,
+            } => {
+                let layer = &layers[current_layer];
+
+                // Transformations can rotate or skew primitives, so they
+                // need their own layer
+                layers.push(Layer {
+                    transformation: layer.transformation
+                        * Transformation::translate(
+                            translation.x,
+                            translation.y,
+                        )
+                        * *transformation,
+                    ..Layer::new(layer.bounds)
+                });
+
+                Self::process_primitive(
+                    layers,
+                    Vector::new(0.0, 0.0),
+                    content,
+                    layers.len() - 1,
+                );
+            }
             Primitive::Translate {
                 translation: new_translation,
                 content,
@@ -243,17 +450,40 @@ impl<'a> Layer<'a> {
                     current_layer,
                 );
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                filter_method,
+                opacity,
+                rotation,
+                bounds,
+            } => {
                 let layer = &mut layers[current_layer];
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
+                    filter_method: *filter_method,
+                    opacity: *opacity,
+                    rotation: *rotation,
+                    bounds: *bounds + translation,
+                });
+            }
+            Primitive::NineSlice {
+                handle,
+                insets,
+                bounds,
+            } => {
+                let layer = &mut layers[current_layer];
+
+                layer.images.push(Image::NineSlice {
+                    handle: handle.clone(),
+                    insets: *insets,
                     bounds: *bounds + translation,
                 });
             }
             Primitive::Svg {
                 handle,
                 color,
+                stylesheet,
                 bounds,
             } => {
                 let layer = &mut layers[current_layer];
@@ -261,9 +491,294 @@ impl<'a> Layer<'a> {
                 layer.images.push(Image::Vector {
                     handle: handle.clone(),
                     color: *color,
+                    stylesheet: stylesheet.clone(),
+                    bounds: *bounds + translation,
+                });
+            }
+            Primitive::Custom { bounds, primitive } => {
+                let layer = &mut layers[current_layer];
+
+                layer.custom.push(Custom {
                     bounds: *bounds + translation,
+                    primitive: primitive.clone(),
                 });
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    use iced_native::Shadow;
+
+    fn viewport() -> Viewport {
+        Viewport::with_physical_size(Size::new(800, 600), 1.0)
+    }
+
+    fn quad(bounds: Rectangle, shadow: Shadow) -> Primitive {
+        Primitive::Quad {
+            bounds,
+            background: Background::Color(Color::WHITE),
+            border_radius: [0.0; 4],
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            shadow,
+        }
+    }
+
+    #[test]
+    fn quads_keep_their_shadow() {
+        let shadow = Shadow {
+            color: Color::from_rgba(0.0, 0.0, 0.0, 0.5),
+            offset: Vector::new(2.0, 4.0),
+            blur_radius: 8.0,
+        };
+
+        let primitives = [Primitive::Translate {
+            translation: Vector::new(10.0, 20.0),
+            content: Box::new(quad(
+                Rectangle::new(Point::ORIGIN, Size::new(50.0, 30.0)),
+                shadow,
+            )),
+        }];
+
+        let layers = Layer::generate(&primitives, &viewport());
+        let quad = layers[0].quads[0];
+
+        assert_eq!(quad.position, [10.0, 20.0]);
+        assert_eq!(quad.shadow_color, [0.0, 0.0, 0.0, 0.5]);
+        assert_eq!(quad.shadow_offset, [2.0, 4.0]);
+        assert_eq!(quad.shadow_blur_radius, 8.0);
+    }
+
+    fn square(x: f32, y: f32) -> Primitive {
+        quad(
+            Rectangle::new(Point::new(x, y), Size::new(10.0, 10.0)),
+            Shadow::default(),
+        )
+    }
+
+    #[test]
+    fn opacity_groups_span_their_nested_layers() {
+        let primitives = [
+            square(0.0, 0.0),
+            Primitive::Opacity {
+                opacity: 0.5,
+                content: Box::new(Primitive::Group {
+                    primitives: vec![
+                        square(10.0, 10.0),
+                        Primitive::Clip {
+                            bounds: Rectangle::new(
+                                Point::ORIGIN,
+                                Size::new(100.0, 100.0),
+                            ),
+                            content: Box::new(square(20.0, 20.0)),
+                        },
+                    ],
+                }),
+            },
+        ];
+
+        let layers = Layer::generate(&primitives, &viewport());
+
+        assert_eq!(layers.len(), 3);
+        assert_eq!(layers[0].quads.len(), 1);
+        assert_eq!(layers[0].group, None);
+        assert_eq!(
+            layers[1].group,
+            Some(Group {
+                opacity: 0.5,
+                blur_radius: 0.0,
+                layers: 2,
+                mask: None,
+            })
+        );
+        assert_eq!(layers[1].quads[0].position, [10.0, 10.0]);
+        assert_eq!(layers[2].quads[0].position, [20.0, 20.0]);
+        assert!(layers[1].group.unwrap().is_offscreen());
+    }
+
+    #[test]
+    fn opacity_is_clamped_and_invisible_groups_are_dropped() {
+        let opacity = |opacity| Primitive::Opacity {
+            opacity,
+            content: Box::new(square(0.0, 0.0)),
+        };
+
+        let opaque = [opacity(2.0)];
+        let layers = Layer::generate(&opaque, &viewport());
+        let group = layers[1].group.unwrap();
+
+        assert_eq!(group.opacity, 1.0);
+        assert!(!group.is_offscreen());
+
+        for opacity in [opacity(0.0), opacity(-1.0)] {
+            let layers =
+                Layer::generate(std::slice::from_ref(&opacity), &viewport());
+
+            assert_eq!(layers.len(), 1);
+            assert!(layers[0].quads.is_empty());
+        }
+    }
+
+    #[test]
+    fn blur_groups_are_clipped_and_nest_in_opacity_groups() {
+        let primitives = [Primitive::Opacity {
+            opacity: 0.5,
+            content: Box::new(Primitive::Group {
+                primitives: vec![
+                    Primitive::Blur {
+                        bounds: Rectangle::new(
+                            Point::new(700.0, 500.0),
+                            Size::new(200.0, 200.0),
+                        ),
+                        radius: 4.0,
+                        content: Box::new(square(750.0, 550.0)),
+                    },
+                    Primitive::Blur {
+                        bounds: Rectangle::new(
+                            Point::ORIGIN,
+                            Size::new(10.0, 10.0),
+                        ),
+                        radius: -1.0,
+                        content: Box::new(square(0.0, 0.0)),
+                    },
+                    Primitive::Blur {
+                        bounds: Rectangle::new(
+                            Point::new(900.0, 0.0),
+                            Size::new(10.0, 10.0),
+                        ),
+                        radius: 4.0,
+                        content: Box::new(square(900.0, 0.0)),
+                    },
+                ],
+            }),
+        }];
+
+        let layers = Layer::generate(&primitives, &viewport());
+
+        // The last blur is outside of the viewport
+        assert_eq!(layers.len(), 4);
+        assert_eq!(layers[1].group.unwrap().opacity, 0.5);
+        assert_eq!(layers[1].group.unwrap().layers, 3);
+
+        let blur = layers[2].group.unwrap();
+
+        assert_eq!(
+            layers[2].bounds,
+            Rectangle::new(Point::new(700.0, 500.0), Size::new(100.0, 100.0))
+        );
+        assert_eq!((blur.blur_radius, blur.layers), (4.0, 1));
+        assert_eq!(layers[2].quads.len(), 1);
+
+        let unblurred = layers[3].group.unwrap();
+
+        assert_eq!(unblurred.blur_radius, 0.0);
+        assert!(!unblurred.is_offscreen());
+    }
+
+    #[test]
+    fn backdrop_blurs_start_a_layer_with_translated_bounds() {
+        let backdrop_blur = |radius| Primitive::Translate {
+            translation: Vector::new(10.0, 10.0),
+            content: Box::new(Primitive::BackdropBlur {
+                bounds: Rectangle::new(Point::ORIGIN, Size::new(50.0, 50.0)),
+                radius,
+                border_radius: [4.0; 4],
+                content: Box::new(square(0.0, 0.0)),
+            }),
+        };
+
+        let primitives = [Primitive::Opacity {
+            opacity: 0.5,
+            content: Box::new(Primitive::Group {
+                primitives: vec![backdrop_blur(8.0), backdrop_blur(0.0)],
+            }),
+        }];
+
+        let layers = Layer::generate(&primitives, &viewport());
+
+        assert_eq!(layers.len(), 4);
+        assert_eq!(layers[1].group.unwrap().layers, 3);
+        assert_eq!(
+            layers[2].backdrop,
+            Some(Backdrop {
+                bounds: Rectangle::new(
+                    Point::new(10.0, 10.0),
+                    Size::new(50.0, 50.0)
+                ),
+                border_radius: [4.0; 4],
+                blur_radius: 8.0,
+            })
+        );
+        assert_eq!(layers[2].quads[0].position, [10.0, 10.0]);
+        assert_eq!(layers[3].backdrop, None);
+        assert_eq!(layers[3].quads.len(), 1);
+    }
+
+    #[test]
+    fn masks_point_to_their_layers_relative_to_the_group() {
+        let clipped = |x, y| Primitive::Clip {
+            bounds: Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0)),
+            content: Box::new(square(x, y)),
+        };
+
+        let primitives = [Primitive::Opacity {
+            opacity: 0.5,
+            content: Box::new(Primitive::Group {
+                primitives: vec![
+                    clipped(0.0, 0.0),
+                    Primitive::Mask {
+                        bounds: Rectangle::new(
+                            Point::new(50.0, 50.0),
+                            Size::new(1000.0, 1000.0),
+                        ),
+                        mask: Box::new(Primitive::Group {
+                            primitives: vec![
+                                square(60.0, 60.0),
+                                clipped(70.0, 70.0),
+                            ],
+                        }),
+                        content: Box::new(Primitive::Group {
+                            primitives: vec![
+                                square(80.0, 80.0),
+                                clipped(90.0, 90.0),
+                            ],
+                        }),
+                    },
+                ],
+            }),
+        }];
+
+        let layers = Layer::generate(&primitives, &viewport());
+
+        assert_eq!(layers.len(), 7);
+        assert_eq!(layers[1].group.unwrap().layers, 6);
+        assert_eq!(
+            layers[3].group,
+            Some(Group {
+                opacity: 1.0,
+                blur_radius: 0.0,
+                layers: 4,
+                mask: Some(2),
+            })
+        );
+        assert_eq!(
+            layers[3].bounds,
+            Rectangle::new(Point::new(50.0, 50.0), Size::new(750.0, 550.0))
+        );
+
+        let positions: Vec<_> = layers[3..]
+            .iter()
+            .map(|layer| layer.quads[0].position)
+            .collect();
+
+        assert_eq!(
+            positions,
+            [[80.0, 80.0], [90.0, 90.0], [60.0, 60.0], [70.0, 70.0]]
+        );
+    }
+}
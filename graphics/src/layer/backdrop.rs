@@ -0,0 +1,17 @@
+use crate::Rectangle;
+
+/// A blur applied to the contents drawn behind a [`Layer`] before drawing
+/// the [`Layer`] itself.
+///
+/// [`Layer`]: super::Layer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backdrop {
+    /// The bounds of the blurred region, in logical coordinates.
+    pub bounds: Rectangle,
+
+    /// The border radius of the blurred region.
+    pub border_radius: [f32; 4],
+
+    /// The blur radius, in logical pixels.
+    pub blur_radius: f32,
+}
@@ -0,0 +1,19 @@
+use crate::Rectangle;
+
+use std::any::Any;
+use std::sync::Arc;
+
+/// A primitive that a [`Backend`] draws with its own logic.
+///
+/// [`Backend`]: crate::Backend
+#[derive(Debug, Clone)]
+pub struct Custom {
+    /// The bounds of the primitive, before the transformation of its
+    /// [`Layer`].
+    ///
+    /// [`Layer`]: super::Layer
+    pub bounds: Rectangle,
+
+    /// The backend-specific primitive.
+    pub primitive: Arc<dyn Any + Send + Sync>,
+}
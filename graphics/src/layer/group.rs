@@ -0,0 +1,29 @@
+/// A run of consecutive layers that must be composited together, with some
+/// opacity, blur or mask.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Group {
+    /// The opacity of the [`Group`], from `0.0` to `1.0`.
+    pub opacity: f32,
+
+    /// The blur radius of the [`Group`], in logical pixels.
+    pub blur_radius: f32,
+
+    /// The amount of layers in the [`Group`], including the one that starts
+    /// it.
+    pub layers: usize,
+
+    /// The index of the first layer of the mask of the [`Group`] relative to
+    /// its start, if any.
+    ///
+    /// The layers from this index on are not part of the content of the
+    /// [`Group`]; their alpha is used to mask it instead.
+    pub mask: Option<usize>,
+}
+
+impl Group {
+    /// Returns true if the [`Group`] needs to be rendered offscreen to be
+    /// composited.
+    pub fn is_offscreen(&self) -> bool {
+        self.opacity < 1.0 || self.blur_radius > 0.0 || self.mask.is_some()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Color, Rectangle};
+use crate::{Color, Padding, Rectangle};
 
 use iced_native::{image, svg};
 
@@ -10,6 +10,27 @@ pub enum Image {
         /// The handle of a raster image.
         handle: image::Handle,
 
+        /// The filtering method of the image.
+        filter_method: image::FilterMethod,
+
+        /// The opacity of the image.
+        opacity: f32,
+
+        /// The clockwise rotation of the image around its center, in
+        /// radians.
+        rotation: f32,
+
+        /// The bounds of the image.
+        bounds: Rectangle,
+    },
+    /// A raster image drawn in nine slices.
+    NineSlice {
+        /// The handle of a raster image.
+        handle: image::Handle,
+
+        /// The insets of the image, in pixels, delimiting its slices.
+        insets: Padding,
+
         /// The bounds of the image.
         bounds: Rectangle,
     },
@@ -21,6 +42,9 @@ pub enum Image {
         /// The [`Color`] filter
         color: Option<Color>,
 
+        /// The [`svg::Stylesheet`] applied to the image.
+        stylesheet: Option<svg::Stylesheet>,
+
         /// The bounds of the image.
         bounds: Rectangle,
     },
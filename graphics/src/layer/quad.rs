@@ -21,6 +21,15 @@ pub struct Quad {
 
     /// The border width of the [`Quad`].
     pub border_width: f32,
+
+    /// The shadow color of the [`Quad`], in __linear RGB__.
+    pub shadow_color: [f32; 4],
+
+    /// The shadow offset of the [`Quad`].
+    pub shadow_offset: [f32; 2],
+
+    /// The shadow blur radius of the [`Quad`].
+    pub shadow_blur_radius: f32,
 }
 
 #[allow(unsafe_code)]
@@ -28,3 +37,30 @@ unsafe impl bytemuck::Zeroable for Quad {}
 
 #[allow(unsafe_code)]
 unsafe impl bytemuck::Pod for Quad {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fields_match_vertex_attributes() {
+        // The quad pipelines read the fields as consecutive floats, in order
+        let quad = Quad {
+            position: [0.0, 1.0],
+            size: [2.0, 3.0],
+            color: [4.0, 5.0, 6.0, 7.0],
+            border_color: [8.0, 9.0, 10.0, 11.0],
+            border_radius: [12.0, 13.0, 14.0, 15.0],
+            border_width: 16.0,
+            shadow_color: [17.0, 18.0, 19.0, 20.0],
+            shadow_offset: [21.0, 22.0],
+            shadow_blur_radius: 23.0,
+        };
+
+        let floats: [f32; 24] = bytemuck::cast(quad);
+
+        for (i, float) in floats.into_iter().enumerate() {
+            assert_eq!(float, i as f32);
+        }
+    }
+}
@@ -28,6 +28,7 @@ mod transformation;
 mod viewport;
 
 pub mod backend;
+pub mod export;
 pub mod font;
 pub mod gradient;
 pub mod image;
@@ -51,5 +52,5 @@ pub use window::compositor;
 
 pub use iced_native::alignment;
 pub use iced_native::{
-    Alignment, Background, Color, Font, Point, Rectangle, Size, Vector,
+    Alignment, Background, Color, Font, Padding, Point, Rectangle, Size, Vector,
 };
@@ -1,11 +1,15 @@
 use iced_native::image;
 use iced_native::svg;
-use iced_native::{Background, Color, Font, Rectangle, Size, Vector};
+use iced_native::{
+    Background, Color, Font, Padding, Rectangle, Shadow, Size, Vector,
+};
 
 use crate::alignment;
 use crate::gradient::Gradient;
 use crate::triangle;
+use crate::Transformation;
 
+use std::any::Any;
 use std::sync::Arc;
 
 /// A rendering primitive.
@@ -48,11 +52,28 @@ pub enum Primitive {
         border_width: f32,
         /// The border color of the quad
         border_color: Color,
+        /// The shadow of the quad
+        shadow: Shadow,
     },
     /// An image primitive
     Image {
         /// The handle of the image
         handle: image::Handle,
+        /// The filtering method of the image
+        filter_method: image::FilterMethod,
+        /// The opacity of the image, between `0.0` and `1.0`
+        opacity: f32,
+        /// The clockwise rotation of the image around its center, in radians
+        rotation: f32,
+        /// The bounds of the image
+        bounds: Rectangle,
+    },
+    /// A nine-slice image primitive
+    NineSlice {
+        /// The handle of the image
+        handle: image::Handle,
+        /// The insets of the image, in pixels, delimiting its slices
+        insets: Padding,
         /// The bounds of the image
         bounds: Rectangle,
     },
@@ -64,6 +85,9 @@ pub enum Primitive {
         /// The [`Color`] filter
         color: Option<Color>,
 
+        /// The [`svg::Stylesheet`] applied to the SVG
+        stylesheet: Option<svg::Stylesheet>,
+
         /// The bounds of the viewport
         bounds: Rectangle,
     },
@@ -82,6 +106,60 @@ pub enum Primitive {
         /// The primitive to translate
         content: Box<Primitive>,
     },
+    /// A primitive that applies an arbitrary 2D [`Transformation`]
+    Transform {
+        /// The transformation to apply
+        transformation: Transformation,
+
+        /// The primitive to transform
+        content: Box<Primitive>,
+    },
+    /// A primitive that composites its content as a group with some opacity
+    Opacity {
+        /// The opacity of the group, from `0.0` to `1.0`
+        opacity: f32,
+
+        /// The content of the group
+        content: Box<Primitive>,
+    },
+    /// A primitive that masks its content with the alpha of another
+    /// primitive
+    Mask {
+        /// The bounds of the masked content
+        bounds: Rectangle,
+
+        /// The primitive whose alpha is used as the mask
+        mask: Box<Primitive>,
+
+        /// The content to mask
+        content: Box<Primitive>,
+    },
+    /// A primitive that blurs its content
+    Blur {
+        /// The bounds where the blurred content is drawn
+        bounds: Rectangle,
+
+        /// The blur radius, in logical pixels
+        radius: f32,
+
+        /// The content to blur
+        content: Box<Primitive>,
+    },
+    /// A primitive that blurs whatever has been drawn behind its bounds
+    /// before drawing its content on top
+    BackdropBlur {
+        /// The bounds of the blurred region
+        bounds: Rectangle,
+
+        /// The blur radius, in logical pixels
+        radius: f32,
+
+        /// The border radius of the blurred region
+        border_radius: [f32; 4],
+
+        /// The content drawn on top of the blurred region
+        content: Box<Primitive>,
+    },
     /// A low-level primitive to render a mesh of triangles with a solid color.
     ///
     /// It can be used to render many kinds of geometry freely.
@@ -117,4 +195,16 @@ pub enum Primitive {
         /// The cached primitive
         cache: Arc<Primitive>,
     },
+    /// A primitive drawn by the [`Backend`] with its own logic, like the
+    /// ones produced by the `Shader` widget of `iced_wgpu`.
+    ///
+    /// Backends ignore the custom primitives they do not know.
+    ///
+    /// [`Backend`]: crate::Backend
+    Custom {
+        /// The bounds of the primitive
+        bounds: Rectangle,
+        /// The backend-specific primitive
+        primitive: Arc<dyn Any + Send + Sync>,
+    },
 }
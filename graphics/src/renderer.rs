@@ -1,12 +1,14 @@
 //! Create a renderer from a [`Backend`].
 use crate::backend::{self, Backend};
-use crate::{Primitive, Vector};
+use crate::{Primitive, Transformation, Vector};
 use iced_native::image;
 use iced_native::layout;
 use iced_native::renderer;
 use iced_native::svg;
 use iced_native::text::{self, Text};
-use iced_native::{Background, Color, Element, Font, Point, Rectangle, Size};
+use iced_native::{
+    Background, Color, Element, Font, Padding, Point, Rectangle, Shadow, Size,
+};
 
 pub use iced_native::renderer::Style;
 
@@ -40,6 +42,31 @@ impl<B: Backend, T> Renderer<B, T> {
         self.primitives.push(primitive);
     }
 
+    /// Applies a [`Transformation`] to the primitives recorded in the given
+    /// closure.
+    ///
+    /// The [`Transformation`] is applied in logical coordinates, before any
+    /// scaling.
+    pub fn with_transformation(
+        &mut self,
+        transformation: Transformation,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let current_primitives = std::mem::take(&mut self.primitives);
+
+        f(self);
+
+        let layer_primitives =
+            std::mem::replace(&mut self.primitives, current_primitives);
+
+        self.primitives.push(Primitive::Transform {
+            transformation,
+            content: Box::new(Primitive::Group {
+                primitives: layer_primitives,
+            }),
+        });
+    }
+
     /// Runs the given closure with the [`Backend`] and the recorded primitives
     /// of the [`Renderer`].
     pub fn with_primitives(&mut self, f: impl FnOnce(&mut B, &[Primitive])) {
@@ -81,6 +108,42 @@ where
         });
     }
 
+    fn with_rounded_layer(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: renderer::BorderRadius,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let border_radius: [f32; 4] = border_radius.into();
+
+        if border_radius.iter().all(|radius| *radius <= 0.0) {
+            self.with_layer(bounds, f);
+            return;
+        }
+
+        let current_primitives = std::mem::take(&mut self.primitives);
+
+        f(self);
+
+        let layer_primitives =
+            std::mem::replace(&mut self.primitives, current_primitives);
+
+        self.primitives.push(Primitive::Mask {
+            bounds,
+            mask: Box::new(Primitive::Quad {
+                bounds,
+                background: Background::Color(Color::WHITE),
+                border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            }),
+            content: Box::new(Primitive::Group {
+                primitives: layer_primitives,
+            }),
+        });
+    }
+
     fn with_translation(
         &mut self,
         translation: Vector,
@@ -101,6 +164,68 @@ where
         });
     }
 
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self)) {
+        let current_primitives = std::mem::take(&mut self.primitives);
+
+        f(self);
+
+        let layer_primitives =
+            std::mem::replace(&mut self.primitives, current_primitives);
+
+        self.primitives.push(Primitive::Opacity {
+            opacity,
+            content: Box::new(Primitive::Group {
+                primitives: layer_primitives,
+            }),
+        });
+    }
+
+    fn with_blur(
+        &mut self,
+        bounds: Rectangle,
+        radius: f32,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let current_primitives = std::mem::take(&mut self.primitives);
+
+        f(self);
+
+        let layer_primitives =
+            std::mem::replace(&mut self.primitives, current_primitives);
+
+        self.primitives.push(Primitive::Blur {
+            bounds,
+            radius,
+            content: Box::new(Primitive::Group {
+                primitives: layer_primitives,
+            }),
+        });
+    }
+
+    fn with_backdrop_blur(
+        &mut self,
+        bounds: Rectangle,
+        radius: f32,
+        border_radius: renderer::BorderRadius,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let current_primitives = std::mem::take(&mut self.primitives);
+
+        f(self);
+
+        let layer_primitives =
+            std::mem::replace(&mut self.primitives, current_primitives);
+
+        self.primitives.push(Primitive::BackdropBlur {
+            bounds,
+            radius,
+            border_radius: border_radius.into(),
+            content: Box::new(Primitive::Group {
+                primitives: layer_primitives,
+            }),
+        });
+    }
+
     fn fill_quad(
         &mut self,
         quad: renderer::Quad,
@@ -112,6 +237,7 @@ where
             border_radius: quad.border_radius.into(),
             border_width: quad.border_width,
             border_color: quad.border_color,
+            shadow: quad.shadow,
         });
     }
 
@@ -186,8 +312,38 @@ where
         self.backend().dimensions(handle)
     }
 
-    fn draw(&mut self, handle: image::Handle, bounds: Rectangle) {
-        self.draw_primitive(Primitive::Image { handle, bounds })
+    fn status(&self, handle: &image::Handle) -> image::Status {
+        self.backend().status(handle)
+    }
+
+    fn draw(
+        &mut self,
+        handle: image::Handle,
+        filter_method: image::FilterMethod,
+        opacity: f32,
+        rotation: f32,
+        bounds: Rectangle,
+    ) {
+        self.draw_primitive(Primitive::Image {
+            handle,
+            filter_method,
+            opacity,
+            rotation,
+            bounds,
+        })
+    }
+
+    fn draw_nine_slice(
+        &mut self,
+        handle: image::Handle,
+        insets: Padding,
+        bounds: Rectangle,
+    ) {
+        self.draw_primitive(Primitive::NineSlice {
+            handle,
+            insets,
+            bounds,
+        })
     }
 }
 
@@ -203,11 +359,13 @@ where
         &mut self,
         handle: svg::Handle,
         color: Option<Color>,
+        stylesheet: Option<svg::Stylesheet>,
         bounds: Rectangle,
     ) {
         self.draw_primitive(Primitive::Svg {
             handle,
             color,
+            stylesheet,
             bounds,
         })
     }
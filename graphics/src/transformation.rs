@@ -1,4 +1,6 @@
-use glam::{Mat4, Vec3};
+use crate::{Point, Rectangle};
+
+use glam::{Mat4, Vec3, Vec4};
 use std::ops::Mul;
 
 /// A 2D transformation matrix.
@@ -30,6 +32,109 @@ impl Transformation {
     pub fn scale(x: f32, y: f32) -> Transformation {
         Transformation(Mat4::from_scale(Vec3::new(x, y, 1.0)))
     }
+
+    /// Creates a rotate transformation, given an angle in radians.
+    ///
+    /// Positive angles rotate clockwise, since the y axis points down.
+    pub fn rotate(angle: f32) -> Transformation {
+        Transformation(Mat4::from_rotation_z(angle))
+    }
+
+    /// Creates a skew transformation, given the angles in radians between
+    /// each skewed axis and its original direction.
+    pub fn skew(x: f32, y: f32) -> Transformation {
+        Transformation(Mat4::from_cols(
+            Vec4::new(1.0, y.tan(), 0.0, 0.0),
+            Vec4::new(x.tan(), 1.0, 0.0, 0.0),
+            Vec4::Z,
+            Vec4::W,
+        ))
+    }
+
+    /// Creates a 2D affine transformation from the coefficients of its
+    /// matrix, mapping `(x, y)` to `(a * x + c * y + e, b * x + d * y + f)`.
+    pub fn affine(
+        a: f32,
+        b: f32,
+        c: f32,
+        d: f32,
+        e: f32,
+        f: f32,
+    ) -> Transformation {
+        Transformation(Mat4::from_cols(
+            Vec4::new(a, b, 0.0, 0.0),
+            Vec4::new(c, d, 0.0, 0.0),
+            Vec4::Z,
+            Vec4::new(e, f, 0.0, 1.0),
+        ))
+    }
+
+    /// Returns the inverse of the [`Transformation`], if it exists.
+    pub fn inverse(&self) -> Option<Transformation> {
+        let determinant = self.0.determinant();
+
+        if determinant.abs() > f32::EPSILON {
+            Some(Transformation(self.0.inverse()))
+        } else {
+            None
+        }
+    }
+
+    /// Applies the [`Transformation`] to the given [`Point`].
+    pub fn transform_point(&self, point: Point) -> Point {
+        let point = self.0.transform_point3(Vec3::new(point.x, point.y, 0.0));
+
+        Point::new(point.x, point.y)
+    }
+
+    /// Applies the [`Transformation`] to the given [`Rectangle`] and returns
+    /// the smallest [`Rectangle`] containing the result.
+    pub fn transform_rectangle(&self, rectangle: Rectangle) -> Rectangle {
+        if *self == Self::identity() {
+            return rectangle;
+        }
+
+        let corners = [
+            Point::new(rectangle.x, rectangle.y),
+            Point::new(rectangle.x + rectangle.width, rectangle.y),
+            Point::new(rectangle.x, rectangle.y + rectangle.height),
+            Point::new(
+                rectangle.x + rectangle.width,
+                rectangle.y + rectangle.height,
+            ),
+        ]
+        .map(|corner| self.transform_point(corner));
+
+        let (min_x, max_x, min_y, max_y) = corners.iter().fold(
+            (
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+            ),
+            |(min_x, max_x, min_y, max_y), corner| {
+                (
+                    min_x.min(corner.x),
+                    max_x.max(corner.x),
+                    min_y.min(corner.y),
+                    max_y.max(corner.y),
+                )
+            },
+        );
+
+        Rectangle {
+            x: min_x,
+            y: min_y,
+            width: max_x - min_x,
+            height: max_y - min_y,
+        }
+    }
+}
+
+impl Default for Transformation {
+    fn default() -> Self {
+        Self::identity()
+    }
 }
 
 impl Mul for Transformation {
@@ -57,3 +162,44 @@ impl From<Transformation> for Mat4 {
         transformation.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_maps_points_back() {
+        let transformation = Transformation::translate(10.0, 20.0)
+            * Transformation::rotate(std::f32::consts::FRAC_PI_2)
+            * Transformation::scale(2.0, 2.0);
+
+        let point = transformation.transform_point(Point::new(1.0, 0.0));
+
+        assert!((point.x - 10.0).abs() < 1e-5);
+        assert!((point.y - 22.0).abs() < 1e-5);
+
+        let inverse = transformation.inverse().unwrap();
+        let original = inverse.transform_point(point);
+
+        assert!((original.x - 1.0).abs() < 1e-5);
+        assert!(original.y.abs() < 1e-5);
+
+        assert_eq!(Transformation::scale(0.0, 1.0).inverse(), None);
+    }
+
+    #[test]
+    fn affine_matches_composition() {
+        let (sin, cos) = 0.5f32.sin_cos();
+
+        let affine = Transformation::affine(cos, sin, -sin, cos, 10.0, 20.0);
+        let composition =
+            Transformation::translate(10.0, 20.0) * Transformation::rotate(0.5);
+
+        let point = Point::new(3.0, 4.0);
+        let expected = composition.transform_point(point);
+        let actual = affine.transform_point(point);
+
+        assert!((actual.x - expected.x).abs() < 1e-5);
+        assert!((actual.y - expected.y).abs() < 1e-5);
+    }
+}
@@ -14,3 +14,8 @@ pub mod qr_code;
 #[cfg(feature = "qr_code")]
 #[doc(no_inline)]
 pub use qr_code::QRCode;
+
+pub mod transform;
+
+#[doc(no_inline)]
+pub use transform::Transform;
@@ -1,9 +1,11 @@
 use crate::gradient::Gradient;
 use crate::triangle;
 use crate::widget::canvas::{path, Fill, Geometry, Path, Stroke, Style, Text};
-use crate::Primitive;
+use crate::{Primitive, Transformation};
 
-use iced_native::{Point, Rectangle, Size, Vector};
+use iced_native::image;
+use iced_native::svg;
+use iced_native::{Color, Point, Rectangle, Size, Vector};
 
 use lyon::geom::euclid;
 use lyon::tessellation;
@@ -337,6 +339,108 @@ impl Frame {
         });
     }
 
+    /// Draws the image of the given [`image::Handle`] on the [`Frame`],
+    /// stretched to fit the given bounds.
+    ///
+    /// The image follows the current transform of the [`Frame`]. However,
+    /// like text, it will be drawn on top of the geometry of the [`Frame`].
+    pub fn draw_image(
+        &mut self,
+        bounds: Rectangle,
+        handle: impl Into<image::Handle>,
+    ) {
+        let handle = handle.into();
+
+        self.push_transformed(bounds, |bounds| Primitive::Image {
+            handle,
+            filter_method: image::FilterMethod::default(),
+            opacity: 1.0,
+            rotation: 0.0,
+            bounds,
+        });
+    }
+
+    /// Draws the vector graphic of the given [`svg::Handle`] on the
+    /// [`Frame`], stretched to fit the given bounds and optionally filled
+    /// with a [`Color`].
+    ///
+    /// The vector graphic follows the current transform of the [`Frame`].
+    /// However, like text, it will be drawn on top of the geometry of the
+    /// [`Frame`].
+    pub fn draw_svg(
+        &mut self,
+        bounds: Rectangle,
+        handle: impl Into<svg::Handle>,
+        color: impl Into<Option<Color>>,
+    ) {
+        let handle = handle.into();
+        let color = color.into();
+
+        self.push_transformed(bounds, |bounds| Primitive::Svg {
+            handle,
+            color,
+            stylesheet: None,
+            bounds,
+        });
+    }
+
+    /// Pushes the [`Primitive`] produced for the given bounds, following
+    /// the current transform of the [`Frame`].
+    fn push_transformed(
+        &mut self,
+        bounds: Rectangle,
+        primitive: impl FnOnce(Rectangle) -> Primitive,
+    ) {
+        let transform = self.transforms.current;
+        let raw = transform.raw;
+
+        // Translations and positive scales can be applied to the bounds
+        // directly, without drawing the primitive in a separate layer
+        if transform.is_identity
+            || (raw.m12 == 0.0
+                && raw.m21 == 0.0
+                && raw.m11 > 0.0
+                && raw.m22 > 0.0)
+        {
+            self.primitives.push(primitive(Rectangle {
+                x: raw.m11 * bounds.x + raw.m31,
+                y: raw.m22 * bounds.y + raw.m32,
+                width: raw.m11 * bounds.width,
+                height: raw.m22 * bounds.height,
+            }));
+
+            return;
+        }
+
+        let transformation = Transformation::affine(
+            raw.m11, raw.m12, raw.m21, raw.m22, raw.m31, raw.m32,
+        );
+
+        // Consecutive primitives sharing a transform are grouped, so they
+        // are all drawn in the same layer
+        if let Some(Primitive::Transform {
+            transformation: last,
+            content,
+        }) = self.primitives.last_mut()
+        {
+            match content.as_mut() {
+                Primitive::Group { primitives } if *last == transformation => {
+                    primitives.push(primitive(bounds));
+
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        self.primitives.push(Primitive::Transform {
+            transformation,
+            content: Box::new(Primitive::Group {
+                primitives: vec![primitive(bounds)],
+            }),
+        });
+    }
+
     /// Stores the current transform of the [`Frame`] and executes the given
     /// drawing operations, restoring the transform afterwards.
     ///
@@ -365,9 +469,13 @@ impl Frame {
 
         let primitives = frame.into_primitives();
 
-        let (text, meshes) = primitives
-            .into_iter()
-            .partition(|primitive| matches!(primitive, Primitive::Text { .. }));
+        // Meshes are already clipped to the size of the frame
+        let (meshes, clipped) = primitives.into_iter().partition(|primitive| {
+            matches!(
+                primitive,
+                Primitive::SolidMesh { .. } | Primitive::GradientMesh { .. }
+            )
+        });
 
         let translation = Vector::new(region.x, region.y);
 
@@ -382,7 +490,7 @@ impl Frame {
                     content: Box::new(Primitive::Clip {
                         bounds: Rectangle::with_size(region.size()),
                         content: Box::new(Primitive::Group {
-                            primitives: text,
+                            primitives: clipped,
                         }),
                     }),
                 },
@@ -390,6 +498,33 @@ impl Frame {
         });
     }
 
+    /// Executes the given drawing operations inside the given [`Path`],
+    /// clipping anything outside of it with antialiased edges.
+    ///
+    /// The [`Path`] and the drawing operations follow the current transform
+    /// of the [`Frame`]. Any transformations performed are local to the
+    /// provided closure.
+    pub fn with_clip_path(&mut self, path: &Path, f: impl FnOnce(&mut Frame)) {
+        let mut content = Frame::new(self.size);
+        content.transforms.current = self.transforms.current;
+
+        f(&mut content);
+
+        let mut mask = Frame::new(self.size);
+        mask.transforms.current = self.transforms.current;
+        mask.fill(path, Color::WHITE);
+
+        self.primitives.push(Primitive::Mask {
+            bounds: Rectangle::with_size(self.size),
+            mask: Box::new(Primitive::Group {
+                primitives: mask.into_primitives(),
+            }),
+            content: Box::new(Primitive::Group {
+                primitives: content.into_primitives(),
+            }),
+        });
+    }
+
     /// Applies a translation to the current transform of the [`Frame`].
     #[inline]
     pub fn translate(&mut self, translation: Vector) {
@@ -528,3 +663,121 @@ impl tessellation::StrokeVertexConstructor<triangle::ColoredVertex2D>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw_tiles(frame: &mut Frame) {
+        for x in 0..3 {
+            frame.draw_image(
+                Rectangle::new(
+                    Point::new(x as f32 * 10.0, 0.0),
+                    Size::new(10.0, 10.0),
+                ),
+                image::Handle::from_pixels(1, 1, vec![0; 4]),
+            );
+        }
+    }
+
+    #[test]
+    fn images_under_translations_and_scales_are_moved() {
+        let mut frame = Frame::new(Size::new(100.0, 100.0));
+
+        frame.translate(Vector::new(5.0, 5.0));
+        frame.scale(2.0);
+        draw_tiles(&mut frame);
+
+        let bounds: Vec<_> = frame
+            .primitives
+            .iter()
+            .map(|primitive| match primitive {
+                Primitive::Image { bounds, .. } => *bounds,
+                _ => panic!("unexpected primitive: {primitive:?}"),
+            })
+            .collect();
+
+        assert_eq!(
+            bounds,
+            [
+                Rectangle::new(Point::new(5.0, 5.0), Size::new(20.0, 20.0)),
+                Rectangle::new(Point::new(25.0, 5.0), Size::new(20.0, 20.0)),
+                Rectangle::new(Point::new(45.0, 5.0), Size::new(20.0, 20.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn images_sharing_a_rotation_are_grouped() {
+        let mut frame = Frame::new(Size::new(100.0, 100.0));
+
+        frame.rotate(0.5);
+        draw_tiles(&mut frame);
+
+        frame.rotate(0.5);
+        draw_tiles(&mut frame);
+
+        let groups: Vec<_> = frame
+            .primitives
+            .iter()
+            .map(|primitive| match primitive {
+                Primitive::Transform { content, .. } => {
+                    match content.as_ref() {
+                        Primitive::Group { primitives } => primitives.len(),
+                        _ => panic!("unexpected content: {content:?}"),
+                    }
+                }
+                _ => panic!("unexpected primitive: {primitive:?}"),
+            })
+            .collect();
+
+        assert_eq!(groups, [3, 3]);
+    }
+
+    #[test]
+    fn clip_paths_mask_content_drawn_with_the_same_transform() {
+        let mut frame = Frame::new(Size::new(100.0, 100.0));
+
+        frame.translate(Vector::new(5.0, 5.0));
+        frame.with_clip_path(
+            &Path::rectangle(Point::ORIGIN, Size::new(10.0, 10.0)),
+            draw_tiles,
+        );
+
+        let (bounds, mask, content) = match &frame.primitives[..] {
+            [Primitive::Mask {
+                bounds,
+                mask,
+                content,
+            }] => (*bounds, mask.as_ref(), content.as_ref()),
+            primitives => panic!("unexpected primitives: {primitives:?}"),
+        };
+
+        assert_eq!(bounds, Rectangle::with_size(Size::new(100.0, 100.0)));
+
+        match mask {
+            Primitive::Group { primitives } => match &primitives[..] {
+                [Primitive::SolidMesh { buffers, .. }] => {
+                    assert!(buffers.vertices.iter().all(|vertex| {
+                        (5.0..=15.0).contains(&vertex.position[0])
+                            && (5.0..=15.0).contains(&vertex.position[1])
+                    }));
+                }
+                primitives => panic!("unexpected mask: {primitives:?}"),
+            },
+            _ => panic!("unexpected mask: {mask:?}"),
+        }
+
+        match content {
+            Primitive::Group { primitives } => {
+                assert_eq!(primitives.len(), 3);
+                assert!(matches!(
+                    primitives[0],
+                    Primitive::Image { bounds, .. }
+                        if bounds.position() == Point::new(5.0, 5.0)
+                ));
+            }
+            _ => panic!("unexpected content: {content:?}"),
+        }
+    }
+}
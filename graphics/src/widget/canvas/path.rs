@@ -7,11 +7,21 @@ mod builder;
 pub use arc::Arc;
 pub use builder::Builder;
 
-use crate::widget::canvas::LineDash;
+use crate::widget::canvas::{FillRule, LineDash};
+use crate::Transformation;
 
-use iced_native::{Point, Size};
+use iced_native::{Point, Rectangle, Size, Vector};
+use lyon::algorithms::aabb::bounding_box;
+use lyon::algorithms::hit_test::hit_test_path;
+use lyon::algorithms::length::approximate_length;
+use lyon::algorithms::measure::{PathMeasurements, SampleType};
 use lyon::algorithms::walk::{walk_along_path, RepeatedPattern, WalkerEvent};
 use lyon::path::iterator::PathIterator;
+use lyon::path::PathEvent;
+
+/// The maximum distance between a curve and the line segments that
+/// approximate it when measuring a [`Path`].
+const TOLERANCE: f32 = lyon::tessellation::FillOptions::DEFAULT_TOLERANCE;
 
 /// An immutable set of points that may or may not be connected.
 ///
@@ -55,6 +65,106 @@ impl Path {
         Self::new(|p| p.circle(center, radius))
     }
 
+    /// Returns true if the given [`Point`] is inside the [`Path`] when it is
+    /// filled with the given [`FillRule`].
+    pub fn contains(&self, point: Point, rule: FillRule) -> bool {
+        hit_test_path(
+            &lyon::math::Point::new(point.x, point.y),
+            self.raw.iter(),
+            rule.into(),
+            TOLERANCE,
+        )
+    }
+
+    /// Returns true if the given [`Point`] is covered by the stroke of the
+    /// [`Path`] with the given width.
+    ///
+    /// Line caps and joins are not taken into account.
+    pub fn stroke_contains(&self, point: Point, width: f32) -> bool {
+        let point = lyon::math::Point::new(point.x, point.y);
+        let half_width = width / 2.0;
+
+        self.raw
+            .iter()
+            .flattened(TOLERANCE)
+            .filter_map(|event| match event {
+                PathEvent::Line { from, to } => Some((from, to)),
+                PathEvent::End {
+                    last,
+                    first,
+                    close: true,
+                } => Some((last, first)),
+                _ => None,
+            })
+            .any(|(from, to)| {
+                lyon::geom::LineSegment { from, to }.distance_to_point(point)
+                    <= half_width
+            })
+    }
+
+    /// Returns the smallest [`Rectangle`] containing the [`Path`].
+    pub fn bounds(&self) -> Rectangle {
+        let bounds = bounding_box(self.raw.iter());
+
+        Rectangle {
+            x: bounds.min.x,
+            y: bounds.min.y,
+            width: bounds.width(),
+            height: bounds.height(),
+        }
+    }
+
+    /// Returns the approximate length of the [`Path`].
+    pub fn length(&self) -> f32 {
+        approximate_length(self.raw.iter(), TOLERANCE)
+    }
+
+    /// Returns the [`Point`] at the given distance along the [`Path`], if it
+    /// is not empty.
+    ///
+    /// The distance is clamped to the length of the [`Path`].
+    pub fn point_at_length(&self, distance: f32) -> Option<Point> {
+        let position = self.sample(distance)?.0;
+
+        Some(Point::new(position.x, position.y))
+    }
+
+    /// Returns the unit tangent at the given distance along the [`Path`], if
+    /// it has any length.
+    ///
+    /// The distance is clamped to the length of the [`Path`].
+    pub fn tangent_at_length(&self, distance: f32) -> Option<Vector> {
+        let tangent = self.sample(distance)?.1;
+
+        (tangent.x.is_finite() && tangent.y.is_finite())
+            .then(|| Vector::new(tangent.x, tangent.y))
+    }
+
+    /// Returns a copy of the [`Path`] with the given [`Transformation`]
+    /// applied to all of its points.
+    pub fn transform(&self, transformation: &Transformation) -> Path {
+        let matrix = transformation.as_ref();
+
+        self.transformed(&lyon::math::Transform::new(
+            matrix[0], matrix[1], matrix[4], matrix[5], matrix[12], matrix[13],
+        ))
+    }
+
+    fn sample(
+        &self,
+        distance: f32,
+    ) -> Option<(lyon::math::Point, lyon::math::Vector)> {
+        let measurements = PathMeasurements::from_path(&self.raw, TOLERANCE);
+        let mut sampler =
+            measurements.create_sampler(&self.raw, SampleType::Distance);
+
+        let sample = sampler.sample(distance);
+        let position = sample.position();
+
+        (position.x.is_finite() && position.y.is_finite())
+            .then(|| (position, sample.tangent()))
+    }
+
     #[inline]
     pub(crate) fn raw(&self) -> &lyon::path::Path {
         &self.raw
@@ -107,3 +217,43 @@ pub(super) fn dashed(path: &Path, line_dash: LineDash<'_>) -> Path {
         );
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hit_tests_fill_and_stroke() {
+        let square = Path::rectangle(Point::ORIGIN, Size::new(10.0, 10.0));
+
+        assert!(square.contains(Point::new(5.0, 5.0), FillRule::NonZero));
+        assert!(!square.contains(Point::new(15.0, 5.0), FillRule::NonZero));
+
+        assert!(square.stroke_contains(Point::new(5.0, 0.5), 2.0));
+        assert!(square.stroke_contains(Point::new(0.5, 5.0), 2.0));
+        assert!(!square.stroke_contains(Point::new(5.0, 5.0), 2.0));
+    }
+
+    #[test]
+    fn measures_and_samples() {
+        let line = Path::line(Point::ORIGIN, Point::new(10.0, 0.0));
+
+        assert_eq!(
+            line.bounds(),
+            Rectangle::new(Point::ORIGIN, Size::new(10.0, 0.0))
+        );
+        assert!((line.length() - 10.0).abs() < 1e-3);
+
+        let point = line.point_at_length(4.0).unwrap();
+        assert!((point.x - 4.0).abs() < 1e-3);
+
+        let tangent = line.tangent_at_length(4.0).unwrap();
+        assert!((tangent.x - 1.0).abs() < 1e-3);
+
+        let empty = Path::new(|_| {});
+        assert_eq!(empty.point_at_length(1.0), None);
+
+        let moved = line.transform(&Transformation::translate(0.0, 5.0));
+        assert_eq!(moved.bounds().y, 5.0);
+    }
+}
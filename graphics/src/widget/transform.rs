@@ -0,0 +1,249 @@
+//! Rotate, scale or skew a widget.
+pub use crate::Transformation;
+
+use crate::{Backend, Renderer};
+
+use iced_native::event::{self, Event};
+use iced_native::layout::{self, Layout};
+use iced_native::mouse;
+use iced_native::overlay;
+use iced_native::renderer;
+use iced_native::touch;
+use iced_native::widget::{Operation, Tree};
+use iced_native::{
+    Clipboard, Element, Length, Point, Rectangle, Shell, Widget,
+};
+
+/// A widget that applies a [`Transformation`] to its content.
+///
+/// The [`Transformation`] is relative to the top-left corner of the content.
+/// It only affects how the content is drawn and where it receives the cursor;
+/// the layout of the content stays the same.
+///
+/// Overlays of the content, like the menu of a `PickList`, are not
+/// transformed.
+#[allow(missing_debug_implementations)]
+pub struct Transform<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    transformation: Transformation,
+}
+
+impl<'a, Message, Renderer> Transform<'a, Message, Renderer> {
+    /// Creates a [`Transform`] with the given content and [`Transformation`].
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        transformation: Transformation,
+    ) -> Self {
+        Transform {
+            content: content.into(),
+            transformation,
+        }
+    }
+
+    /// Returns the [`Transformation`] of the content in absolute
+    /// coordinates, given its bounds.
+    fn absolute(&self, bounds: Rectangle) -> Transformation {
+        Transformation::translate(bounds.x, bounds.y)
+            * self.transformation
+            * Transformation::translate(-bounds.x, -bounds.y)
+    }
+
+    /// Maps the given cursor position to the space of the content.
+    fn cursor(&self, bounds: Rectangle, cursor_position: Point) -> Point {
+        self.absolute(bounds)
+            .inverse()
+            .map(|inverse| inverse.transform_point(cursor_position))
+            .unwrap_or(Point::new(-1.0, -1.0))
+    }
+
+    /// Maps the given viewport to the space of the content.
+    fn viewport(&self, bounds: Rectangle, viewport: &Rectangle) -> Rectangle {
+        self.absolute(bounds)
+            .inverse()
+            .map(|inverse| inverse.transform_rectangle(*viewport))
+            .unwrap_or(*viewport)
+    }
+
+    /// Maps the positions of the given [`Event`] to the space of the content.
+    fn event(&self, bounds: Rectangle, event: Event) -> Event {
+        let map = |position| self.cursor(bounds, position);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                Event::Mouse(mouse::Event::CursorMoved {
+                    position: map(position),
+                })
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                Event::Touch(touch::Event::FingerPressed {
+                    id,
+                    position: map(position),
+                })
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                Event::Touch(touch::Event::FingerMoved {
+                    id,
+                    position: map(position),
+                })
+            }
+            Event::Touch(touch::Event::FingerLifted { id, position }) => {
+                Event::Touch(touch::Event::FingerLifted {
+                    id,
+                    position: map(position),
+                })
+            }
+            Event::Touch(touch::Event::FingerLost { id, position }) => {
+                Event::Touch(touch::Event::FingerLost {
+                    id,
+                    position: map(position),
+                })
+            }
+            _ => event,
+        }
+    }
+}
+
+impl<'a, Message, B, T> Widget<Message, Renderer<B, T>>
+    for Transform<'a, Message, Renderer<B, T>>
+where
+    B: Backend,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer<B, T>,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer<B, T>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer<B, T>,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        let event = self.event(bounds, event);
+        let cursor_position = self.cursor(bounds, cursor_position);
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer<B, T>,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            self.cursor(bounds, cursor_position),
+            &self.viewport(bounds, viewport),
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<B, T>,
+        theme: &T,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let cursor_position = self.cursor(bounds, cursor_position);
+        let viewport = self.viewport(bounds, viewport);
+
+        renderer.with_transformation(self.absolute(bounds), |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                renderer_style,
+                layout,
+                cursor_position,
+                &viewport,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer<B, T>,
+    ) -> Option<overlay::Element<'b, Message, Renderer<B, T>>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, B, T> From<Transform<'a, Message, Renderer<B, T>>>
+    for Element<'a, Message, Renderer<B, T>>
+where
+    Message: 'a,
+    B: 'a + Backend,
+    T: 'a,
+{
+    fn from(
+        transform: Transform<'a, Message, Renderer<B, T>>,
+    ) -> Element<'a, Message, Renderer<B, T>> {
+        Element::new(transform)
+    }
+}
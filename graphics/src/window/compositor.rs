@@ -1,5 +1,6 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
+use crate::image;
 use crate::{Color, Error, Viewport};
 
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
@@ -43,6 +44,19 @@ pub trait Compositor: Sized {
     /// Returns [`Information`] used by this [`Compositor`].
     fn fetch_information(&self) -> Information;
 
+    /// Returns the [`image::Statistics`] of the image caches of the given
+    /// [`Renderer`].
+    ///
+    /// By default, it returns empty statistics.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn image_statistics(
+        &self,
+        _renderer: &Self::Renderer,
+    ) -> image::Statistics {
+        image::Statistics::default()
+    }
+
     /// Presents the [`Renderer`] primitives to the next frame of the given [`Surface`].
     ///
     /// [`Renderer`]: Self::Renderer
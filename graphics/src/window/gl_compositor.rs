@@ -1,6 +1,7 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
 use crate::compositor::Information;
+use crate::image;
 use crate::{Color, Error, Size, Viewport};
 
 use core::ffi::c_void;
@@ -57,6 +58,19 @@ pub trait GLCompositor: Sized {
     /// Returns [`Information`] used by this [`GLCompositor`].
     fn fetch_information(&self) -> Information;
 
+    /// Returns the [`image::Statistics`] of the image caches of the given
+    /// [`Renderer`].
+    ///
+    /// By default, it returns empty statistics.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn image_statistics(
+        &self,
+        _renderer: &Self::Renderer,
+    ) -> image::Statistics {
+        image::Statistics::default()
+    }
+
     /// Presents the primitives of the [`Renderer`] to the next frame of the
     /// [`GLCompositor`].
     ///
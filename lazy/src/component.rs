@@ -377,6 +377,23 @@ where
             ) {
                 self.operation.custom(state, id);
             }
+
+            fn bounds(&mut self, id: &widget::Id, bounds: Rectangle) {
+                self.operation.bounds(id, bounds);
+            }
+
+            fn text(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                text: &str,
+            ) {
+                self.operation.text(id, bounds, text);
+            }
+
+            fn inspect(&mut self, name: &'static str, bounds: Rectangle) {
+                self.operation.inspect(name, bounds);
+            }
         }
 
         let tree = tree.state.downcast_mut::<Rc<RefCell<Option<Tree>>>>();
@@ -1,4 +1,5 @@
 use crate::clipboard;
+use crate::metrics;
 use crate::system;
 use crate::widget;
 use crate::window;
@@ -27,6 +28,9 @@ pub enum Action<T> {
 
     /// Run a widget action.
     Widget(widget::Action<T>),
+
+    /// Run a metrics action.
+    Metrics(metrics::Action<T>),
 }
 
 impl<T> Action<T> {
@@ -49,6 +53,7 @@ impl<T> Action<T> {
             Self::Window(window) => Action::Window(window.map(f)),
             Self::System(system) => Action::System(system.map(f)),
             Self::Widget(widget) => Action::Widget(widget.map(f)),
+            Self::Metrics(metrics) => Action::Metrics(metrics.map(f)),
         }
     }
 }
@@ -63,6 +68,7 @@ impl<T> fmt::Debug for Action<T> {
             Self::Window(action) => write!(f, "Action::Window({action:?})"),
             Self::System(action) => write!(f, "Action::System({action:?})"),
             Self::Widget(_action) => write!(f, "Action::Widget"),
+            Self::Metrics(action) => write!(f, "Action::Metrics({action:?})"),
         }
     }
 }
@@ -1,7 +1,10 @@
 #![allow(missing_docs)]
+use crate::image;
+use crate::inspector::Inspection;
+use crate::metrics::{Histogram, Metrics, Summary};
 use crate::time;
 
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 
 /// A bunch of time measurements for debugging purposes.
 #[derive(Debug)]
@@ -31,6 +34,14 @@ pub struct Debug {
 
     message_count: usize,
     last_messages: VecDeque<String>,
+    message_durations: BTreeMap<String, TimeBuffer>,
+    current_message: Option<String>,
+
+    image_statistics: image::Statistics,
+
+    is_inspecting: bool,
+    shows_outlines: bool,
+    inspection: Option<Inspection>,
 }
 
 impl Debug {
@@ -63,6 +74,14 @@ impl Debug {
 
             message_count: 0,
             last_messages: VecDeque::new(),
+            message_durations: BTreeMap::new(),
+            current_message: None,
+
+            image_statistics: image::Statistics::default(),
+
+            is_inspecting: false,
+            shows_outlines: false,
+            inspection: None,
         }
     }
 
@@ -70,6 +89,25 @@ impl Debug {
         self.is_enabled = !self.is_enabled;
     }
 
+    pub fn toggle_inspector(&mut self) {
+        self.is_inspecting = !self.is_inspecting;
+        self.inspection = None;
+    }
+
+    pub fn toggle_outlines(&mut self) {
+        self.shows_outlines = !self.shows_outlines;
+    }
+
+    /// Returns whether the widgets should be inspected, either to highlight
+    /// the one under the cursor or to outline all of them.
+    pub fn is_inspecting(&self) -> bool {
+        self.is_inspecting || self.shows_outlines
+    }
+
+    pub fn shows_outlines(&self) -> bool {
+        self.shows_outlines
+    }
+
     pub fn startup_started(&mut self) {
         self.startup_start = time::Instant::now();
     }
@@ -83,8 +121,16 @@ impl Debug {
     }
 
     pub fn update_finished(&mut self) {
-        self.update_durations
-            .push(time::Instant::now() - self.update_start);
+        let duration = time::Instant::now() - self.update_start;
+
+        self.update_durations.push(duration);
+
+        if let Some(message) = self.current_message.take() {
+            self.message_durations
+                .entry(message)
+                .or_insert_with(|| TimeBuffer::new(200))
+                .push(duration);
+        }
     }
 
     pub fn view_started(&mut self) {
@@ -133,7 +179,17 @@ impl Debug {
     }
 
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
-        self.last_messages.push_back(format!("{message:?}"));
+        let message = format!("{message:?}");
+
+        // Name messages after their variant, falling back to their type
+        let name = message
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .next()
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| std::any::type_name::<Message>());
+
+        self.current_message = Some(name.to_owned());
+        self.last_messages.push_back(message);
 
         if self.last_messages.len() > 10 {
             let _ = self.last_messages.pop_front();
@@ -142,17 +198,51 @@ impl Debug {
         self.message_count += 1;
     }
 
-    pub fn overlay(&self) -> Vec<String> {
-        if !self.is_enabled {
-            return Vec::new();
+    /// Returns the [`Metrics`] of the recent timings.
+    pub fn metrics(&self) -> Metrics {
+        Metrics {
+            update: Summary::new(self.update_durations.samples()),
+            view: Summary::new(self.view_durations.samples()),
+            layout: Summary::new(self.layout_durations.samples()),
+            event_processing: Summary::new(self.event_durations.samples()),
+            draw: Summary::new(self.draw_durations.samples()),
+            render: Summary::new(self.render_durations.samples()),
+            messages: self
+                .message_durations
+                .iter()
+                .map(|(name, durations)| {
+                    (name.clone(), Summary::new(durations.samples()))
+                })
+                .collect(),
+            frame_times: Histogram::frame_times(
+                self.render_durations.samples(),
+            ),
         }
+    }
+
+    pub fn log_image_statistics(&mut self, statistics: image::Statistics) {
+        self.image_statistics = statistics;
+    }
 
+    pub fn log_inspection(&mut self, inspection: Option<Inspection>) {
+        self.inspection = inspection;
+    }
+
+    pub fn overlay(&self) -> Vec<String> {
         let mut lines = Vec::new();
 
         fn key_value<T: std::fmt::Debug>(key: &str, value: T) -> String {
             format!("{key} {value:?}")
         }
 
+        if self.is_inspecting {
+            lines.extend(self.inspection_overlay());
+        }
+
+        if !self.is_enabled {
+            return lines;
+        }
+
         lines.push(format!(
             "{} {} - {}",
             env!("CARGO_PKG_NAME"),
@@ -172,6 +262,16 @@ impl Debug {
             self.draw_durations.average(),
         ));
         lines.push(key_value("Render:", self.render_durations.average()));
+        lines.push(format!(
+            "Image cache: {} entries, {:.1} MiB host, {:.1} MiB device, \
+            {} atlas layers, {:.1}% hit rate, {} loading",
+            self.image_statistics.entries,
+            mebibytes(self.image_statistics.host_bytes),
+            mebibytes(self.image_statistics.device_bytes),
+            self.image_statistics.atlas_layers,
+            self.image_statistics.hit_rate() * 100.0,
+            self.image_statistics.loading,
+        ));
         lines.push(key_value("Message count:", self.message_count));
         lines.push(String::from("Last messages:"));
         lines.extend(self.last_messages.iter().map(|msg| {
@@ -184,6 +284,37 @@ impl Debug {
 
         lines
     }
+
+    fn inspection_overlay(&self) -> Vec<String> {
+        let Some(inspection) = &self.inspection else {
+            return vec![String::from("Inspector: nothing under the cursor")];
+        };
+
+        let bounds = inspection.bounds;
+        let padding = inspection.padding;
+        let (min, max) = (inspection.limits.min(), inspection.limits.max());
+
+        vec![
+            format!("Inspector: {}", inspection.path.join(" > ")),
+            format!("    Widget: {}", inspection.name),
+            match &inspection.id {
+                Some(id) => format!("    Id: {id:?}"),
+                None => String::from("    Id: none"),
+            },
+            format!(
+                "    Bounds: {} x {} at ({}, {})",
+                bounds.width, bounds.height, bounds.x, bounds.y
+            ),
+            format!(
+                "    Padding: {} {} {} {}",
+                padding.top, padding.right, padding.bottom, padding.left
+            ),
+            format!(
+                "    Limits: {} x {} to {} x {}",
+                min.width, min.height, max.width, max.height
+            ),
+        ]
+    }
 }
 
 impl Default for Debug {
@@ -192,6 +323,10 @@ impl Default for Debug {
     }
 }
 
+fn mebibytes(bytes: usize) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0)
+}
+
 #[derive(Debug)]
 struct TimeBuffer {
     head: usize,
@@ -214,12 +349,17 @@ impl TimeBuffer {
         self.size = (self.size + 1).min(self.contents.len());
     }
 
-    fn average(&self) -> time::Duration {
-        let sum: time::Duration = if self.size == self.contents.len() {
-            self.contents[..].iter().sum()
+    fn samples(&self) -> &[time::Duration] {
+        // The first sample is pushed at index 1
+        if self.size == self.contents.len() {
+            &self.contents[..]
         } else {
-            self.contents[..self.size].iter().sum()
-        };
+            &self.contents[1..=self.size]
+        }
+    }
+
+    fn average(&self) -> time::Duration {
+        let sum: time::Duration = self.samples().iter().sum();
 
         sum / self.size.max(1) as u32
     }
@@ -1,4 +1,8 @@
 #![allow(missing_docs)]
+use crate::image;
+use crate::inspector::Inspection;
+use crate::metrics::Metrics;
+
 #[derive(Debug, Default)]
 pub struct Debug;
 
@@ -41,6 +45,22 @@ impl Debug {
     ) {
     }
 
+    pub fn metrics(&self) -> Metrics {
+        Metrics::default()
+    }
+
+    pub fn log_image_statistics(&mut self, _statistics: image::Statistics) {}
+
+    pub fn is_inspecting(&self) -> bool {
+        false
+    }
+
+    pub fn shows_outlines(&self) -> bool {
+        false
+    }
+
+    pub fn log_inspection(&mut self, _inspection: Option<Inspection>) {}
+
     pub fn overlay(&self) -> Vec<String> {
         Vec::new()
     }
@@ -6,7 +6,7 @@ use crate::renderer;
 use crate::widget;
 use crate::widget::tree::{self, Tree};
 use crate::{
-    Clipboard, Color, Layout, Length, Point, Rectangle, Shell, Widget,
+    Clipboard, Color, Layout, Length, Point, Rectangle, Shadow, Shell, Widget,
 };
 
 use std::any::Any;
@@ -32,6 +32,10 @@ impl<'a, Message, Renderer> Element<'a, Message, Renderer> {
     where
         Renderer: crate::Renderer,
     {
+        // Record the type and limits of every widget for the inspector
+        #[cfg(feature = "debug")]
+        let widget = crate::inspector::Inspect::new(widget);
+
         Self {
             widget: Box::new(widget),
         }
@@ -338,6 +342,23 @@ where
             fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
                 self.operation.custom(state, id);
             }
+
+            fn bounds(&mut self, id: &widget::Id, bounds: Rectangle) {
+                self.operation.bounds(id, bounds);
+            }
+
+            fn text(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                text: &str,
+            ) {
+                self.operation.text(id, bounds, text);
+            }
+
+            fn inspect(&mut self, name: &'static str, bounds: Rectangle) {
+                self.operation.inspect(name, bounds);
+            }
         }
 
         self.widget.operate(
@@ -533,6 +554,7 @@ where
                     border_color: color,
                     border_width: 1.0,
                     border_radius: 0.0.into(),
+                    shadow: Shadow::default(),
                 },
                 Color::TRANSPARENT,
             );
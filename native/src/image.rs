@@ -1,14 +1,18 @@
 //! Load and draw raster graphics.
-use crate::{Hasher, Rectangle, Size};
+use crate::{Hasher, Padding, Rectangle, Size};
 
 use std::hash::{Hash, Hasher as _};
+use std::io;
 use std::path::PathBuf;
+use std::sync::atomic::{self, AtomicU64};
 use std::sync::Arc;
+use std::time::Duration;
 
 /// A handle of some image data.
 #[derive(Debug, Clone)]
 pub struct Handle {
-    id: u64,
+    id: Id,
+    version: u64,
     data: Data,
 }
 
@@ -49,21 +53,82 @@ impl Handle {
         Self::from_data(Data::Bytes(Bytes::new(bytes)))
     }
 
+    /// Creates an image [`Handle`] of the given [`Data`] with a new unique
+    /// identifier.
+    ///
+    /// Unlike the other constructors, the data is not hashed. Therefore,
+    /// creating a [`Handle`] is cheap regardless of the size of the image,
+    /// but two handles of the same data are considered different images.
+    pub fn unique(data: Data) -> Handle {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self::from_id(
+            Id::Unique(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed)),
+            data,
+        )
+    }
+
+    /// Creates an image [`Handle`] of the given [`Data`] with the given
+    /// identifier.
+    ///
+    /// The data is not hashed. It is up to the caller to make sure that
+    /// different images do not share the same identifier. Custom identifiers
+    /// never collide with the ones of other handles.
+    pub fn with_id(id: u64, data: Data) -> Handle {
+        Self::from_id(Id::Custom(id), data)
+    }
+
     fn from_data(data: Data) -> Handle {
         let mut hasher = Hasher::default();
         data.hash(&mut hasher);
 
+        Self::from_id(Id::Hash(hasher.finish()), data)
+    }
+
+    fn from_id(id: Id, data: Data) -> Handle {
         Handle {
-            id: hasher.finish(),
+            id,
+            version: 0,
             data,
         }
     }
 
+    /// Replaces the pixels of the [`Handle`], keeping its identifier.
+    ///
+    /// Renderers notice the new version of the image and upload its pixels
+    /// in place of the old ones, reusing their memory if the dimensions have
+    /// not changed. This makes it suitable for video and live previews.
+    ///
+    /// Clones of the [`Handle`] made before the update are not affected.
+    pub fn update_pixels(
+        &mut self,
+        width: u32,
+        height: u32,
+        pixels: impl AsRef<[u8]> + Send + Sync + 'static,
+    ) {
+        static NEXT_VERSION: AtomicU64 = AtomicU64::new(1);
+
+        self.version = NEXT_VERSION.fetch_add(1, atomic::Ordering::Relaxed);
+        self.data = Data::Rgba {
+            width,
+            height,
+            pixels: Bytes::new(pixels),
+        };
+    }
+
     /// Returns the unique identifier of the [`Handle`].
-    pub fn id(&self) -> u64 {
+    pub fn id(&self) -> Id {
         self.id
     }
 
+    /// Returns the version of the pixels of the [`Handle`].
+    ///
+    /// It increases every time the pixels are replaced with
+    /// [`Handle::update_pixels`].
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
     /// Returns a reference to the image [`Data`].
     pub fn data(&self) -> &Data {
         &self.data
@@ -85,6 +150,64 @@ impl Hash for Handle {
     }
 }
 
+/// The identifier of an image [`Handle`].
+///
+/// Identifiers are namespaced by how they were obtained, so that hashes,
+/// unique and custom identifiers never collide with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Id {
+    /// An identifier produced by [`Handle::unique`].
+    Unique(u64),
+
+    /// An identifier given to [`Handle::with_id`].
+    Custom(u64),
+
+    /// The hash of the data of the image.
+    Hash(u64),
+}
+
+/// The frames of an animated image.
+///
+/// Cloning an [`Animation`] is cheap.
+#[derive(Debug, Clone)]
+pub struct Animation {
+    frames: Arc<[Frame]>,
+}
+
+impl Animation {
+    /// Creates a new [`Animation`] with the given frames.
+    pub fn new(frames: impl Into<Vec<Frame>>) -> Self {
+        Self {
+            frames: frames.into().into(),
+        }
+    }
+
+    /// Returns the frames of the [`Animation`].
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Returns the total duration of a run of the [`Animation`].
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+
+    /// Returns true if both animations share the same frames.
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.frames, &other.frames)
+    }
+}
+
+/// A frame of an [`Animation`].
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// The [`Handle`] of the image of the [`Frame`].
+    pub handle: Handle,
+
+    /// The time the [`Frame`] is displayed for.
+    pub delay: Duration,
+}
+
 /// A wrapper around raw image data.
 ///
 /// It behaves like a `&[u8]`.
@@ -156,6 +279,101 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// The filtering method used to sample an image when it is scaled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FilterMethod {
+    /// Bilinear interpolation, which smooths out the image.
+    #[default]
+    Linear,
+
+    /// Nearest neighbor, which keeps the pixels of the image sharp.
+    ///
+    /// Useful for pixel art and magnified images.
+    Nearest,
+}
+
+/// The loading status of an image.
+#[derive(Debug, Clone)]
+pub enum Status {
+    /// The image is still being decoded.
+    Loading,
+
+    /// The image is ready to be drawn and has the given dimensions.
+    Loaded(Size<u32>),
+
+    /// The image could not be loaded.
+    Failed(Error),
+}
+
+/// An error produced when loading an image.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The image file could not be read.
+    #[error("the image file could not be read: {0}")]
+    Io(Arc<io::Error>),
+
+    /// The image data could not be decoded.
+    #[error("the image data could not be decoded: {0}")]
+    Invalid(String),
+}
+
+/// Statistics of the image caches of a renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Statistics {
+    /// The amount of raster images and rasterized vector images cached.
+    pub entries: usize,
+
+    /// The bytes of decoded image data kept in host memory.
+    pub host_bytes: usize,
+
+    /// The bytes of image data uploaded to device memory.
+    pub device_bytes: usize,
+
+    /// The amount of texture atlas layers allocated, if the renderer uses
+    /// a texture atlas.
+    pub atlas_layers: usize,
+
+    /// The amount of lookups that found their image already cached.
+    pub hits: u64,
+
+    /// The amount of lookups that had to load their image.
+    pub misses: u64,
+
+    /// The amount of images drawn in the last frame that are still being
+    /// decoded in the background.
+    pub loading: usize,
+}
+
+impl Statistics {
+    /// Returns the ratio of lookups that found their image already cached,
+    /// between `0.0` and `1.0`.
+    pub fn hit_rate(&self) -> f32 {
+        let lookups = self.hits + self.misses;
+
+        if lookups == 0 {
+            return 0.0;
+        }
+
+        self.hits as f32 / lookups as f32
+    }
+}
+
+impl std::ops::Add for Statistics {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            entries: self.entries + other.entries,
+            host_bytes: self.host_bytes + other.host_bytes,
+            device_bytes: self.device_bytes + other.device_bytes,
+            atlas_layers: self.atlas_layers.max(other.atlas_layers),
+            hits: self.hits + other.hits,
+            misses: self.misses + other.misses,
+            loading: self.loading + other.loading,
+        }
+    }
+}
+
 /// A [`Renderer`] that can render raster graphics.
 ///
 /// [renderer]: crate::renderer
@@ -168,7 +386,102 @@ pub trait Renderer: crate::Renderer {
     /// Returns the dimensions of an image for the given [`Handle`].
     fn dimensions(&self, handle: &Self::Handle) -> Size<u32>;
 
+    /// Returns the loading [`Status`] of the image with the given [`Handle`].
+    ///
+    /// Renderers that decode images in the background should report
+    /// [`Status::Loading`] until the image is ready, and count the loading
+    /// images they draw in their [`Statistics`], so that shells keep
+    /// redrawing until they are ready. By default, images are assumed to be
+    /// loaded right away.
+    ///
+    /// [`Handle`]: Self::Handle
+    fn status(&self, handle: &Self::Handle) -> Status {
+        Status::Loaded(self.dimensions(handle))
+    }
+
     /// Draws an image with the given [`Handle`] and inside the provided
     /// `bounds`.
-    fn draw(&mut self, handle: Self::Handle, bounds: Rectangle);
+    ///
+    /// The image is sampled with the given [`FilterMethod`], its alpha is
+    /// multiplied by `opacity`, and it is rotated around the center of the
+    /// `bounds` by `rotation` radians, clockwise.
+    ///
+    /// [`Handle`]: Self::Handle
+    fn draw(
+        &mut self,
+        handle: Self::Handle,
+        filter_method: FilterMethod,
+        opacity: f32,
+        rotation: f32,
+        bounds: Rectangle,
+    );
+
+    /// Draws an image with the given [`Handle`] in nine slices, stretched to
+    /// fill the provided `bounds`.
+    ///
+    /// The `insets` of the image, in pixels, delimit its slices. The corners
+    /// keep their size, while the edges and the center stretch. By default,
+    /// the whole image is stretched instead.
+    ///
+    /// [`Handle`]: Self::Handle
+    fn draw_nine_slice(
+        &mut self,
+        handle: Self::Handle,
+        insets: Padding,
+        bounds: Rectangle,
+    ) {
+        let _ = insets;
+
+        self.draw(handle, FilterMethod::default(), 1.0, 0.0, bounds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_handles_differ() {
+        let pixels = vec![0; 4];
+
+        let a = Handle::unique(Data::Bytes(Bytes::new(pixels.clone())));
+        let b = Handle::unique(Data::Bytes(Bytes::new(pixels)));
+
+        assert_ne!(a.id(), b.id());
+    }
+
+    #[test]
+    fn custom_ids_never_collide() {
+        let pixels = vec![0; 4];
+
+        let unique = Handle::unique(Data::Bytes(Bytes::new(pixels.clone())));
+        let Id::Unique(id) = unique.id() else {
+            panic!("unexpected id: {:?}", unique.id());
+        };
+
+        let custom = Handle::with_id(id, Data::Bytes(Bytes::new(pixels)));
+
+        assert_eq!(custom.id(), Id::Custom(id));
+        assert_ne!(custom.id(), unique.id());
+    }
+
+    #[test]
+    fn update_pixels_keeps_id() {
+        let mut handle = Handle::from_pixels(1, 1, vec![0; 4]);
+        let id = handle.id();
+        let version = handle.version();
+
+        handle.update_pixels(2, 1, vec![255; 8]);
+
+        assert_eq!(handle.id(), id);
+        assert!(handle.version() > version);
+        assert!(matches!(
+            handle.data(),
+            Data::Rgba {
+                width: 2,
+                height: 1,
+                ..
+            }
+        ));
+    }
 }
@@ -0,0 +1,466 @@
+//! Inspect the widgets of a user interface.
+//!
+//! When the `debug` feature is enabled, every [`Element`] records the type
+//! of its widget and the [`layout::Limits`] it was laid out with. The
+//! [`UserInterface`] can then highlight the widget under the cursor and
+//! outline the whole widget tree.
+//!
+//! [`Element`]: crate::Element
+//! [`UserInterface`]: crate::UserInterface
+use crate::layout;
+use crate::widget;
+use crate::{Padding, Rectangle};
+
+/// The details of an inspected widget.
+#[derive(Debug, Clone)]
+pub struct Inspection {
+    /// The type names of the widgets from the root of the tree to the
+    /// inspected widget, included.
+    pub path: Vec<&'static str>,
+
+    /// The full type name of the widget.
+    pub name: &'static str,
+
+    /// The [`widget::Id`] of the widget, if it has one.
+    pub id: Option<widget::Id>,
+
+    /// The bounds of the widget.
+    pub bounds: Rectangle,
+
+    /// The space between the bounds of the widget and its contents.
+    pub padding: Padding,
+
+    /// The [`layout::Limits`] the widget was laid out with.
+    pub limits: layout::Limits,
+}
+
+/// The type and [`layout::Limits`] of the widget that produced a
+/// [`layout::Node`].
+#[derive(Debug, Clone, Copy)]
+pub struct Record {
+    /// The full type name of the widget.
+    pub name: &'static str,
+
+    /// The [`layout::Limits`] the widget was laid out with.
+    pub limits: layout::Limits,
+}
+
+#[cfg(feature = "debug")]
+pub(crate) use inspection::{inspect, Ids};
+
+#[cfg(feature = "debug")]
+mod inspection {
+    use super::Inspection;
+
+    use crate::layout::Layout;
+    use crate::renderer;
+    use crate::widget::{self, Operation};
+    use crate::{Color, Padding, Point, Rectangle, Shadow};
+
+    use std::any::Any;
+
+    /// Draws the inspection of the given [`Layout`] and returns the details of the
+    /// widget under the cursor, if any.
+    ///
+    /// When `outlines` is true, the bounds of every widget are outlined.
+    pub(crate) fn inspect<Renderer: crate::Renderer>(
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        ids: &[(&'static str, Rectangle, widget::Id)],
+        cursor_position: Point,
+        outlines: bool,
+    ) -> Option<Inspection> {
+        let mut path = Vec::new();
+        let mut hovered = None;
+        let mut current = Some(layout)
+            .filter(|layout| layout.bounds().contains(cursor_position));
+
+        // Later children are drawn on top, so they take precedence
+        while let Some(layout) = current {
+            if let Some(record) = layout.record() {
+                path.push(short_name(record.name));
+                hovered = Some((layout, record));
+            }
+
+            current = layout
+                .children()
+                .filter(|child| child.bounds().contains(cursor_position))
+                .last();
+        }
+
+        renderer.with_layer(layout.bounds(), |renderer| {
+            if outlines {
+                outline(renderer, layout);
+            }
+
+            if let Some((layout, _)) = hovered {
+                highlight(renderer, layout);
+            }
+        });
+
+        hovered.map(|(layout, record)| {
+            let bounds = layout.bounds();
+            let name = strip_generics(record.name);
+
+            let id = ids.iter().find_map(|(id_name, id_bounds, id)| {
+                (*id_name == record.name && *id_bounds == bounds)
+                    .then(|| id.clone())
+            });
+
+            Inspection {
+                path,
+                name,
+                id,
+                bounds,
+                padding: padding(layout),
+                limits: record.limits,
+            }
+        })
+    }
+
+    /// An [`Operation`] that collects the [`widget::Id`] of every inspected
+    /// widget that has one.
+    #[derive(Debug, Default)]
+    pub(crate) struct Ids {
+        current: Option<(&'static str, Rectangle)>,
+        ids: Vec<(&'static str, Rectangle, widget::Id)>,
+    }
+
+    impl Ids {
+        /// Returns the collected type names, bounds and ids.
+        pub fn into_inner(self) -> Vec<(&'static str, Rectangle, widget::Id)> {
+            self.ids
+        }
+
+        fn record(&mut self, id: Option<&widget::Id>) {
+            if let Some(id) = id {
+                if let Some((name, bounds)) = self.current.take() {
+                    self.ids.push((name, bounds, id.clone()));
+                }
+            }
+        }
+    }
+
+    impl<T> Operation<T> for Ids {
+        fn inspect(&mut self, name: &'static str, bounds: Rectangle) {
+            self.current = Some((name, bounds));
+        }
+
+        fn container(
+            &mut self,
+            id: Option<&widget::Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            self.record(id);
+
+            operate_on_children(self);
+        }
+
+        fn focusable(
+            &mut self,
+            _state: &mut dyn widget::operation::Focusable,
+            id: Option<&widget::Id>,
+        ) {
+            self.record(id);
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn widget::operation::Scrollable,
+            id: Option<&widget::Id>,
+        ) {
+            self.record(id);
+        }
+
+        fn text_input(
+            &mut self,
+            _state: &mut dyn widget::operation::TextInput,
+            id: Option<&widget::Id>,
+        ) {
+            self.record(id);
+        }
+
+        fn custom(&mut self, _state: &mut dyn Any, id: Option<&widget::Id>) {
+            self.record(id);
+        }
+    }
+
+    fn outline<Renderer: crate::Renderer>(
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+    ) {
+        if layout.record().is_some() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    border_color: Color::from_rgba(1.0, 0.0, 0.0, 0.6),
+                    border_width: 1.0,
+                    border_radius: 0.0.into(),
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        for child in layout.children() {
+            outline(renderer, child);
+        }
+    }
+
+    fn highlight<Renderer: crate::Renderer>(
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+    ) {
+        let bounds = layout.bounds();
+        let padding = padding(layout);
+
+        let content = Rectangle {
+            x: bounds.x + padding.left,
+            y: bounds.y + padding.top,
+            width: (bounds.width - padding.horizontal()).max(0.0),
+            height: (bounds.height - padding.vertical()).max(0.0),
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_color: Color::from_rgb(0.2, 0.5, 1.0),
+                border_width: 1.0,
+                border_radius: 0.0.into(),
+                shadow: Shadow::default(),
+            },
+            Color::from_rgba(0.5, 0.8, 0.5, 0.3),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: content,
+                border_color: Color::TRANSPARENT,
+                border_width: 0.0,
+                border_radius: 0.0.into(),
+                shadow: Shadow::default(),
+            },
+            Color::from_rgba(0.2, 0.5, 1.0, 0.3),
+        );
+    }
+
+    /// Returns the space between the bounds of the given [`Layout`] and the
+    /// bounds of all of its children.
+    fn padding(layout: Layout<'_>) -> Padding {
+        let bounds = layout.bounds();
+
+        let content =
+            layout.children().map(|child| child.bounds()).reduce(union);
+
+        content.map_or(Padding::ZERO, |content| Padding {
+            top: (content.y - bounds.y).max(0.0),
+            right: (bounds.x + bounds.width - content.x - content.width)
+                .max(0.0),
+            bottom: (bounds.y + bounds.height - content.y - content.height)
+                .max(0.0),
+            left: (content.x - bounds.x).max(0.0),
+        })
+    }
+
+    /// Returns the smallest [`Rectangle`] containing both given ones.
+    fn union(a: Rectangle, b: Rectangle) -> Rectangle {
+        let x = a.x.min(b.x);
+        let y = a.y.min(b.y);
+
+        Rectangle {
+            x,
+            y,
+            width: (a.x + a.width).max(b.x + b.width) - x,
+            height: (a.y + a.height).max(b.y + b.height) - y,
+        }
+    }
+
+    /// Removes the generic parameters of the given type name.
+    fn strip_generics(name: &'static str) -> &'static str {
+        name.split('<').next().unwrap_or(name)
+    }
+
+    /// Removes the module path and generic parameters of the given type name.
+    fn short_name(name: &'static str) -> &'static str {
+        let name = strip_generics(name);
+
+        name.rsplit("::").next().unwrap_or(name)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn short_name_strips_path_and_generics() {
+            let name = "iced_native::widget::button::Button<'_, app::Message>";
+
+            assert_eq!(
+                strip_generics(name),
+                "iced_native::widget::button::Button"
+            );
+            assert_eq!(short_name(name), "Button");
+            assert_eq!(short_name("Text"), "Text");
+        }
+    }
+}
+
+#[cfg(feature = "debug")]
+pub(crate) use wrapper::Inspect;
+
+#[cfg(feature = "debug")]
+mod wrapper {
+    use super::Record;
+
+    use crate::event::{self, Event};
+    use crate::layout;
+    use crate::mouse;
+    use crate::overlay;
+    use crate::renderer;
+    use crate::widget::{self, tree, Tree};
+    use crate::{Clipboard, Layout, Length, Point, Rectangle, Shell, Widget};
+
+    /// A [`Widget`] that records the type and limits of the widget it wraps.
+    pub(crate) struct Inspect<W> {
+        widget: W,
+        name: &'static str,
+    }
+
+    impl<W> Inspect<W> {
+        pub fn new(widget: W) -> Self {
+            Self {
+                widget,
+                name: std::any::type_name::<W>(),
+            }
+        }
+    }
+
+    impl<Message, Renderer, W> Widget<Message, Renderer> for Inspect<W>
+    where
+        Renderer: crate::Renderer,
+        W: Widget<Message, Renderer>,
+    {
+        fn tag(&self) -> tree::Tag {
+            self.widget.tag()
+        }
+
+        fn state(&self) -> tree::State {
+            self.widget.state()
+        }
+
+        fn children(&self) -> Vec<Tree> {
+            self.widget.children()
+        }
+
+        fn diff(&self, tree: &mut Tree) {
+            self.widget.diff(tree)
+        }
+
+        fn width(&self) -> Length {
+            self.widget.width()
+        }
+
+        fn height(&self) -> Length {
+            self.widget.height()
+        }
+
+        fn layout(
+            &self,
+            renderer: &Renderer,
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            let mut node = self.widget.layout(renderer, limits);
+
+            // Wrappers, like the one of `Element::map`, keep the record of
+            // the widget they wrap
+            if node.record().is_none() {
+                node.set_record(Record {
+                    name: self.name,
+                    limits: *limits,
+                });
+            }
+
+            node
+        }
+
+        fn operate(
+            &self,
+            tree: &mut Tree,
+            layout: Layout<'_>,
+            renderer: &Renderer,
+            operation: &mut dyn widget::Operation<Message>,
+        ) {
+            operation.inspect(self.name, layout.bounds());
+
+            self.widget.operate(tree, layout, renderer, operation);
+        }
+
+        fn on_event(
+            &mut self,
+            tree: &mut Tree,
+            event: Event,
+            layout: Layout<'_>,
+            cursor_position: Point,
+            renderer: &Renderer,
+            clipboard: &mut dyn Clipboard,
+            shell: &mut Shell<'_, Message>,
+        ) -> event::Status {
+            self.widget.on_event(
+                tree,
+                event,
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            )
+        }
+
+        fn draw(
+            &self,
+            tree: &Tree,
+            renderer: &mut Renderer,
+            theme: &Renderer::Theme,
+            style: &renderer::Style,
+            layout: Layout<'_>,
+            cursor_position: Point,
+            viewport: &Rectangle,
+        ) {
+            self.widget.draw(
+                tree,
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            )
+        }
+
+        fn mouse_interaction(
+            &self,
+            tree: &Tree,
+            layout: Layout<'_>,
+            cursor_position: Point,
+            viewport: &Rectangle,
+            renderer: &Renderer,
+        ) -> mouse::Interaction {
+            self.widget.mouse_interaction(
+                tree,
+                layout,
+                cursor_position,
+                viewport,
+                renderer,
+            )
+        }
+
+        fn overlay<'a>(
+            &'a mut self,
+            tree: &'a mut Tree,
+            layout: Layout<'_>,
+            renderer: &Renderer,
+        ) -> Option<overlay::Element<'a, Message, Renderer>> {
+            self.widget.overlay(tree, layout, renderer)
+        }
+    }
+}
@@ -7,6 +7,8 @@ pub mod flex;
 pub use limits::Limits;
 pub use node::Node;
 
+#[cfg(feature = "debug")]
+use crate::inspector;
 use crate::{Point, Rectangle, Vector};
 
 /// The bounds of a [`Node`] and its children, using absolute coordinates.
@@ -53,6 +55,13 @@ impl<'a> Layout<'a> {
         }
     }
 
+    /// Returns the [`inspector::Record`] of the widget that produced the
+    /// [`Node`], if it was recorded.
+    #[cfg(feature = "debug")]
+    pub fn record(&self) -> Option<&'a inspector::Record> {
+        self.node.record()
+    }
+
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     pub fn children(self) -> impl Iterator<Item = Layout<'a>> {
         self.node.children().iter().map(move |node| {
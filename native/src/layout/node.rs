@@ -1,3 +1,5 @@
+#[cfg(feature = "debug")]
+use crate::inspector;
 use crate::{Alignment, Point, Rectangle, Size, Vector};
 
 /// The bounds of an element and its children.
@@ -5,6 +7,8 @@ use crate::{Alignment, Point, Rectangle, Size, Vector};
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    #[cfg(feature = "debug")]
+    record: Option<inspector::Record>,
 }
 
 impl Node {
@@ -23,6 +27,8 @@ impl Node {
                 height: size.height,
             },
             children,
+            #[cfg(feature = "debug")]
+            record: None,
         }
     }
 
@@ -41,6 +47,18 @@ impl Node {
         &self.children
     }
 
+    /// Returns the [`inspector::Record`] of the widget that produced the
+    /// [`Node`], if it was recorded.
+    #[cfg(feature = "debug")]
+    pub fn record(&self) -> Option<&inspector::Record> {
+        self.record.as_ref()
+    }
+
+    #[cfg(feature = "debug")]
+    pub(crate) fn set_record(&mut self, record: inspector::Record) {
+        self.record = Some(record);
+    }
+
     /// Aligns the [`Node`] in the given space.
     pub fn align(
         &mut self,
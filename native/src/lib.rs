@@ -48,8 +48,10 @@ pub mod clipboard;
 pub mod command;
 pub mod event;
 pub mod image;
+pub mod inspector;
 pub mod keyboard;
 pub mod layout;
+pub mod metrics;
 pub mod mouse;
 pub mod overlay;
 pub mod program;
@@ -58,6 +60,7 @@ pub mod subscription;
 pub mod svg;
 pub mod system;
 pub mod text;
+pub mod theme;
 pub mod touch;
 pub mod user_interface;
 pub mod widget;
@@ -81,11 +84,10 @@ pub use iced_core::alignment;
 pub use iced_core::time;
 pub use iced_core::{
     color, Alignment, Background, Color, ContentFit, Font, Length, Padding,
-    Pixels, Point, Rectangle, Size, Vector,
+    Pixels, Point, Rectangle, Shadow, Size, Vector,
 };
 pub use iced_futures::{executor, futures};
 pub use iced_style::application;
-pub use iced_style::theme;
 
 #[doc(no_inline)]
 pub use executor::Executor;
@@ -0,0 +1,342 @@
+//! Measure the performance of an application.
+//!
+//! The [`Metrics`] are only recorded when the `debug` feature is enabled.
+//! Otherwise, they are always empty.
+use crate::command::{self, Command};
+use crate::time::Duration;
+
+use iced_futures::MaybeSend;
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+/// A snapshot of the recent timings of an application.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Metrics {
+    /// The [`Summary`] of the time spent updating the application.
+    pub update: Summary,
+
+    /// The [`Summary`] of the time spent building views.
+    pub view: Summary,
+
+    /// The [`Summary`] of the time spent computing layouts.
+    pub layout: Summary,
+
+    /// The [`Summary`] of the time spent processing events.
+    pub event_processing: Summary,
+
+    /// The [`Summary`] of the time spent generating primitives.
+    pub draw: Summary,
+
+    /// The [`Summary`] of the time spent rendering frames.
+    pub render: Summary,
+
+    /// The [`Summary`] of the time spent updating the application, by type of
+    /// message.
+    ///
+    /// Messages are named after their variant.
+    pub messages: BTreeMap<String, Summary>,
+
+    /// The [`Histogram`] of the time spent rendering recent frames.
+    pub frame_times: Histogram,
+}
+
+impl Metrics {
+    /// Returns the [`Metrics`] as JSON lines, one object per line.
+    ///
+    /// Every summary produces a line with its `kind`, `name`, amount of
+    /// `samples` and its `mean`, `p50`, `p90`, `p99` and `max` durations in
+    /// microseconds. Every bucket of the frame time histogram produces a
+    /// line with its upper bound `le_us`, which is `null` for the last one,
+    /// and its `count`.
+    pub fn to_json_lines(&self) -> String {
+        let mut lines = String::new();
+
+        let stages = [
+            ("update", &self.update),
+            ("view", &self.view),
+            ("layout", &self.layout),
+            ("event_processing", &self.event_processing),
+            ("draw", &self.draw),
+            ("render", &self.render),
+        ];
+
+        for (name, summary) in stages {
+            summary_line(&mut lines, "stage", name, summary);
+        }
+
+        for (name, summary) in &self.messages {
+            summary_line(&mut lines, "message", name, summary);
+        }
+
+        for bucket in &self.frame_times.buckets {
+            let _ = match bucket.upper_bound {
+                Some(upper_bound) => writeln!(
+                    lines,
+                    "{{\"kind\":\"frame_time\",\"le_us\":{},\"count\":{}}}",
+                    upper_bound.as_micros(),
+                    bucket.count
+                ),
+                None => writeln!(
+                    lines,
+                    "{{\"kind\":\"frame_time\",\"le_us\":null,\"count\":{}}}",
+                    bucket.count
+                ),
+            };
+        }
+
+        lines
+    }
+}
+
+/// The distribution of some recent durations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// The amount of durations.
+    pub samples: usize,
+
+    /// The mean duration.
+    pub mean: Duration,
+
+    /// The median duration.
+    pub p50: Duration,
+
+    /// The 90th percentile of the durations.
+    pub p90: Duration,
+
+    /// The 99th percentile of the durations.
+    pub p99: Duration,
+
+    /// The longest duration.
+    pub max: Duration,
+}
+
+impl Summary {
+    /// Summarizes the given durations.
+    pub fn new(durations: &[Duration]) -> Self {
+        if durations.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = durations.to_vec();
+        sorted.sort_unstable();
+
+        // Nearest-rank percentiles
+        let percentile = |p: f64| {
+            let rank = (p * sorted.len() as f64).ceil() as usize;
+
+            sorted[rank.clamp(1, sorted.len()) - 1]
+        };
+
+        Self {
+            samples: sorted.len(),
+            mean: sorted.iter().sum::<Duration>() / sorted.len() as u32,
+            p50: percentile(0.5),
+            p90: percentile(0.9),
+            p99: percentile(0.99),
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// The amount of durations that fall in some consecutive ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Histogram {
+    /// The buckets of the [`Histogram`], sorted by their upper bound.
+    pub buckets: Vec<Bucket>,
+}
+
+impl Histogram {
+    /// The upper bounds of the buckets of a frame time [`Histogram`], in
+    /// milliseconds.
+    pub const FRAME_TIME_BOUNDS: [u64; 6] = [4, 8, 16, 33, 50, 100];
+
+    /// Creates a [`Histogram`] of the given durations with buckets up to the
+    /// given bounds, which must be sorted.
+    ///
+    /// An additional bucket without upper bound counts the rest of the
+    /// durations.
+    pub fn new(bounds: &[Duration], durations: &[Duration]) -> Self {
+        let mut buckets: Vec<Bucket> = bounds
+            .iter()
+            .map(|bound| Bucket {
+                upper_bound: Some(*bound),
+                count: 0,
+            })
+            .chain(std::iter::once(Bucket {
+                upper_bound: None,
+                count: 0,
+            }))
+            .collect();
+
+        for duration in durations {
+            if let Some(bucket) = buckets.iter_mut().find(|bucket| {
+                !matches!(bucket.upper_bound, Some(bound) if *duration > bound)
+            }) {
+                bucket.count += 1;
+            }
+        }
+
+        Self { buckets }
+    }
+
+    /// Creates a [`Histogram`] of the given frame times, using the
+    /// [`Histogram::FRAME_TIME_BOUNDS`].
+    pub fn frame_times(durations: &[Duration]) -> Self {
+        let bounds = Self::FRAME_TIME_BOUNDS.map(Duration::from_millis);
+
+        Self::new(&bounds, durations)
+    }
+}
+
+/// A bucket of a [`Histogram`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    /// The inclusive upper bound of the durations of the [`Bucket`].
+    ///
+    /// The last bucket of a [`Histogram`] has no upper bound.
+    pub upper_bound: Option<Duration>,
+
+    /// The amount of durations in the [`Bucket`].
+    pub count: usize,
+}
+
+/// A metrics action to be performed by some [`Command`].
+///
+/// [`Command`]: crate::Command
+pub enum Action<T> {
+    /// Fetch the current [`Metrics`] and produce `T` with them.
+    Fetch(Box<dyn Closure<T>>),
+}
+
+/// A closure that produces some `T` out of [`Metrics`].
+pub trait Closure<T>: Fn(Metrics) -> T + MaybeSend {}
+
+impl<T, O> Closure<O> for T where T: Fn(Metrics) -> O + MaybeSend {}
+
+impl<T> Action<T> {
+    /// Maps the output of a metrics [`Action`] using the provided closure.
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + MaybeSend + Sync,
+    ) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Self::Fetch(o) => Action::Fetch(Box::new(move |m| f(o(m)))),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fetch(_) => write!(f, "Action::Fetch"),
+        }
+    }
+}
+
+/// Fetches the current [`Metrics`] of the application.
+pub fn fetch<Message>(
+    f: impl Fn(Metrics) -> Message + MaybeSend + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Metrics(Action::Fetch(Box::new(f))))
+}
+
+fn summary_line(lines: &mut String, kind: &str, name: &str, summary: &Summary) {
+    let _ = writeln!(
+        lines,
+        "{{\"kind\":\"{kind}\",\"name\":\"{}\",\"samples\":{},\
+        \"mean_us\":{},\"p50_us\":{},\"p90_us\":{},\"p99_us\":{},\
+        \"max_us\":{}}}",
+        escape(name),
+        summary.samples,
+        summary.mean.as_micros(),
+        summary.p50.as_micros(),
+        summary.p90.as_micros(),
+        summary.p99.as_micros(),
+        summary.max.as_micros(),
+    );
+}
+
+fn escape(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+
+    for c in string.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(durations: &[u64]) -> Vec<Duration> {
+        durations
+            .iter()
+            .copied()
+            .map(Duration::from_millis)
+            .collect()
+    }
+
+    #[test]
+    fn summary_uses_nearest_rank_percentiles() {
+        let durations = millis(&(1..=100).rev().collect::<Vec<_>>());
+        let summary = Summary::new(&durations);
+
+        assert_eq!(summary.samples, 100);
+        assert_eq!(summary.mean, Duration::from_micros(50_500));
+        assert_eq!(summary.p50, Duration::from_millis(50));
+        assert_eq!(summary.p90, Duration::from_millis(90));
+        assert_eq!(summary.p99, Duration::from_millis(99));
+        assert_eq!(summary.max, Duration::from_millis(100));
+
+        assert_eq!(Summary::new(&[]), Summary::default());
+    }
+
+    #[test]
+    fn frame_times_are_bucketed_with_inclusive_bounds() {
+        let histogram = Histogram::frame_times(&millis(&[1, 4, 5, 16, 250]));
+
+        let counts: Vec<_> = histogram
+            .buckets
+            .iter()
+            .map(|bucket| bucket.count)
+            .collect();
+
+        assert_eq!(counts, [2, 1, 1, 0, 0, 0, 1]);
+        assert_eq!(histogram.buckets[6].upper_bound, None);
+    }
+
+    #[test]
+    fn json_lines_escape_message_names() {
+        let metrics = Metrics {
+            messages: BTreeMap::from([(
+                String::from("Say\"Hi\""),
+                Summary::new(&millis(&[2])),
+            )]),
+            ..Metrics::default()
+        };
+
+        let json = metrics.to_json_lines();
+        let lines: Vec<_> = json.lines().collect();
+
+        assert_eq!(lines.len(), 7);
+        assert_eq!(
+            lines[6],
+            "{\"kind\":\"message\",\"name\":\"Say\\\"Hi\\\"\",\"samples\":1,\
+            \"mean_us\":2000,\"p50_us\":2000,\"p90_us\":2000,\"p99_us\":2000,\
+            \"max_us\":2000}"
+        );
+    }
+}
@@ -205,6 +205,23 @@ where
             fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
                 self.operation.custom(state, id);
             }
+
+            fn bounds(&mut self, id: &widget::Id, bounds: Rectangle) {
+                self.operation.bounds(id, bounds);
+            }
+
+            fn text(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                text: &str,
+            ) {
+                self.operation.text(id, bounds, text);
+            }
+
+            fn inspect(&mut self, name: &'static str, bounds: Rectangle) {
+                self.operation.inspect(name, bounds);
+            }
         }
 
         self.content
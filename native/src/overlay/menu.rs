@@ -12,7 +12,7 @@ use crate::widget::scrollable::{self, Scrollable};
 use crate::widget::Tree;
 use crate::{
     Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
-    Rectangle, Shell, Size, Vector, Widget,
+    Rectangle, Shadow, Shell, Size, Vector, Widget,
 };
 
 pub use iced_style::menu::{Appearance, StyleSheet};
@@ -285,6 +285,7 @@ where
                 border_color: appearance.border_color,
                 border_width: appearance.border_width,
                 border_radius: appearance.border_radius.into(),
+                shadow: appearance.shadow,
             },
             appearance.background,
         );
@@ -481,6 +482,7 @@ where
                         border_color: Color::TRANSPARENT,
                         border_width: 0.0,
                         border_radius: appearance.border_radius.into(),
+                        shadow: Shadow::default(),
                     },
                     appearance.selected_background,
                 );
@@ -5,7 +5,7 @@ mod null;
 pub use null::Null;
 
 use crate::layout;
-use crate::{Background, Color, Element, Rectangle, Vector};
+use crate::{Background, Color, Element, Rectangle, Shadow, Vector};
 
 /// A component that can be used by widgets to draw themselves on a screen.
 pub trait Renderer: Sized {
@@ -29,6 +29,19 @@ pub trait Renderer: Sized {
     /// The layer will clip its contents to the provided `bounds`.
     fn with_layer(&mut self, bounds: Rectangle, f: impl FnOnce(&mut Self));
 
+    /// Draws the primitives recorded in the given closure in a new layer that
+    /// clips its contents to the provided `bounds` with rounded corners.
+    ///
+    /// By default, the corners are ignored and a regular layer is used.
+    fn with_rounded_layer(
+        &mut self,
+        bounds: Rectangle,
+        _border_radius: BorderRadius,
+        f: impl FnOnce(&mut Self),
+    ) {
+        self.with_layer(bounds, f);
+    }
+
     /// Applies a `translation` to the primitives recorded in the given closure.
     fn with_translation(
         &mut self,
@@ -36,6 +49,51 @@ pub trait Renderer: Sized {
         f: impl FnOnce(&mut Self),
     );
 
+    /// Draws the primitives recorded in the given closure as a single group
+    /// with the given `opacity`.
+    ///
+    /// The group is composited as a whole, so overlapping primitives inside of
+    /// it do not show through each other. An `opacity` of `0.0` is fully
+    /// transparent and `1.0` is fully opaque.
+    ///
+    /// By default, the primitives are drawn fully opaque.
+    fn with_opacity(&mut self, _opacity: f32, f: impl FnOnce(&mut Self)) {
+        f(self);
+    }
+
+    /// Draws the primitives recorded in the given closure blurred by the
+    /// given `radius`, in logical pixels.
+    ///
+    /// The blurred result will be clipped to the provided `bounds`.
+    ///
+    /// By default, the primitives are drawn in a regular layer, unblurred.
+    fn with_blur(
+        &mut self,
+        bounds: Rectangle,
+        _radius: f32,
+        f: impl FnOnce(&mut Self),
+    ) {
+        self.with_layer(bounds, f);
+    }
+
+    /// Blurs whatever has already been drawn behind the provided `bounds` by
+    /// the given `radius` and then draws the primitives recorded in the given
+    /// closure on top of it.
+    ///
+    /// This can be used to implement frosted glass effects.
+    ///
+    /// By default, the backdrop is left untouched and the primitives are drawn
+    /// in a regular layer.
+    fn with_backdrop_blur(
+        &mut self,
+        bounds: Rectangle,
+        _radius: f32,
+        _border_radius: BorderRadius,
+        f: impl FnOnce(&mut Self),
+    ) {
+        self.with_layer(bounds, f);
+    }
+
     /// Fills a [`Quad`] with the provided [`Background`].
     fn fill_quad(&mut self, quad: Quad, background: impl Into<Background>);
 
@@ -57,6 +115,9 @@ pub struct Quad {
 
     /// The border color of the [`Quad`].
     pub border_color: Color,
+
+    /// The [`Shadow`] of the [`Quad`].
+    pub shadow: Shadow,
 }
 
 /// The border radi for the corners of a graphics primitive in the order:
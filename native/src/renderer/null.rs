@@ -27,6 +27,8 @@ impl Renderer for Null {
     ) {
     }
 
+    fn with_opacity(&mut self, _opacity: f32, _f: impl FnOnce(&mut Self)) {}
+
     fn clear(&mut self) {}
 
     fn fill_quad(
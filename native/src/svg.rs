@@ -2,6 +2,7 @@
 use crate::{Color, Hasher, Rectangle, Size};
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::hash::{Hash, Hasher as _};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -77,6 +78,138 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// A stylesheet applied to an SVG when it is drawn.
+///
+/// It can contain CSS rules and variables. Variables are referenced with
+/// `var(--name)` or `var(--name, fallback)` both in the CSS rules and in the
+/// SVG document itself, which lets multi-color icons follow a theme.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Stylesheet {
+    css: Cow<'static, str>,
+    variables: BTreeMap<Cow<'static, str>, String>,
+}
+
+impl Stylesheet {
+    /// Creates a new [`Stylesheet`] with the given CSS rules.
+    pub fn new(css: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            css: css.into(),
+            variables: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the value of the variable with the given name, without the
+    /// leading `--`.
+    pub fn variable(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<String>,
+    ) -> Self {
+        let _ = self.variables.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the value of the variable with the given name, without the
+    /// leading `--`, to a [`Color`].
+    pub fn color(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        color: Color,
+    ) -> Self {
+        let [r, g, b, a] = color.into_rgba8();
+
+        self.variable(
+            name,
+            format!("rgba({r}, {g}, {b}, {})", a as f32 / 255.0),
+        )
+    }
+
+    /// Returns the CSS rules of the [`Stylesheet`].
+    pub fn css(&self) -> &str {
+        &self.css
+    }
+
+    /// Returns the value of the variable with the given name, if set.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.variables.get(name).map(String::as_str)
+    }
+
+    /// Applies the [`Stylesheet`] to the given SVG document.
+    ///
+    /// The CSS rules are embedded in a `<style>` element at the start of the
+    /// document, and every variable reference is replaced with its value.
+    /// References to unknown variables fall back to their default value, if
+    /// any.
+    pub fn apply(&self, document: &str) -> String {
+        let mut styled = String::with_capacity(document.len() + self.css.len());
+
+        match document
+            .find("<svg")
+            .and_then(|start| Some(start + document[start..].find('>')? + 1))
+        {
+            Some(end) if !self.css.is_empty() => {
+                styled.push_str(&document[..end]);
+                styled.push_str("<style>");
+                styled.push_str(&self.css);
+                styled.push_str("</style>");
+                styled.push_str(&document[end..]);
+            }
+            _ => styled.push_str(document),
+        }
+
+        self.resolve(&styled)
+    }
+
+    fn resolve(&self, text: &str) -> String {
+        let mut resolved = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(start) = rest.find("var(--") {
+            // Find the closing parenthesis, skipping the ones of the fallback
+            let mut depth = 0;
+
+            let Some(length) = rest[start..].find(|c: char| {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+
+                c == ')' && depth == 0
+            }) else {
+                break;
+            };
+
+            let reference = &rest[start + "var(--".len()..start + length];
+
+            let (name, fallback) = match reference.split_once(',') {
+                Some((name, fallback)) => (name.trim(), Some(fallback.trim())),
+                None => (reference.trim(), None),
+            };
+
+            resolved.push_str(&rest[..start]);
+
+            match self.get(name).or(fallback) {
+                Some(value) => resolved.push_str(value),
+                None => resolved.push_str(&rest[start..=start + length]),
+            }
+
+            rest = &rest[start + length + 1..];
+        }
+
+        resolved.push_str(rest);
+        resolved
+    }
+
+    /// Returns an identifier of the contents of the [`Stylesheet`].
+    pub fn id(&self) -> u64 {
+        let mut hasher = Hasher::default();
+        self.hash(&mut hasher);
+
+        hasher.finish()
+    }
+}
+
 /// A [`Renderer`] that can render vector graphics.
 ///
 /// [renderer]: crate::renderer
@@ -84,6 +217,41 @@ pub trait Renderer: crate::Renderer {
     /// Returns the default dimensions of an SVG for the given [`Handle`].
     fn dimensions(&self, handle: &Handle) -> Size<u32>;
 
-    /// Draws an SVG with the given [`Handle`], an optional [`Color`] filter, and inside the provided `bounds`.
-    fn draw(&mut self, handle: Handle, color: Option<Color>, bounds: Rectangle);
+    /// Draws an SVG with the given [`Handle`], an optional [`Color`] filter,
+    /// an optional [`Stylesheet`], and inside the provided `bounds`.
+    fn draw(
+        &mut self,
+        handle: Handle,
+        color: Option<Color>,
+        stylesheet: Option<Stylesheet>,
+        bounds: Rectangle,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stylesheet_embeds_css() {
+        let stylesheet = Stylesheet::new(".icon { fill: red; }");
+
+        assert_eq!(
+            stylesheet.apply(r#"<svg width="1"><path class="icon"/></svg>"#),
+            r#"<svg width="1"><style>.icon { fill: red; }</style><path class="icon"/></svg>"#
+        );
+    }
+
+    #[test]
+    fn stylesheet_resolves_variables() {
+        let stylesheet = Stylesheet::new(".a { fill: var(--primary); }")
+            .variable("primary", "#ff0000");
+
+        assert_eq!(
+            stylesheet.apply(
+                r#"<svg><path fill="var(--secondary, rgb(0, 0, 255))"/><path fill="var(--other)"/></svg>"#
+            ),
+            r#"<svg><style>.a { fill: #ff0000; }</style><path fill="rgb(0, 0, 255)"/><path fill="var(--other)"/></svg>"#
+        );
+    }
 }
@@ -1,4 +1,5 @@
 use crate::system;
+use crate::theme;
 
 use iced_futures::MaybeSend;
 use std::fmt;
@@ -7,12 +8,20 @@ use std::fmt;
 pub enum Action<T> {
     /// Query system information and produce `T` with the result.
     QueryInformation(Box<dyn Closure<T>>),
+
+    /// Query the light or dark preference of the system and produce `T` with
+    /// the result.
+    QueryTheme(Box<dyn ThemeClosure<T>>),
 }
 
 pub trait Closure<T>: Fn(system::Information) -> T + MaybeSend {}
 
 impl<T, O> Closure<O> for T where T: Fn(system::Information) -> O + MaybeSend {}
 
+pub trait ThemeClosure<T>: Fn(theme::Mode) -> T + MaybeSend {}
+
+impl<T, O> ThemeClosure<O> for T where T: Fn(theme::Mode) -> O + MaybeSend {}
+
 impl<T> Action<T> {
     /// Maps the output of a system [`Action`] using the provided closure.
     pub fn map<A>(
@@ -26,6 +35,9 @@ impl<T> Action<T> {
             Self::QueryInformation(o) => {
                 Action::QueryInformation(Box::new(move |s| f(o(s))))
             }
+            Self::QueryTheme(o) => {
+                Action::QueryTheme(Box::new(move |mode| f(o(mode))))
+            }
         }
     }
 }
@@ -34,6 +46,7 @@ impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::QueryInformation(_) => write!(f, "Action::QueryInformation"),
+            Self::QueryTheme(_) => write!(f, "Action::QueryTheme"),
         }
     }
 }
@@ -0,0 +1,171 @@
+//! Use the built-in theme and styles.
+pub use iced_style::theme::*;
+
+#[cfg(all(
+    any(feature = "theme-toml", feature = "theme-json"),
+    not(target_arch = "wasm32")
+))]
+pub use watch::watch;
+
+#[cfg(all(
+    any(feature = "theme-toml", feature = "theme-json"),
+    not(target_arch = "wasm32")
+))]
+mod watch {
+    use crate::event::{self, Event};
+    use crate::subscription::{Recipe, Subscription};
+    use crate::theme::{Definition, Error, Theme};
+    use crate::Hasher;
+
+    use iced_futures::futures::channel::mpsc;
+    use iced_futures::futures::stream::StreamExt;
+    use iced_futures::BoxStream;
+
+    use std::hash::Hash;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    /// The interval between checks for changes of a watched theme file.
+    const INTERVAL: Duration = Duration::from_millis(500);
+
+    /// Returns a [`Subscription`] that loads the [`Theme`] definition at the
+    /// given path and loads it again every time the file changes.
+    ///
+    /// The [`Format`] of the file is guessed from its extension. Any error
+    /// produced while reading or parsing it is reported, and the file keeps
+    /// being watched.
+    ///
+    /// Every watched path is polled by its own thread, which checks the
+    /// modification time of the file twice a second. The thread stops shortly
+    /// after the [`Subscription`] is dropped. Watching a handful of files is
+    /// cheap, but this is meant for development and not for watching many
+    /// files at once.
+    ///
+    /// [`Format`]: crate::theme::Format
+    pub fn watch(
+        path: impl Into<PathBuf>,
+    ) -> Subscription<Result<Theme, Error>> {
+        Subscription::from_recipe(Watch { path: path.into() })
+    }
+
+    struct Watch {
+        path: PathBuf,
+    }
+
+    impl Recipe<Hasher, (Event, event::Status)> for Watch {
+        type Output = Result<Theme, Error>;
+
+        fn hash(&self, state: &mut Hasher) {
+            std::any::TypeId::of::<Self>().hash(state);
+            self.path.hash(state);
+        }
+
+        fn stream(
+            self: Box<Self>,
+            _input: BoxStream<(Event, event::Status)>,
+        ) -> BoxStream<Self::Output> {
+            let (sender, receiver) = mpsc::unbounded();
+
+            let _ = std::thread::Builder::new()
+                .name(String::from("iced_native::theme::watch"))
+                .spawn(move || poll(self.path, sender));
+
+            receiver.boxed()
+        }
+    }
+
+    fn poll(
+        path: PathBuf,
+        sender: mpsc::UnboundedSender<Result<Theme, Error>>,
+    ) {
+        let mut watcher = Watcher::new(path);
+
+        while !sender.is_closed() {
+            if let Some(theme) = watcher.check() {
+                if sender.unbounded_send(theme).is_err() {
+                    break;
+                }
+            }
+
+            std::thread::sleep(INTERVAL);
+        }
+    }
+
+    /// Loads a theme file again whenever its modification time changes.
+    struct Watcher {
+        path: PathBuf,
+        last_modified: Option<Option<SystemTime>>,
+    }
+
+    impl Watcher {
+        fn new(path: PathBuf) -> Self {
+            Self {
+                path,
+                last_modified: None,
+            }
+        }
+
+        /// Returns the loaded theme if the file changed since the last check.
+        fn check(&mut self) -> Option<Result<Theme, Error>> {
+            let modified = std::fs::metadata(&self.path)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+
+            if self.last_modified == Some(modified) {
+                return None;
+            }
+
+            self.last_modified = Some(modified);
+
+            Some(Definition::load(&self.path).map(Theme::from))
+        }
+    }
+
+    #[cfg(all(test, feature = "theme-toml"))]
+    mod tests {
+        use super::*;
+
+        use std::fs;
+
+        #[test]
+        fn watcher_reports_changes_and_errors() {
+            let path = std::env::temp_dir().join(format!(
+                "iced_native-theme-watch-{}.toml",
+                std::process::id()
+            ));
+
+            fs::write(
+                &path,
+                r##"
+                [palette]
+                background = "#1e1e2e"
+                text = "#cdd6f4"
+                primary = "#89b4fa"
+                success = "#a6e3a1"
+                danger = "#f38ba8"
+                "##,
+            )
+            .unwrap();
+
+            let mut watcher = Watcher::new(path.clone());
+
+            let theme = watcher.check().unwrap().unwrap();
+            assert_eq!(
+                theme.palette().primary,
+                crate::Color::from_rgb8(0x89, 0xb4, 0xfa)
+            );
+            assert!(watcher.check().is_none());
+
+            fs::remove_file(&path).unwrap();
+
+            assert!(matches!(watcher.check(), Some(Err(Error::Io(_)))));
+            assert!(watcher.check().is_none());
+
+            fs::write(&path, "[palette]\nbackground = 42\n").unwrap();
+
+            assert!(matches!(watcher.check(), Some(Err(Error::Parse { .. }))));
+
+            fs::remove_file(&path).unwrap();
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Implement your own event loop to drive a user interface.
 use crate::application;
 use crate::event::{self, Event};
+use crate::inspector::Inspection;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
@@ -552,6 +553,50 @@ where
         }
     }
 
+    /// Inspects the widgets of the [`UserInterface`], highlighting the one
+    /// under the cursor and returning its [`Inspection`], if any.
+    ///
+    /// When `outlines` is true, the bounds of every widget are outlined too.
+    ///
+    /// This should be called after [`UserInterface::draw`], so the inspection
+    /// is drawn on top. Widgets are only recorded when the `debug` feature is
+    /// enabled.
+    pub fn inspect(
+        &mut self,
+        renderer: &mut Renderer,
+        cursor_position: Point,
+        outlines: bool,
+    ) -> Option<Inspection> {
+        #[cfg(feature = "debug")]
+        {
+            use crate::inspector;
+
+            let mut ids = inspector::Ids::default();
+
+            self.root.as_widget().operate(
+                &mut self.state,
+                Layout::new(&self.base),
+                renderer,
+                &mut ids,
+            );
+
+            inspector::inspect(
+                renderer,
+                Layout::new(&self.base),
+                &ids.into_inner(),
+                cursor_position,
+                outlines,
+            )
+        }
+
+        #[cfg(not(feature = "debug"))]
+        {
+            let _ = (renderer, cursor_position, outlines);
+
+            None
+        }
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
@@ -13,11 +13,16 @@
 //! [renderer]: crate::renderer
 pub mod button;
 pub mod checkbox;
+pub mod color_picker;
 pub mod column;
+pub mod combo_box;
 pub mod container;
+pub mod date_picker;
 pub mod helpers;
 pub mod image;
 pub mod mouse_area;
+pub mod number_input;
+pub mod opacity;
 pub mod operation;
 pub mod pane_grid;
 pub mod pick_list;
@@ -31,6 +36,7 @@ pub mod space;
 pub mod svg;
 pub mod text;
 pub mod text_input;
+pub mod time_picker;
 pub mod toggler;
 pub mod tooltip;
 pub mod tree;
@@ -44,16 +50,26 @@ pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use color_picker::ColorPicker;
+#[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
+pub use combo_box::ComboBox;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
 pub use helpers::*;
 #[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
+pub use opacity::Opacity;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -78,6 +94,8 @@ pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
@@ -2,6 +2,7 @@ use crate::widget::operation::{
     self, Focusable, Operation, Scrollable, TextInput,
 };
 use crate::widget::Id;
+use crate::Rectangle;
 
 use iced_futures::MaybeSend;
 
@@ -99,6 +100,18 @@ where
             fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
                 self.operation.custom(state, id);
             }
+
+            fn bounds(&mut self, id: &Id, bounds: Rectangle) {
+                self.operation.bounds(id, bounds);
+            }
+
+            fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+                self.operation.text(id, bounds, text);
+            }
+
+            fn inspect(&mut self, name: &'static str, bounds: Rectangle) {
+                self.operation.inspect(name, bounds);
+            }
         }
 
         let Self { operation, .. } = self;
@@ -11,7 +11,7 @@ use crate::widget::tree::{self, Tree};
 use crate::widget::Operation;
 use crate::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
-    Rectangle, Shell, Vector, Widget,
+    Rectangle, Shadow, Shell, Vector, Widget,
 };
 
 pub use iced_style::button::{Appearance, StyleSheet};
@@ -385,7 +385,10 @@ where
         style_sheet.active(style)
     };
 
-    if styling.background.is_some() || styling.border_width > 0.0 {
+    if styling.background.is_some()
+        || styling.border_width > 0.0
+        || styling.shadow.is_visible()
+    {
         if styling.shadow_offset != Vector::default() {
             // TODO: Implement proper shadow support
             renderer.fill_quad(
@@ -398,6 +401,7 @@ where
                     border_radius: styling.border_radius.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
                 },
                 Background::Color([0.0, 0.0, 0.0, 0.5].into()),
             );
@@ -409,6 +413,7 @@ where
                 border_radius: styling.border_radius.into(),
                 border_width: styling.border_width,
                 border_color: styling.border_color,
+                shadow: styling.shadow,
             },
             styling
                 .background
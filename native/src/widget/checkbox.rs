@@ -9,7 +9,7 @@ use crate::touch;
 use crate::widget::{self, Row, Text, Tree};
 use crate::{
     Alignment, Clipboard, Element, Layout, Length, Pixels, Point, Rectangle,
-    Shell, Widget,
+    Shadow, Shell, Widget,
 };
 
 pub use iced_style::checkbox::{Appearance, StyleSheet};
@@ -247,6 +247,7 @@ where
                     border_radius: custom_style.border_radius.into(),
                     border_width: custom_style.border_width,
                     border_color: custom_style.border_color,
+                    shadow: Shadow::default(),
                 },
                 custom_style.background,
             );
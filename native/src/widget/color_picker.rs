@@ -0,0 +1,1336 @@
+//! Let your users pick a [`Color`].
+//!
+//! A [`ColorPicker`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::text_input::{self, StyleSheet as _, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shadow, Shell, Size, Vector, Widget,
+};
+
+pub use iced_style::color_picker::{Appearance, StyleSheet};
+
+/// A widget for picking a [`Color`].
+///
+/// It is made of a saturation/value square, a hue strip, an alpha strip and
+/// text fields to type the color in hexadecimal, RGB and HSL notation.
+///
+/// The gradients are drawn using plain quads, so the [`ColorPicker`] looks
+/// the same with every renderer.
+///
+/// # Example
+/// ```
+/// # pub type ColorPicker<'a, Message> = iced_native::widget::ColorPicker<'a, Message, iced_native::renderer::Null>;
+/// # use iced_native::Color;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ColorChanged(Color),
+///     PickFromScreen,
+/// }
+///
+/// let color = Color::from_rgb(0.2, 0.4, 0.6);
+///
+/// let color_picker = ColorPicker::new(color, Message::ColorChanged)
+///     .on_eyedropper(Message::PickFromScreen);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ColorPicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    color: Color,
+    on_change: Box<dyn Fn(Color) -> Message + 'a>,
+    on_eyedropper: Option<Message>,
+    eyedropper_label: String,
+    width: Length,
+    spacing: f32,
+    padding: Padding,
+    size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    input_style: <Renderer::Theme as text_input::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ColorPicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// The default width of a [`ColorPicker`].
+    pub const DEFAULT_WIDTH: f32 = 240.0;
+
+    /// The default spacing of a [`ColorPicker`].
+    pub const DEFAULT_SPACING: f32 = 8.0;
+
+    /// The default padding of the text fields of a [`ColorPicker`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// Creates a new [`ColorPicker`] with the current [`Color`] and the
+    /// message to produce when it changes.
+    pub fn new(
+        color: Color,
+        on_change: impl Fn(Color) -> Message + 'a,
+    ) -> Self {
+        Self {
+            color,
+            on_change: Box::new(on_change),
+            on_eyedropper: None,
+            eyedropper_label: String::from("Pick"),
+            width: Length::Fixed(Self::DEFAULT_WIDTH),
+            spacing: Self::DEFAULT_SPACING,
+            padding: Self::DEFAULT_PADDING,
+            size: None,
+            font: Default::default(),
+            style: Default::default(),
+            input_style: Default::default(),
+        }
+    }
+
+    /// Sets the message produced when the eyedropper button of the
+    /// [`ColorPicker`] is pressed.
+    ///
+    /// The button is only shown when this message is set. Sampling the
+    /// screen is platform-specific, so it is up to the application to pick
+    /// the color and feed it back to the [`ColorPicker`].
+    pub fn on_eyedropper(mut self, message: Message) -> Self {
+        self.on_eyedropper = Some(message);
+        self
+    }
+
+    /// Sets the label of the eyedropper button of the [`ColorPicker`].
+    pub fn eyedropper_label(mut self, label: impl Into<String>) -> Self {
+        self.eyedropper_label = label.into();
+        self
+    }
+
+    /// Sets the width of the [`ColorPicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the parts of the [`ColorPicker`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the text fields of the [`ColorPicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ColorPicker`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`ColorPicker`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`ColorPicker`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the text fields of the [`ColorPicker`].
+    pub fn input_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.input_style = style.into();
+        self
+    }
+
+    fn text_size(&self, renderer: &Renderer) -> f32 {
+        self.size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn change(
+        &self,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+        hsv: Hsv,
+        alpha: f32,
+    ) {
+        let color = hsv.to_color(alpha);
+
+        state.hsv = hsv;
+        state.color = color;
+        state.sync_fields();
+
+        if color != self.color {
+            shell.publish((self.on_change)(color));
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ColorPicker<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.color))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.color != self.color {
+            state.hsv = Hsv::from_color(self.color, state.hsv.hue);
+            state.color = self.color;
+            state.sync_fields();
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.text_size(renderer);
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let width = limits.max().width;
+
+        let label_width = ROW_LABELS
+            .iter()
+            .map(|label| {
+                renderer.measure_width(label, text_size, self.font.clone())
+            })
+            .fold(0.0, f32::max);
+
+        let label_offset = label_width + self.spacing;
+
+        let input = |width: f32| {
+            text_input::layout(
+                renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(width, f32::MAX)),
+                Length::Fill,
+                self.padding,
+                self.size,
+                None,
+            )
+        };
+
+        let row = |widths: &[f32], button: Option<f32>| {
+            let mut x = label_offset;
+
+            let mut children: Vec<_> = widths
+                .iter()
+                .map(|&width| {
+                    let node = input(width).translate(Vector::new(x, 0.0));
+
+                    x += width + self.spacing;
+
+                    node
+                })
+                .collect();
+
+            let height = children
+                .iter()
+                .map(|child| child.size().height)
+                .fold(0.0, f32::max);
+
+            if let Some(button_width) = button {
+                children.push(
+                    layout::Node::new(Size::new(button_width, height))
+                        .translate(Vector::new(width - button_width, 0.0)),
+                );
+            }
+
+            layout::Node::with_children(Size::new(width, height), children)
+        };
+
+        let fields_width = width - label_offset;
+        let third = (fields_width - 2.0 * self.spacing) / 3.0;
+
+        let hex = if self.on_eyedropper.is_some() {
+            let button_width = renderer.measure_width(
+                &self.eyedropper_label,
+                text_size,
+                self.font.clone(),
+            ) + self.padding.horizontal();
+
+            row(
+                &[fields_width - button_width - self.spacing],
+                Some(button_width),
+            )
+        } else {
+            row(&[fields_width], None)
+        };
+
+        let mut nodes = vec![
+            layout::Node::new(Size::new(width, width)),
+            layout::Node::new(Size::new(width, text_size)),
+            layout::Node::new(Size::new(width, text_size)),
+            hex,
+            row(&[third, third, third], None),
+            row(&[third, third, third], None),
+        ];
+
+        let mut y = 0.0;
+
+        for node in &mut nodes {
+            node.move_to(Point::new(0.0, y));
+
+            y += node.size().height + self.spacing;
+        }
+
+        layout::Node::with_children(Size::new(width, y - self.spacing), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let parts = Parts::new(layout);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(button) = parts.eyedropper {
+                    if button.contains(cursor_position) {
+                        if let Some(message) = self.on_eyedropper.clone() {
+                            shell.publish(message);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                state.drag = if parts.square.contains(cursor_position) {
+                    Some(Drag::Square)
+                } else if parts.hue.contains(cursor_position) {
+                    Some(Drag::Hue)
+                } else if parts.alpha.contains(cursor_position) {
+                    Some(Drag::Alpha)
+                } else {
+                    None
+                };
+
+                // Let the text fields lose focus
+                let status = self.update_fields(
+                    state,
+                    event.clone(),
+                    &parts,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                );
+
+                if state.drag.is_none() {
+                    return status;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {}
+            _ => {
+                return self.update_fields(
+                    state,
+                    event,
+                    &parts,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                );
+            }
+        }
+
+        if let Some(drag) = state.drag {
+            let ratio = |bounds: Rectangle| {
+                (
+                    ((cursor_position.x - bounds.x) / bounds.width)
+                        .clamp(0.0, 1.0),
+                    ((cursor_position.y - bounds.y) / bounds.height)
+                        .clamp(0.0, 1.0),
+                )
+            };
+
+            let hsv = state.hsv;
+            let alpha = state.color.a;
+
+            match drag {
+                Drag::Square => {
+                    let (x, y) = ratio(parts.square);
+
+                    self.change(
+                        state,
+                        shell,
+                        Hsv {
+                            saturation: x,
+                            value: 1.0 - y,
+                            ..hsv
+                        },
+                        alpha,
+                    );
+                }
+                Drag::Hue => {
+                    let (x, _) = ratio(parts.hue);
+
+                    self.change(
+                        state,
+                        shell,
+                        Hsv {
+                            hue: x * 360.0,
+                            ..hsv
+                        },
+                        alpha,
+                    );
+                }
+                Drag::Alpha => {
+                    let (x, _) = ratio(parts.alpha);
+
+                    self.change(state, shell, hsv, x);
+                }
+            }
+
+            return event::Status::Captured;
+        }
+
+        self.update_fields(
+            state,
+            event,
+            &parts,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let parts = Parts::new(layout);
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if [parts.square, parts.hue, parts.alpha]
+            .iter()
+            .chain(parts.eyedropper.iter())
+            .any(|bounds| bounds.contains(cursor_position))
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        parts
+            .fields
+            .iter()
+            .map(|field| {
+                text_input::mouse_interaction(*field, cursor_position, false)
+            })
+            .find(|interaction| *interaction != mouse::Interaction::default())
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance =
+            <Renderer::Theme as StyleSheet>::appearance(theme, &self.style);
+        let parts = Parts::new(layout);
+        let text_size = self.text_size(renderer);
+        let hsv = state.hsv;
+
+        // Saturation and value square
+        let square = parts.square;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: square,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            Hsv {
+                saturation: 1.0,
+                value: 1.0,
+                ..hsv
+            }
+            .to_color(1.0),
+        );
+
+        fill_gradient(renderer, square, Axis::Horizontal, |t| Color {
+            a: 1.0 - t,
+            ..Color::WHITE
+        });
+
+        fill_gradient(renderer, square, Axis::Vertical, |t| Color {
+            a: t,
+            ..Color::BLACK
+        });
+
+        draw_border(renderer, square, &appearance);
+
+        draw_handle(
+            renderer,
+            Point::new(
+                square.x + hsv.saturation * square.width,
+                square.y + (1.0 - hsv.value) * square.height,
+            ),
+            text_size / 2.0,
+            &appearance,
+        );
+
+        // Hue strip
+        fill_gradient(renderer, parts.hue, Axis::Horizontal, |t| {
+            Hsv {
+                hue: t * 360.0,
+                saturation: 1.0,
+                value: 1.0,
+            }
+            .to_color(1.0)
+        });
+
+        draw_border(renderer, parts.hue, &appearance);
+        draw_marker(renderer, parts.hue, hsv.hue / 360.0, &appearance);
+
+        // Alpha strip
+        fill_checkerboard(
+            renderer,
+            parts.alpha,
+            parts.alpha.height / 2.0,
+            appearance.checkerboard,
+        );
+
+        fill_gradient(renderer, parts.alpha, Axis::Horizontal, |t| Color {
+            a: t,
+            ..state.color
+        });
+
+        draw_border(renderer, parts.alpha, &appearance);
+        draw_marker(renderer, parts.alpha, state.color.a, &appearance);
+
+        // Text fields
+        for (row, label) in parts.rows.iter().zip(ROW_LABELS) {
+            renderer.fill_text(Text {
+                content: label,
+                bounds: Rectangle {
+                    y: row.center_y(),
+                    ..*row
+                },
+                size: text_size,
+                color: appearance.text_color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+
+        for (field, layout) in state.fields.iter().zip(parts.fields) {
+            let text_input = &field.text_input;
+            let bounds = layout.bounds();
+
+            let appearance = if field.is_invalid {
+                theme.invalid(&self.input_style)
+            } else if text_input.is_focused() {
+                theme.focused(&self.input_style)
+            } else if bounds.contains(cursor_position) {
+                theme.hovered(&self.input_style)
+            } else {
+                theme.active(&self.input_style)
+            };
+
+            text_input::draw_with_appearance(
+                renderer,
+                theme,
+                layout,
+                text_input,
+                &field.value,
+                "",
+                self.size,
+                &self.font,
+                false,
+                false,
+                None,
+                &self.input_style,
+                appearance,
+            );
+        }
+
+        if let Some(bounds) = parts.eyedropper {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius.into(),
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                    shadow: Shadow::default(),
+                },
+                appearance.button_background,
+            );
+
+            renderer.fill_text(Text {
+                content: &self.eyedropper_label,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                color: appearance.button_text_color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> ColorPicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn update_fields(
+        &self,
+        state: &mut State,
+        event: Event,
+        parts: &Parts<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut status = event::Status::Ignored;
+
+        for (index, layout) in parts.fields.iter().enumerate() {
+            let Field {
+                text_input,
+                value,
+                is_invalid,
+            } = &mut state.fields[index];
+
+            let was_focused = text_input.is_focused();
+
+            let mut edits = Vec::new();
+            let mut local_shell = Shell::new(&mut edits);
+
+            let field_status = text_input::update(
+                event.clone(),
+                *layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                &mut local_shell,
+                value,
+                self.size,
+                &self.font,
+                false,
+                Some(&|text| text),
+                None,
+                &None,
+                || text_input,
+            );
+
+            status = status.merge(field_status);
+
+            if let Some(redraw_request) = local_shell.redraw_request() {
+                shell.request_redraw(redraw_request);
+            }
+
+            let is_focused = text_input.is_focused();
+
+            if let Some(text) = edits.pop() {
+                match FieldKind::ALL[index].parse(&text, state.hsv, state.color)
+                {
+                    Some((hsv, alpha)) => {
+                        state.fields[index].is_invalid = false;
+
+                        self.change(state, shell, hsv, alpha);
+                    }
+                    None => {
+                        *is_invalid = true;
+                    }
+                }
+            } else if was_focused && !is_focused {
+                state.sync_fields();
+            }
+        }
+
+        status
+    }
+}
+
+impl<'a, Message, Renderer> From<ColorPicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn from(color_picker: ColorPicker<'a, Message, Renderer>) -> Self {
+        Self::new(color_picker)
+    }
+}
+
+/// The local state of a [`ColorPicker`].
+#[derive(Debug, Clone)]
+pub struct State {
+    color: Color,
+    hsv: Hsv,
+    drag: Option<Drag>,
+    fields: [Field; 7],
+}
+
+impl State {
+    /// Creates a new [`State`] for a [`ColorPicker`] showing the given
+    /// [`Color`].
+    pub fn new(color: Color) -> Self {
+        let mut state = Self {
+            color,
+            hsv: Hsv::from_color(color, 0.0),
+            drag: None,
+            fields: Default::default(),
+        };
+
+        state.sync_fields();
+        state
+    }
+
+    /// Returns whether any of the text fields of the [`ColorPicker`] is
+    /// focused.
+    pub fn is_focused(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|field| field.text_input.is_focused())
+    }
+
+    /// Updates the text of every unfocused field to match the current
+    /// color.
+    fn sync_fields(&mut self) {
+        for (field, kind) in self.fields.iter_mut().zip(FieldKind::ALL) {
+            if !field.text_input.is_focused() {
+                field.value = Value::new(&kind.format(self.hsv, self.color));
+                field.is_invalid = false;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Field {
+    text_input: text_input::State,
+    value: Value,
+    is_invalid: bool,
+}
+
+impl Default for Field {
+    fn default() -> Self {
+        Self {
+            text_input: text_input::State::new(),
+            value: Value::new(""),
+            is_invalid: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Drag {
+    Square,
+    Hue,
+    Alpha,
+}
+
+/// The labels of the rows of text fields.
+const ROW_LABELS: [&str; 3] = ["Hex", "RGB", "HSL"];
+
+/// The kind of value a text field of a [`ColorPicker`] edits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    Hex,
+    Red,
+    Green,
+    Blue,
+    Hue,
+    Saturation,
+    Lightness,
+}
+
+impl FieldKind {
+    const ALL: [Self; 7] = [
+        Self::Hex,
+        Self::Red,
+        Self::Green,
+        Self::Blue,
+        Self::Hue,
+        Self::Saturation,
+        Self::Lightness,
+    ];
+
+    fn format(self, hsv: Hsv, color: Color) -> String {
+        let [r, g, b, _] = color.into_rgba8();
+        let (_, saturation, lightness) = hsv.to_hsl();
+
+        match self {
+            Self::Hex => to_hex(color),
+            Self::Red => r.to_string(),
+            Self::Green => g.to_string(),
+            Self::Blue => b.to_string(),
+            Self::Hue => format!("{:.0}", hsv.hue),
+            Self::Saturation => format!("{:.0}", saturation * 100.0),
+            Self::Lightness => format!("{:.0}", lightness * 100.0),
+        }
+    }
+
+    /// Parses the text of a field, returning the resulting color as
+    /// [`Hsv`] and alpha.
+    fn parse(self, text: &str, hsv: Hsv, color: Color) -> Option<(Hsv, f32)> {
+        let text = text.trim();
+
+        let channel = |text: &str| {
+            text.parse::<u8>()
+                .ok()
+                .map(|channel| f32::from(channel) / 255.0)
+        };
+
+        let percentage = |text: &str| {
+            text.parse::<f32>()
+                .ok()
+                .filter(|value| (0.0..=100.0).contains(value))
+                .map(|value| value / 100.0)
+        };
+
+        let rgb =
+            |color: Color| Some((Hsv::from_color(color, hsv.hue), color.a));
+        let (hue, saturation, lightness) = hsv.to_hsl();
+
+        match self {
+            Self::Hex => rgb(from_hex(text)?),
+            Self::Red => rgb(Color {
+                r: channel(text)?,
+                ..color
+            }),
+            Self::Green => rgb(Color {
+                g: channel(text)?,
+                ..color
+            }),
+            Self::Blue => rgb(Color {
+                b: channel(text)?,
+                ..color
+            }),
+            Self::Hue => {
+                let hue = text
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|hue| (0.0..=360.0).contains(hue))?;
+
+                Some((Hsv::from_hsl(hue, saturation, lightness), color.a))
+            }
+            Self::Saturation => Some((
+                Hsv::from_hsl(hue, percentage(text)?, lightness),
+                color.a,
+            )),
+            Self::Lightness => Some((
+                Hsv::from_hsl(hue, saturation, percentage(text)?),
+                color.a,
+            )),
+        }
+    }
+}
+
+/// Formats a [`Color`] in hexadecimal notation, including the alpha channel
+/// only when the color is translucent.
+///
+/// This is the same as [`Color::into_hex`].
+pub fn to_hex(color: Color) -> String {
+    color.into_hex()
+}
+
+/// Parses a [`Color`] in hexadecimal notation, like `#rgb`, `#rgba`,
+/// `#rrggbb` or `#rrggbbaa`. The leading `#` is optional.
+///
+/// This is the same as [`Color::parse`].
+pub fn from_hex(hex: &str) -> Option<Color> {
+    Color::parse(hex)
+}
+
+/// A color in the HSV color space.
+///
+/// The hue is kept separately from the [`Color`] of a [`ColorPicker`], so it
+/// is not lost when the color becomes a shade of gray.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hsv {
+    hue: f32,
+    saturation: f32,
+    value: f32,
+}
+
+impl Hsv {
+    /// Converts a [`Color`] into [`Hsv`], using the given hue if the color
+    /// has none.
+    fn from_color(color: Color, hue: f32) -> Self {
+        let max = color.r.max(color.g).max(color.b);
+        let min = color.r.min(color.g).min(color.b);
+        let delta = max - min;
+
+        let hue = if delta <= f32::EPSILON {
+            hue
+        } else if max == color.r {
+            60.0 * ((color.g - color.b) / delta).rem_euclid(6.0)
+        } else if max == color.g {
+            60.0 * ((color.b - color.r) / delta + 2.0)
+        } else {
+            60.0 * ((color.r - color.g) / delta + 4.0)
+        };
+
+        Self {
+            hue,
+            saturation: if max <= f32::EPSILON {
+                0.0
+            } else {
+                delta / max
+            },
+            value: max,
+        }
+    }
+
+    fn to_color(self, alpha: f32) -> Color {
+        let chroma = self.value * self.saturation;
+        let sector = (self.hue / 60.0).rem_euclid(6.0);
+        let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let m = self.value - chroma;
+
+        Color::from_rgba(r + m, g + m, b + m, alpha)
+    }
+
+    fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let value = lightness + saturation * lightness.min(1.0 - lightness);
+
+        Self {
+            hue,
+            saturation: if value <= f32::EPSILON {
+                0.0
+            } else {
+                2.0 * (1.0 - lightness / value)
+            },
+            value,
+        }
+    }
+
+    fn to_hsl(self) -> (f32, f32, f32) {
+        let lightness = self.value * (1.0 - self.saturation / 2.0);
+        let saturation =
+            if lightness <= f32::EPSILON || lightness >= 1.0 - f32::EPSILON {
+                0.0
+            } else {
+                (self.value - lightness) / lightness.min(1.0 - lightness)
+            };
+
+        (self.hue, saturation, lightness)
+    }
+}
+
+/// The bounds of the different parts of a [`ColorPicker`].
+struct Parts<'a> {
+    square: Rectangle,
+    hue: Rectangle,
+    alpha: Rectangle,
+    rows: [Rectangle; 3],
+    fields: Vec<Layout<'a>>,
+    eyedropper: Option<Rectangle>,
+}
+
+impl<'a> Parts<'a> {
+    fn new(layout: Layout<'a>) -> Self {
+        let mut children = layout.children();
+
+        let square = children.next().unwrap().bounds();
+        let hue = children.next().unwrap().bounds();
+        let alpha = children.next().unwrap().bounds();
+
+        let rows: Vec<_> = children.collect();
+        let mut fields: Vec<_> =
+            rows.iter().flat_map(|row| row.children()).collect();
+
+        // The eyedropper button is the last child of the hex row
+        let eyedropper = (fields.len() > FieldKind::ALL.len())
+            .then(|| fields.remove(1).bounds());
+
+        Self {
+            square,
+            hue,
+            alpha,
+            rows: [rows[0].bounds(), rows[1].bounds(), rows[2].bounds()],
+            fields,
+            eyedropper,
+        }
+    }
+}
+
+/// The direction of a gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+/// The approximate width of every slice of a gradient, in logical pixels.
+const GRADIENT_SLICE: f32 = 2.0;
+
+/// Fills the given bounds with a gradient made of thin solid slices.
+///
+/// The `color` function receives the position of every slice along the
+/// axis, from `0.0` to `1.0`.
+fn fill_gradient<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    axis: Axis,
+    color: impl Fn(f32) -> Color,
+) where
+    Renderer: crate::Renderer,
+{
+    let length = match axis {
+        Axis::Horizontal => bounds.width,
+        Axis::Vertical => bounds.height,
+    };
+
+    let slices = (length / GRADIENT_SLICE).ceil().clamp(1.0, 256.0) as usize;
+    let slice = length / slices as f32;
+
+    for i in 0..slices {
+        let offset = i as f32 * slice;
+        let t = (i as f32 + 0.5) / slices as f32;
+
+        let bounds = match axis {
+            Axis::Horizontal => Rectangle {
+                x: bounds.x + offset,
+                width: slice,
+                ..bounds
+            },
+            Axis::Vertical => Rectangle {
+                y: bounds.y + offset,
+                height: slice,
+                ..bounds
+            },
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            color(t),
+        );
+    }
+}
+
+fn fill_checkerboard<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    size: f32,
+    colors: [Color; 2],
+) where
+    Renderer: crate::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
+        },
+        colors[0],
+    );
+
+    let columns = (bounds.width / size).ceil() as usize;
+    let rows = (bounds.height / size).ceil() as usize;
+
+    for row in 0..rows {
+        for column in (row % 2..columns).step_by(2) {
+            let x = column as f32 * size;
+            let y = row as f32 * size;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + x,
+                        y: bounds.y + y,
+                        width: size.min(bounds.width - x),
+                        height: size.min(bounds.height - y),
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
+                },
+                colors[1],
+            );
+        }
+    }
+}
+
+fn draw_border<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    appearance: &Appearance,
+) where
+    Renderer: crate::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: appearance.border_radius.into(),
+            border_width: appearance.border_width,
+            border_color: appearance.border_color,
+            shadow: Shadow::default(),
+        },
+        Background::Color(Color::TRANSPARENT),
+    );
+}
+
+/// Draws a circular handle centered at the given position.
+fn draw_handle<Renderer>(
+    renderer: &mut Renderer,
+    center: Point,
+    radius: f32,
+    appearance: &Appearance,
+) where
+    Renderer: crate::Renderer,
+{
+    for (radius, color) in [
+        (radius + 1.0, appearance.handle_border_color),
+        (radius, appearance.handle_color),
+        (radius - 2.0, appearance.handle_border_color),
+    ] {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: center.x - radius,
+                    y: center.y - radius,
+                    width: radius * 2.0,
+                    height: radius * 2.0,
+                },
+                border_radius: radius.into(),
+                border_width: 1.0,
+                border_color: color,
+                shadow: Shadow::default(),
+            },
+            Background::Color(Color::TRANSPARENT),
+        );
+    }
+}
+
+/// Draws a vertical marker at the given position of a strip, from `0.0` to
+/// `1.0`.
+fn draw_marker<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    position: f32,
+    appearance: &Appearance,
+) where
+    Renderer: crate::Renderer,
+{
+    const WIDTH: f32 = 4.0;
+
+    let x = bounds.x + position.clamp(0.0, 1.0) * bounds.width;
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: x - WIDTH / 2.0,
+                y: bounds.y - 1.0,
+                width: WIDTH,
+                height: bounds.height + 2.0,
+            },
+            border_radius: 1.0.into(),
+            border_width: 1.0,
+            border_color: appearance.handle_border_color,
+            shadow: Shadow::default(),
+        },
+        appearance.handle_color,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_notation_round_trips() {
+        assert_eq!(from_hex("#f80"), Some(Color::from_rgb8(0xff, 0x88, 0x00)));
+        assert_eq!(
+            from_hex("336699"),
+            Some(Color::from_rgb8(0x33, 0x66, 0x99))
+        );
+        assert_eq!(from_hex("#33669980").map(to_hex), Some("#33669980".into()));
+        assert_eq!(from_hex("#12345"), None);
+        assert_eq!(from_hex("#ggg"), None);
+    }
+
+    #[test]
+    fn hsv_keeps_hue_of_grays() {
+        let orange = Hsv::from_color(Color::from_rgb8(255, 128, 0), 0.0);
+        assert!((orange.hue - 30.0).abs() < 0.5);
+
+        let gray = Hsv::from_color(Color::from_rgb(0.5, 0.5, 0.5), 200.0);
+        assert_eq!(gray.hue, 200.0);
+        assert_eq!(gray.saturation, 0.0);
+
+        let (hue, saturation, lightness) = orange.to_hsl();
+        let hsv = Hsv::from_hsl(hue, saturation, lightness);
+        assert!((hsv.saturation - orange.saturation).abs() < 1e-4);
+        assert!((hsv.value - orange.value).abs() < 1e-4);
+    }
+
+    fn assert_close(a: Color, b: Color) {
+        let channels = |color: Color| [color.r, color.g, color.b, color.a];
+
+        for (a, b) in channels(a).into_iter().zip(channels(b)) {
+            assert!((a - b).abs() < 1e-4, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn hsv_round_trips_colors() {
+        let steps = [0.0, 0.2, 0.5, 0.8, 1.0];
+
+        for r in steps {
+            for g in steps {
+                for b in steps {
+                    for a in [0.0, 0.5, 1.0] {
+                        let color = Color::from_rgba(r, g, b, a);
+                        let hsv = Hsv::from_color(color, 120.0);
+
+                        assert!((0.0..360.0).contains(&hsv.hue), "{hsv:?}");
+                        assert_close(hsv.to_color(color.a), color);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hsv_wraps_hue_around_red() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+
+        assert_eq!(Hsv::from_color(red, 180.0).hue, 0.0);
+
+        let hsv = |hue| Hsv {
+            hue,
+            saturation: 1.0,
+            value: 1.0,
+        };
+
+        assert_close(hsv(0.0).to_color(1.0), red);
+        assert_close(hsv(360.0).to_color(1.0), red);
+        assert_close(hsv(-60.0).to_color(1.0), hsv(300.0).to_color(1.0));
+
+        let pinkish_red = Hsv::from_color(Color::from_rgb(1.0, 0.0, 0.01), 0.0);
+        assert!(pinkish_red.hue > 359.0 && pinkish_red.hue < 360.0);
+    }
+
+    #[test]
+    fn hsl_round_trips_including_grays() {
+        for hue in [0.0, 90.0, 359.0] {
+            for saturation in [0.0, 0.3, 1.0] {
+                for value in [0.0, 0.4, 1.0] {
+                    let hsv = Hsv {
+                        hue,
+                        saturation,
+                        value,
+                    };
+
+                    let (hsl_hue, hsl_saturation, lightness) = hsv.to_hsl();
+                    let round_trip =
+                        Hsv::from_hsl(hsl_hue, hsl_saturation, lightness);
+
+                    assert_eq!(round_trip.hue, hue);
+                    assert_close(round_trip.to_color(1.0), hsv.to_color(1.0));
+                }
+            }
+        }
+
+        let white = Hsv::from_hsl(42.0, 1.0, 1.0);
+        assert_eq!(white.saturation, 0.0);
+        assert_close(
+            white.to_color(0.25),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.25),
+        );
+
+        let black = Hsv::from_hsl(42.0, 1.0, 0.0);
+        assert_eq!(black.hue, 42.0);
+        assert_close(black.to_color(1.0), Color::BLACK);
+    }
+}
@@ -0,0 +1,705 @@
+//! Display a text input that filters a dropdown list of options.
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::overlay::menu::{self, Menu};
+use crate::renderer;
+use crate::text;
+use crate::widget;
+use crate::widget::container;
+use crate::widget::operation::Operation;
+use crate::widget::scrollable;
+use crate::widget::text_input::{self, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Widget,
+};
+use std::borrow::Cow;
+
+/// A widget for searching and selecting a single value from a list of
+/// options.
+///
+/// The text typed by the user filters the options displayed in a dropdown
+/// menu of suggestions. The highlighted suggestion can be changed with the
+/// arrow keys and selected by pressing Enter.
+///
+/// # Example
+/// ```
+/// # pub type ComboBox<'a, T, Message> = iced_native::widget::ComboBox<'a, T, Message, iced_native::renderer::Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     FruitSelected(String),
+/// }
+///
+/// let fruits = vec![
+///     String::from("Apple"),
+///     String::from("Banana"),
+///     String::from("Cherry"),
+/// ];
+///
+/// let combo_box = ComboBox::new(
+///     fruits,
+///     "Search a fruit...",
+///     None,
+///     Message::FruitSelected,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ComboBox<'a, T, Message, Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: text_input::StyleSheet + menu::StyleSheet,
+{
+    id: Option<widget::Id>,
+    options: Cow<'a, [T]>,
+    placeholder: String,
+    selected: Option<T>,
+    on_selected: Box<dyn Fn(T) -> Message + 'a>,
+    on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_custom: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    filter: Filter,
+    width: Length,
+    padding: Padding,
+    size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as text_input::StyleSheet>::Style,
+    menu_style: <Renderer::Theme as menu::StyleSheet>::Style,
+}
+
+impl<'a, T: 'a, Message, Renderer> ComboBox<'a, T, Message, Renderer>
+where
+    T: ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: text_input::StyleSheet + menu::StyleSheet,
+{
+    /// The default padding of a [`ComboBox`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// Creates a new [`ComboBox`] with the given list of options, a
+    /// placeholder, the current selected value, and the message to produce
+    /// when an option is selected.
+    pub fn new(
+        options: impl Into<Cow<'a, [T]>>,
+        placeholder: &str,
+        selected: Option<T>,
+        on_selected: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        Self {
+            id: None,
+            options: options.into(),
+            placeholder: String::from(placeholder),
+            selected,
+            on_selected: Box::new(on_selected),
+            on_input: None,
+            on_custom: None,
+            filter: Filter::default(),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            size: None,
+            font: Default::default(),
+            style: Default::default(),
+            menu_style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the text input of the [`ComboBox`].
+    ///
+    /// The [`ComboBox`] can then be focused with [`text_input::focus`].
+    ///
+    /// [`Id`]: text_input::Id
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the message that should be produced when the text typed in the
+    /// [`ComboBox`] changes.
+    pub fn on_input(
+        mut self,
+        on_input: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Allows the [`ComboBox`] to accept values that are not part of its
+    /// options.
+    ///
+    /// The given closure will be called with the typed text when Enter is
+    /// pressed and no suggestion is highlighted. It is then up to the
+    /// application to turn the text into a new selection.
+    pub fn on_custom(
+        mut self,
+        on_custom: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_custom = Some(Box::new(on_custom));
+        self
+    }
+
+    /// Sets the [`Filter`] used to match the options of the [`ComboBox`].
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets the width of the [`ComboBox`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ComboBox`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ComboBox`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the font of the [`ComboBox`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the text input of the [`ComboBox`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the menu of the [`ComboBox`].
+    pub fn menu_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as menu::StyleSheet>::Style>,
+    ) -> Self {
+        self.menu_style = style.into();
+        self
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
+    for ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + PartialEq + 'static,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: text_input::StyleSheet
+        + menu::StyleSheet
+        + scrollable::StyleSheet
+        + container::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = State::new();
+        state.filter(&self.options, self.filter);
+
+        tree::State::new(state)
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        state.filter(&self.options, self.filter);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        text_input::layout(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.size,
+            None,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        if let Some(id) = &self.id {
+            operation.bounds(id, layout.bounds());
+        }
+
+        operation.focusable(&mut state.text_input, self.id.as_ref());
+        operation.text_input(&mut state.text_input, self.id.as_ref());
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<T>>();
+        let was_focused = state.text_input.is_focused();
+
+        let mut changes = Vec::new();
+        let mut local_shell = Shell::new(&mut changes);
+
+        let mut event_status = text_input::update(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            &mut state.value,
+            self.size,
+            &self.font,
+            false,
+            Some(&|value| value),
+            None,
+            &None,
+            || &mut state.text_input,
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        if let Some(value) = changes.pop() {
+            state.filter(&self.options, self.filter);
+
+            // Free-form values are submitted on Enter unless the user
+            // explicitly highlights a suggestion.
+            state.hovered_option = if self.on_custom.is_some()
+                || state.filtered_options.is_empty()
+            {
+                None
+            } else {
+                Some(0)
+            };
+
+            if let Some(on_input) = &self.on_input {
+                shell.publish((on_input)(value));
+            }
+
+            shell.invalidate_layout();
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code, ..
+        }) = event
+        {
+            if state.text_input.is_focused() {
+                match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        if let Some(option) = state
+                            .hovered_option
+                            .and_then(|index| state.filtered_options.get(index))
+                        {
+                            state.last_selection = Some(option.clone());
+                        } else if let Some(on_custom) = &self.on_custom {
+                            if !state.value.is_empty() {
+                                shell.publish((on_custom)(
+                                    state.value.to_string(),
+                                ));
+
+                                state.close();
+                            }
+                        }
+
+                        event_status = event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Up => {
+                        state.hovered_option = match state.hovered_option {
+                            Some(0) if self.on_custom.is_some() => None,
+                            Some(index) => Some(index.saturating_sub(1)),
+                            None => None,
+                        };
+
+                        event_status = event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Down => {
+                        let last = state.filtered_options.len().checked_sub(1);
+
+                        state.hovered_option = match state.hovered_option {
+                            Some(index) => Some(index + 1).min(last),
+                            None => last.map(|_| 0),
+                        };
+
+                        event_status = event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if let Some(selection) = state.last_selection.take() {
+            state.close();
+
+            shell.publish((self.on_selected)(selection));
+
+            event_status = event::Status::Captured;
+        } else if was_focused && !state.text_input.is_focused() {
+            state.close();
+        }
+
+        if state.text_input.is_focused() != was_focused {
+            state.filter(&self.options, self.filter);
+            shell.invalidate_layout();
+        }
+
+        event_status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        text_input::mouse_interaction(layout, cursor_position, false)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<T>>();
+        let selection = self.selected.as_ref().map(ToString::to_string);
+
+        // While focused, the current selection takes the place of the
+        // placeholder until the user starts typing.
+        let (value, placeholder) = if state.text_input.is_focused() {
+            (
+                state.value.clone(),
+                selection.as_deref().unwrap_or(&self.placeholder),
+            )
+        } else {
+            (
+                Value::new(selection.as_deref().unwrap_or_default()),
+                self.placeholder.as_str(),
+            )
+        };
+
+        text_input::draw(
+            renderer,
+            theme,
+            layout,
+            cursor_position,
+            &state.text_input,
+            &value,
+            placeholder,
+            self.size,
+            &self.font,
+            false,
+            false,
+            None,
+            &self.style,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let State {
+            text_input,
+            menu,
+            hovered_option,
+            last_selection,
+            filtered_options,
+            ..
+        } = tree.state.downcast_mut::<State<T>>();
+
+        if text_input.is_focused() && !filtered_options.is_empty() {
+            let bounds = layout.bounds();
+
+            let mut menu = Menu::new(
+                menu,
+                filtered_options,
+                hovered_option,
+                last_selection,
+            )
+            .width(bounds.width)
+            .padding(self.padding)
+            .font(self.font.clone())
+            .style(self.menu_style.clone());
+
+            if let Some(size) = self.size {
+                menu = menu.text_size(size);
+            }
+
+            Some(menu.overlay(layout.position(), bounds.height))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> From<ComboBox<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + ToString + PartialEq + 'static,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: text_input::StyleSheet
+        + menu::StyleSheet
+        + scrollable::StyleSheet
+        + container::StyleSheet,
+{
+    fn from(combo_box: ComboBox<'a, T, Message, Renderer>) -> Self {
+        Self::new(combo_box)
+    }
+}
+
+/// The strategy used by a [`ComboBox`] to match its options against the
+/// typed text.
+///
+/// Matching is always case-insensitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Filter {
+    /// Matches the options that start with the typed text.
+    Prefix,
+    /// Matches the options that contain all the typed characters in order,
+    /// but not necessarily next to each other.
+    ///
+    /// The closest matches are listed first.
+    ///
+    /// This is the default.
+    #[default]
+    Fuzzy,
+}
+
+impl Filter {
+    /// Returns the score of the given `label` for the given `query`, or
+    /// `None` if the `label` does not match.
+    ///
+    /// A lower score represents a better match.
+    pub fn score(self, query: &str, label: &str) -> Option<usize> {
+        let mut query = query.chars().flat_map(char::to_lowercase);
+        let mut label = label.chars().flat_map(char::to_lowercase);
+
+        match self {
+            Filter::Prefix => {
+                query.all(|c| label.next() == Some(c)).then_some(0)
+            }
+            Filter::Fuzzy => {
+                let mut label = label.enumerate();
+                let mut score = 0;
+                let mut next = 0;
+
+                for c in query {
+                    let (index, _) =
+                        label.find(|(_, candidate)| *candidate == c)?;
+
+                    score += index - next;
+                    next = index + 1;
+                }
+
+                Some(score)
+            }
+        }
+    }
+}
+
+/// The local state of a [`ComboBox`].
+#[derive(Debug)]
+pub struct State<T> {
+    text_input: text_input::State,
+    value: Value,
+    menu: menu::State,
+    hovered_option: Option<usize>,
+    last_selection: Option<T>,
+    options: Vec<T>,
+    labels: Vec<String>,
+    last_search: Option<(String, Filter)>,
+    filtered_options: Vec<T>,
+}
+
+impl<T> State<T> {
+    /// Creates a new [`State`] for a [`ComboBox`].
+    pub fn new() -> Self {
+        Self {
+            text_input: text_input::State::new(),
+            value: Value::new(""),
+            menu: menu::State::default(),
+            hovered_option: None,
+            last_selection: None,
+            options: Vec::new(),
+            labels: Vec::new(),
+            last_search: None,
+            filtered_options: Vec::new(),
+        }
+    }
+
+    /// Returns whether the [`ComboBox`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.text_input.is_focused()
+    }
+
+    fn close(&mut self) {
+        self.text_input.unfocus();
+        self.value = Value::new("");
+        self.hovered_option = None;
+    }
+
+    fn filter(&mut self, options: &[T], filter: Filter)
+    where
+        T: Clone + ToString + PartialEq,
+    {
+        let query = self.value.to_string();
+
+        // Options are only cloned and turned into labels when they change,
+        // and only searched again when they or the query change.
+        if self.options != options {
+            self.options = options.to_vec();
+            self.labels = options.iter().map(ToString::to_string).collect();
+        } else if self.last_search.as_ref().is_some_and(
+            |(last_query, last_filter)| {
+                *last_query == query && *last_filter == filter
+            },
+        ) {
+            return;
+        }
+
+        self.filtered_options = search(&self.labels, &query, filter)
+            .into_iter()
+            .map(|index| self.options[index].clone())
+            .collect();
+
+        self.last_search = Some((query, filter));
+
+        if self
+            .hovered_option
+            .is_some_and(|index| index >= self.filtered_options.len())
+        {
+            self.hovered_option = None;
+        }
+    }
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the indices of the `labels` matching the `query`, best matches
+/// first.
+fn search(labels: &[String], query: &str, filter: Filter) -> Vec<usize> {
+    if query.is_empty() {
+        return (0..labels.len()).collect();
+    }
+
+    let mut matches: Vec<_> = labels
+        .iter()
+        .enumerate()
+        .filter_map(|(index, label)| {
+            filter.score(query, label).map(|score| (score, index))
+        })
+        .collect();
+
+    matches.sort_by_key(|(score, _)| *score);
+
+    matches.into_iter().map(|(_, index)| index).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefix_filter_matches_start_of_label() {
+        assert_eq!(Filter::Prefix.score("ru", "Rust"), Some(0));
+        assert_eq!(Filter::Prefix.score("", "Rust"), Some(0));
+        assert_eq!(Filter::Prefix.score("us", "Rust"), None);
+        assert_eq!(Filter::Prefix.score("rusty", "Rust"), None);
+    }
+
+    #[test]
+    fn fuzzy_filter_prefers_closer_matches() {
+        assert_eq!(Filter::Fuzzy.score("rst", "Rust"), Some(1));
+        assert_eq!(Filter::Fuzzy.score("RUST", "rust"), Some(0));
+        assert_eq!(Filter::Fuzzy.score("tr", "Rust"), None);
+
+        let options = ["Haskell", "Elm", "Lua", "Ruby", "Rust"];
+        let labels: Vec<_> = options.iter().map(ToString::to_string).collect();
+
+        let matches = |query, filter| -> Vec<_> {
+            search(&labels, query, filter)
+                .into_iter()
+                .map(|index| options[index])
+                .collect()
+        };
+
+        assert_eq!(matches("ru", Filter::Fuzzy), ["Ruby", "Rust"]);
+        assert_eq!(matches("l", Filter::Fuzzy), ["Lua", "Elm", "Haskell"]);
+        assert_eq!(matches("", Filter::Prefix), options);
+    }
+
+    #[test]
+    fn state_only_searches_when_query_or_options_change() {
+        let mut state = State::new();
+        let options = vec![String::from("Elm"), String::from("Rust")];
+
+        state.filter(&options, Filter::Fuzzy);
+        assert_eq!(state.filtered_options, options);
+
+        // Stale results are kept while nothing changes
+        state.filtered_options.clear();
+        state.filter(&options, Filter::Fuzzy);
+        assert!(state.filtered_options.is_empty());
+
+        state.value = Value::new("ru");
+        state.filter(&options, Filter::Fuzzy);
+        assert_eq!(state.filtered_options, ["Rust"]);
+
+        let options = vec![String::from("Ruby"), String::from("Rust")];
+
+        state.filter(&options, Filter::Fuzzy);
+        assert_eq!(state.filtered_options, options);
+    }
+}
@@ -31,6 +31,7 @@ where
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     style: <Renderer::Theme as StyleSheet>::Style,
+    clip: bool,
     content: Element<'a, Message, Renderer>,
 }
 
@@ -54,6 +55,7 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             style: Default::default(),
+            clip: false,
             content: content.into(),
         }
     }
@@ -126,6 +128,15 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets whether the contents of the [`Container`] should be clipped to
+    /// its bounds, following the border radius of its style.
+    ///
+    /// By default, the contents are not clipped.
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -178,6 +189,10 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
+        if let Some(id) = &self.id {
+            operation.bounds(&id.0, layout.bounds());
+        }
+
         operation.container(
             self.id.as_ref().map(|id| &id.0),
             &mut |operation| {
@@ -240,22 +255,56 @@ where
         viewport: &Rectangle,
     ) {
         let style = theme.appearance(&self.style);
-
-        draw_background(renderer, &style, layout.bounds());
-
-        self.content.as_widget().draw(
-            &tree.children[0],
-            renderer,
-            theme,
-            &renderer::Style {
-                text_color: style
-                    .text_color
-                    .unwrap_or(renderer_style.text_color),
-            },
-            layout.children().next().unwrap(),
-            cursor_position,
-            viewport,
-        );
+        let bounds = layout.bounds();
+
+        let draw_children = |renderer: &mut Renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style
+                        .text_color
+                        .unwrap_or(renderer_style.text_color),
+                },
+                layout.children().next().unwrap(),
+                cursor_position,
+                viewport,
+            );
+        };
+
+        let draw_content = |renderer: &mut Renderer| {
+            if self.clip {
+                renderer.with_rounded_layer(
+                    bounds,
+                    style.border_radius.into(),
+                    draw_children,
+                );
+            } else {
+                draw_children(renderer);
+            }
+        };
+
+        let draw = |renderer: &mut Renderer| {
+            draw_background(renderer, &style, bounds);
+
+            if style.blur > 0.0 {
+                renderer.with_blur(bounds, style.blur, draw_content);
+            } else {
+                draw_content(renderer);
+            }
+        };
+
+        if style.backdrop_blur > 0.0 {
+            renderer.with_backdrop_blur(
+                bounds,
+                style.backdrop_blur,
+                style.border_radius.into(),
+                draw,
+            );
+        } else {
+            draw(renderer);
+        }
     }
 
     fn overlay<'b>(
@@ -328,13 +377,17 @@ pub fn draw_background<Renderer>(
 ) where
     Renderer: crate::Renderer,
 {
-    if appearance.background.is_some() || appearance.border_width > 0.0 {
+    if appearance.background.is_some()
+        || appearance.border_width > 0.0
+        || appearance.shadow.is_visible()
+    {
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
                 border_radius: appearance.border_radius.into(),
                 border_width: appearance.border_width,
                 border_color: appearance.border_color,
+                shadow: appearance.shadow,
             },
             appearance
                 .background
@@ -0,0 +1,1055 @@
+//! Let your users pick a date, or a range of dates, from a calendar.
+//!
+//! A [`DatePicker`] has some local [`State`].
+mod date;
+
+pub use date::{days_in_month, is_leap_year, Date, Weekday};
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Vector, Widget,
+};
+
+pub use iced_style::date_picker::{Appearance, Grid, StyleSheet};
+
+/// The English names of the months, used by default.
+pub const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The English abbreviations of the days of the week, starting on Monday,
+/// used by default.
+pub const WEEKDAY_NAMES: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// A widget for picking a [`Date`], or a [`DateRange`], from a calendar.
+///
+/// The calendar is displayed as an overlay under the field when it is
+/// clicked, the same way a [`PickList`] opens its menu.
+///
+/// # Example
+/// ```
+/// # pub type DatePicker<'a, Message> = iced_native::widget::DatePicker<'a, Message, iced_native::renderer::Null>;
+/// use iced_native::widget::date_picker::{Date, Weekday};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DateSelected(Date),
+/// }
+///
+/// let date_picker = DatePicker::new(Date::new(2023, 4, 17), Message::DateSelected)
+///     .week_start(Weekday::Sunday)
+///     .min(Date::new(2023, 1, 1).unwrap());
+/// ```
+///
+/// [`PickList`]: crate::widget::PickList
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    selection: Selection<'a, Message>,
+    placeholder: Option<String>,
+    format: Box<dyn Fn(Date) -> String + 'a>,
+    week_start: Weekday,
+    month_names: [&'a str; 12],
+    weekday_names: [&'a str; 7],
+    min: Option<Date>,
+    max: Option<Date>,
+    today: Option<Date>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+enum Selection<'a, Message> {
+    Single {
+        selected: Option<Date>,
+        on_select: Box<dyn Fn(Date) -> Message + 'a>,
+    },
+    Range {
+        selected: Option<DateRange>,
+        on_select: Box<dyn Fn(DateRange) -> Message + 'a>,
+    },
+}
+
+impl<'a, Message, Renderer> DatePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default padding of a [`DatePicker`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// Creates a new [`DatePicker`] with the currently selected [`Date`] and
+    /// the message to produce when a date is picked.
+    pub fn new(
+        selected: Option<Date>,
+        on_select: impl Fn(Date) -> Message + 'a,
+    ) -> Self {
+        Self::with_selection(Selection::Single {
+            selected,
+            on_select: Box::new(on_select),
+        })
+    }
+
+    /// Creates a new [`DatePicker`] selecting a [`DateRange`].
+    ///
+    /// The first date picked in the calendar starts the range and the second
+    /// one completes it, producing the message.
+    pub fn range(
+        selected: Option<DateRange>,
+        on_select: impl Fn(DateRange) -> Message + 'a,
+    ) -> Self {
+        Self::with_selection(Selection::Range {
+            selected,
+            on_select: Box::new(on_select),
+        })
+    }
+
+    fn with_selection(selection: Selection<'a, Message>) -> Self {
+        Self {
+            selection,
+            placeholder: None,
+            format: Box::new(|date| date.to_string()),
+            week_start: Weekday::Monday,
+            month_names: MONTH_NAMES,
+            weekday_names: WEEKDAY_NAMES,
+            min: None,
+            max: None,
+            today: None,
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the placeholder of the [`DatePicker`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the function used to display a [`Date`] in the field of the
+    /// [`DatePicker`].
+    ///
+    /// By default, dates are displayed in the ISO 8601 format.
+    pub fn format(mut self, format: impl Fn(Date) -> String + 'a) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets the first day of the week of the calendar.
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Sets the names of the months displayed in the calendar, starting with
+    /// January.
+    pub fn month_names(mut self, month_names: [&'a str; 12]) -> Self {
+        self.month_names = month_names;
+        self
+    }
+
+    /// Sets the names of the days of the week displayed in the calendar,
+    /// starting with Monday.
+    pub fn weekday_names(mut self, weekday_names: [&'a str; 7]) -> Self {
+        self.weekday_names = weekday_names;
+        self
+    }
+
+    /// Sets the earliest [`Date`] that can be picked.
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be picked.
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the current [`Date`], which will be highlighted in the calendar.
+    ///
+    /// When nothing is selected, the calendar opens on the month of this
+    /// date.
+    pub fn today(mut self, today: Date) -> Self {
+        self.today = Some(today);
+        self
+    }
+
+    /// Sets the width of the [`DatePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`DatePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`DatePicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the font of the [`DatePicker`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`DatePicker`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn label(&self) -> Option<String> {
+        match &self.selection {
+            Selection::Single { selected, .. } => selected.map(&self.format),
+            Selection::Range { selected, .. } => selected.map(|range| {
+                format!(
+                    "{} – {}",
+                    (self.format)(range.start),
+                    (self.format)(range.end)
+                )
+            }),
+        }
+    }
+
+    fn is_selectable(&self, date: Date) -> bool {
+        !matches!(self.min, Some(min) if date < min)
+            && !matches!(self.max, Some(max) if date > max)
+    }
+
+    /// Returns the [`Date`] the calendar opens on.
+    fn anchor(&self) -> Date {
+        let selected = match &self.selection {
+            Selection::Single { selected, .. } => *selected,
+            Selection::Range { selected, .. } => {
+                selected.map(|range| range.start)
+            }
+        };
+
+        selected
+            .or(self.today)
+            .or(self.min)
+            .or(self.max)
+            .unwrap_or(Date::UNIX_EPOCH)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for DatePicker<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let sample = (self.format)(
+            Date::new(2000, 9, 28).expect("Sample date should be valid"),
+        );
+
+        let sample = match self.selection {
+            Selection::Single { .. } => sample,
+            Selection::Range { .. } => format!("{sample} – {sample}"),
+        };
+
+        layout_field(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.text_size,
+            &self.font,
+            &[Some(sample.as_str()), self.placeholder.as_deref()],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if state.is_open {
+                    // The overlay captures the clicks inside of it, so the
+                    // cursor was clicked outside of the calendar.
+                    state.is_open = false;
+
+                    event::Status::Captured
+                } else if layout.bounds().contains(cursor_position) {
+                    let anchor = self.anchor();
+
+                    state.is_open = true;
+                    state.page = anchor.first_of_month();
+                    state.hovered = Some(anchor);
+                    state.range_start = None;
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let appearance = if bounds.contains(cursor_position) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        draw_field(
+            renderer,
+            bounds,
+            appearance,
+            self.padding,
+            self.text_size,
+            &self.font,
+            self.label().as_deref(),
+            self.placeholder.as_deref(),
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.is_open {
+            Some(overlay::Element::new(
+                layout.position(),
+                Box::new(Calendar {
+                    picker: self,
+                    state,
+                    target_height: layout.bounds().height,
+                }),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<DatePicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(date_picker: DatePicker<'a, Message, Renderer>) -> Self {
+        Self::new(date_picker)
+    }
+}
+
+/// A range of consecutive dates, both ends included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    /// The first [`Date`] of the range.
+    pub start: Date,
+    /// The last [`Date`] of the range.
+    pub end: Date,
+}
+
+impl DateRange {
+    /// Creates a new [`DateRange`] between two dates, in any order.
+    pub fn new(a: Date, b: Date) -> Self {
+        Self {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    /// Returns true if the given [`Date`] is part of the [`DateRange`].
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+
+/// The local state of a [`DatePicker`].
+#[derive(Debug, Clone)]
+pub struct State {
+    is_open: bool,
+    page: Date,
+    hovered: Option<Date>,
+    range_start: Option<Date>,
+}
+
+impl State {
+    /// Creates a new [`State`] for a [`DatePicker`].
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            page: Date::UNIX_EPOCH,
+            hovered: None,
+            range_start: None,
+        }
+    }
+
+    /// Returns whether the calendar of the [`DatePicker`] is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The month grid of an open [`DatePicker`].
+struct Calendar<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    picker: &'a DatePicker<'b, Message, Renderer>,
+    state: &'a mut State,
+    target_height: f32,
+}
+
+/// A part of a [`Calendar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    Previous,
+    Next,
+    Day(Date),
+}
+
+impl<'a, 'b, Message, Renderer> Calendar<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The amount of rows of days shown for every month.
+    const WEEKS: usize = 6;
+
+    fn cells(&self, bounds: Rectangle) -> Cells {
+        let padding = self.picker.padding;
+
+        Cells::new(
+            Point::new(bounds.x + padding.left, bounds.y + padding.top),
+            (bounds.width - padding.horizontal()) / 7.0,
+            7,
+            Self::WEEKS + 2,
+        )
+    }
+
+    fn first_visible_day(&self) -> Date {
+        let offset = (self.state.page.weekday().days_from_monday() + 7
+            - self.picker.week_start.days_from_monday())
+            % 7;
+
+        self.state.page.add_days(-i64::from(offset))
+    }
+
+    fn target(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<Target> {
+        let (row, column) =
+            self.cells(layout.bounds()).position(cursor_position)?;
+
+        match row {
+            0 if column == 0 => Some(Target::Previous),
+            0 if column == 6 => Some(Target::Next),
+            0 | 1 => None,
+            _ => Some(Target::Day(
+                self.first_visible_day()
+                    .add_days(((row - 2) * 7 + column) as i64),
+            )),
+        }
+    }
+
+    fn show(&mut self, date: Date) {
+        self.state.page = date.first_of_month();
+        self.state.hovered = Some(date);
+    }
+
+    fn select(&mut self, date: Date, shell: &mut Shell<'_, Message>) {
+        if !self.picker.is_selectable(date) {
+            return;
+        }
+
+        match &self.picker.selection {
+            Selection::Single { on_select, .. } => {
+                shell.publish(on_select(date));
+
+                self.state.is_open = false;
+            }
+            Selection::Range { on_select, .. } => {
+                if let Some(start) = self.state.range_start.take() {
+                    shell.publish(on_select(DateRange::new(start, date)));
+
+                    self.state.is_open = false;
+                } else {
+                    self.state.range_start = Some(date);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Calendar<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let cell = cell_size(renderer, self.picker.text_size);
+        let padding = self.picker.padding;
+
+        let size = Size::new(
+            7.0 * cell + padding.horizontal(),
+            (Self::WEEKS + 2) as f32 * cell + padding.vertical(),
+        );
+
+        let mut node = layout::Node::new(size);
+
+        node.move_to(place_overlay(bounds, position, self.target_height, size));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(Target::Day(date)) =
+                    self.target(layout, cursor_position)
+                {
+                    self.state.hovered = Some(date);
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !layout.bounds().contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+
+                match self.target(layout, cursor_position) {
+                    Some(Target::Previous) => {
+                        self.state.page = self.state.page.add_months(-1);
+                    }
+                    Some(Target::Next) => {
+                        self.state.page = self.state.page.add_months(1);
+                    }
+                    Some(Target::Day(date)) => {
+                        self.select(date, shell);
+                    }
+                    None => {}
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                let hovered = self.state.hovered.unwrap_or(self.state.page);
+
+                match key_code {
+                    keyboard::KeyCode::Left => self.show(hovered.add_days(-1)),
+                    keyboard::KeyCode::Right => self.show(hovered.add_days(1)),
+                    keyboard::KeyCode::Up => self.show(hovered.add_days(-7)),
+                    keyboard::KeyCode::Down => self.show(hovered.add_days(7)),
+                    keyboard::KeyCode::PageUp => {
+                        self.show(hovered.add_months(-1));
+                    }
+                    keyboard::KeyCode::PageDown => {
+                        self.show(hovered.add_months(1));
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        self.select(hovered, shell);
+                    }
+                    keyboard::KeyCode::Escape => {
+                        self.state.is_open = false;
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match self.target(layout, cursor_position) {
+            Some(Target::Day(date)) if !self.picker.is_selectable(date) => {
+                mouse::Interaction::default()
+            }
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        let picker = self.picker;
+        let state = &self.state;
+        let bounds = layout.bounds();
+        let appearance = theme.grid(&picker.style);
+        let cells = self.cells(bounds);
+        let text_size =
+            picker.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_color: appearance.border_color,
+                border_width: appearance.border_width,
+                border_radius: appearance.border_radius.into(),
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        let draw_label = |renderer: &mut Renderer,
+                          content: &str,
+                          bounds: Rectangle,
+                          color| {
+            renderer.fill_text(Text {
+                content,
+                size: text_size,
+                font: picker.font.clone(),
+                color,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        };
+
+        let page = state.page;
+        let arrow_color = |date: Date| {
+            if picker.is_selectable(date) {
+                appearance.text_color
+            } else {
+                appearance.disabled_color
+            }
+        };
+
+        draw_label(
+            renderer,
+            "<",
+            cells.bounds(0, 0),
+            arrow_color(page.add_days(-1)),
+        );
+
+        draw_label(
+            renderer,
+            ">",
+            cells.bounds(0, 6),
+            arrow_color(page.add_months(1)),
+        );
+
+        draw_label(
+            renderer,
+            &format!(
+                "{} {}",
+                picker.month_names[page.month() as usize - 1],
+                page.year()
+            ),
+            Rectangle {
+                width: cells.size * 5.0,
+                ..cells.bounds(0, 1)
+            },
+            appearance.text_color,
+        );
+
+        for column in 0..7 {
+            let weekday =
+                picker.week_start.days_from_monday() as usize + column;
+
+            draw_label(
+                renderer,
+                picker.weekday_names[weekday % 7],
+                cells.bounds(1, column),
+                appearance.muted_color,
+            );
+        }
+
+        let (range, endpoints) = match &picker.selection {
+            Selection::Single { selected, .. } => (None, [*selected, None]),
+            Selection::Range { selected, .. } => {
+                if let Some(start) = state.range_start {
+                    let end = state.hovered;
+
+                    (
+                        end.map(|end| DateRange::new(start, end)),
+                        [Some(start), end],
+                    )
+                } else {
+                    (
+                        *selected,
+                        [
+                            selected.map(|range| range.start),
+                            selected.map(|range| range.end),
+                        ],
+                    )
+                }
+            }
+        };
+
+        let first = self.first_visible_day();
+
+        for index in 0..Self::WEEKS * 7 {
+            let date = first.add_days(index as i64);
+            let bounds = cells.bounds(2 + index / 7, index % 7);
+
+            let is_selectable = picker.is_selectable(date);
+            let is_selected = endpoints.contains(&Some(date));
+            let is_in_range = range.is_some_and(|range| range.contains(date));
+            let is_hovered = is_selectable && state.hovered == Some(date);
+
+            let background = if is_selected && is_selectable {
+                Some(appearance.selected_background)
+            } else if is_in_range {
+                Some(appearance.range_background)
+            } else if is_hovered {
+                Some(appearance.hovered_background)
+            } else {
+                None
+            };
+
+            let is_today = picker.today == Some(date);
+
+            if background.is_some() || is_today {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.cell_border_radius.into(),
+                        border_width: if is_today { 1.0 } else { 0.0 },
+                        border_color: appearance.highlight_color,
+                        shadow: Shadow::default(),
+                    },
+                    background.unwrap_or(Color::TRANSPARENT.into()),
+                );
+            }
+
+            let color = if !is_selectable {
+                appearance.disabled_color
+            } else if is_selected {
+                appearance.selected_text_color
+            } else if date.month() != page.month() {
+                appearance.muted_color
+            } else {
+                appearance.text_color
+            };
+
+            draw_label(renderer, &date.day().to_string(), bounds, color);
+        }
+    }
+}
+
+/// The square cells of the grid of an open picker.
+pub(crate) struct Cells {
+    origin: Point,
+    pub(crate) size: f32,
+    columns: usize,
+    rows: usize,
+}
+
+impl Cells {
+    pub(crate) fn new(
+        origin: Point,
+        size: f32,
+        columns: usize,
+        rows: usize,
+    ) -> Self {
+        Self {
+            origin,
+            size,
+            columns,
+            rows,
+        }
+    }
+
+    /// Returns the bounds of the cell in the given row and column.
+    pub(crate) fn bounds(&self, row: usize, column: usize) -> Rectangle {
+        Rectangle {
+            x: self.origin.x + column as f32 * self.size,
+            y: self.origin.y + row as f32 * self.size,
+            width: self.size,
+            height: self.size,
+        }
+    }
+
+    /// Returns the row and column of the cell under the given point.
+    pub(crate) fn position(&self, point: Point) -> Option<(usize, usize)> {
+        let Vector { x, y } = point - self.origin;
+
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+
+        let column = (x / self.size) as usize;
+        let row = (y / self.size) as usize;
+
+        (column < self.columns && row < self.rows).then_some((row, column))
+    }
+}
+
+/// Returns the size of a cell of the grid of an open picker.
+pub(crate) fn cell_size<Renderer>(
+    renderer: &Renderer,
+    text_size: Option<f32>,
+) -> f32
+where
+    Renderer: text::Renderer,
+{
+    (text_size.unwrap_or_else(|| renderer.default_size()) * 2.0).round()
+}
+
+/// Returns the position of an overlay of the given size, placing it either
+/// under or on top of its target, depending on the available space.
+pub(crate) fn place_overlay(
+    bounds: Size,
+    position: Point,
+    target_height: f32,
+    size: Size,
+) -> Point {
+    let space_below = bounds.height - (position.y + target_height);
+    let space_above = position.y;
+
+    let x = position.x.min(bounds.width - size.width).max(0.0);
+
+    if space_below >= size.height || space_below > space_above {
+        Point::new(x, position.y + target_height)
+    } else {
+        Point::new(x, position.y - size.height)
+    }
+}
+
+/// Computes the layout of the field of a picker, fitting the widest of the
+/// given labels.
+pub(crate) fn layout_field<Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    width: Length,
+    padding: Padding,
+    text_size: Option<f32>,
+    font: &Renderer::Font,
+    labels: &[Option<&str>],
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    let limits = limits.width(width).height(Length::Shrink).pad(padding);
+    let text_size = text_size.unwrap_or_else(|| renderer.default_size());
+
+    let max_width = match width {
+        Length::Shrink => labels
+            .iter()
+            .flatten()
+            .map(|label| {
+                renderer
+                    .measure_width(label, text_size, font.clone())
+                    .round()
+            })
+            .fold(0.0, f32::max),
+        _ => 0.0,
+    };
+
+    let size = {
+        let intrinsic =
+            Size::new(max_width + text_size + padding.left, text_size);
+
+        limits.resolve(intrinsic).pad(padding)
+    };
+
+    layout::Node::new(size)
+}
+
+/// Draws the field of a picker.
+pub(crate) fn draw_field<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    appearance: Appearance,
+    padding: Padding,
+    text_size: Option<f32>,
+    font: &Renderer::Font,
+    label: Option<&str>,
+    placeholder: Option<&str>,
+) where
+    Renderer: text::Renderer,
+{
+    let text_size = text_size.unwrap_or_else(|| renderer.default_size());
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_color: appearance.border_color,
+            border_width: appearance.border_width,
+            border_radius: appearance.border_radius.into(),
+            shadow: Shadow::default(),
+        },
+        appearance.background,
+    );
+
+    renderer.fill_text(Text {
+        content: &Renderer::ARROW_DOWN_ICON.to_string(),
+        size: text_size,
+        font: Renderer::ICON_FONT,
+        color: appearance.handle_color,
+        bounds: Rectangle {
+            x: bounds.x + bounds.width - padding.horizontal(),
+            y: bounds.center_y() - text_size / 2.0,
+            height: text_size,
+            ..bounds
+        },
+        horizontal_alignment: alignment::Horizontal::Right,
+        vertical_alignment: alignment::Vertical::Top,
+    });
+
+    if let Some(content) = label.or(placeholder) {
+        renderer.fill_text(Text {
+            content,
+            size: text_size,
+            font: font.clone(),
+            color: if label.is_some() {
+                appearance.text_color
+            } else {
+                appearance.placeholder_color
+            },
+            bounds: Rectangle {
+                x: bounds.x + padding.left,
+                y: bounds.center_y() - text_size / 2.0,
+                width: bounds.width - padding.horizontal(),
+                height: text_size,
+            },
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+        });
+    }
+}
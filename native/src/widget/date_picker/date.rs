@@ -0,0 +1,206 @@
+use std::fmt;
+
+/// A day of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// The first day of 1970.
+    pub const UNIX_EPOCH: Self = Self {
+        year: 1970,
+        month: 1,
+        day: 1,
+    };
+
+    /// Creates a new [`Date`] from the given year, month (`1..=12`) and day of
+    /// the month.
+    ///
+    /// Returns `None` if the date does not exist.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year, month)
+        {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    /// Returns the year of the [`Date`].
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], starting at 1.
+    pub fn month(self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month of the [`Date`], starting at 1.
+    pub fn day(self) -> u32 {
+        self.day
+    }
+
+    /// Returns the [`Weekday`] of the [`Date`].
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::from_index((self.days_since_epoch() + 3).rem_euclid(7) as usize)
+    }
+
+    /// Returns the first day of the month of the [`Date`].
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    /// Returns the [`Date`] the given amount of days after this one.
+    ///
+    /// A negative amount moves backwards.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Returns the [`Date`] the given amount of months after this one.
+    ///
+    /// A negative amount moves backwards. The day is clamped to the length
+    /// of the resulting month.
+    pub fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u32 + 1;
+
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    // Based on Howard Hinnant's `days_from_civil` algorithm.
+    fn days_since_epoch(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2)
+            / 5
+            + i64::from(self.day)
+            - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4
+            - year_of_era / 100
+            + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    // Based on Howard Hinnant's `civil_from_days` algorithm.
+    fn from_days_since_epoch(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460
+            + day_of_era / 36_524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        } as u32;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year: year as i32,
+            month,
+            day,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns the amount of days in the given month of the given year.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// Returns whether the given year is a leap year.
+pub fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Returns the amount of days between the previous Monday and this
+    /// [`Weekday`].
+    pub fn days_from_monday(self) -> u32 {
+        self as u32
+    }
+
+    pub(super) fn from_index(index: usize) -> Self {
+        match index % 7 {
+            0 => Self::Monday,
+            1 => Self::Tuesday,
+            2 => Self::Wednesday,
+            3 => Self::Thursday,
+            4 => Self::Friday,
+            5 => Self::Saturday,
+            _ => Self::Sunday,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weekday_matches_calendar() {
+        let date = |year, month, day| Date::new(year, month, day).unwrap();
+
+        assert_eq!(date(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(date(2000, 2, 29).weekday(), Weekday::Tuesday);
+        assert_eq!(date(2023, 4, 17).weekday(), Weekday::Monday);
+        assert_eq!(date(1969, 12, 28).weekday(), Weekday::Sunday);
+    }
+
+    #[test]
+    fn arithmetic_crosses_month_and_year_boundaries() {
+        let date = |year, month, day| Date::new(year, month, day).unwrap();
+
+        assert_eq!(date(2023, 12, 31).add_days(1), date(2024, 1, 1));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 15).add_months(-13), date(2022, 12, 15));
+        assert_eq!(Date::new(2023, 2, 29), None);
+    }
+}
@@ -197,6 +197,26 @@ where
     widget::Slider::new(range, value, on_change)
 }
 
+/// Creates a new [`NumberInput`].
+///
+/// [`NumberInput`]: widget::NumberInput
+pub fn number_input<'a, T, Message, Renderer>(
+    range: std::ops::RangeInclusive<T>,
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> widget::NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy
+        + From<u8>
+        + std::cmp::PartialOrd
+        + std::fmt::Display
+        + std::str::FromStr,
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::text_input::StyleSheet,
+{
+    widget::NumberInput::new(range, value, on_change)
+}
+
 /// Creates a new [`VerticalSlider`].
 ///
 /// [`VerticalSlider`]: widget::VerticalSlider
@@ -236,10 +256,79 @@ where
     widget::PickList::new(options, selected, on_selected)
 }
 
+/// Creates a new [`ComboBox`].
+///
+/// [`ComboBox`]: widget::ComboBox
+pub fn combo_box<'a, T, Message, Renderer>(
+    options: impl Into<Cow<'a, [T]>>,
+    placeholder: &str,
+    selected: Option<T>,
+    on_selected: impl Fn(T) -> Message + 'a,
+) -> widget::ComboBox<'a, T, Message, Renderer>
+where
+    T: ToString + 'static,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::text_input::StyleSheet
+        + widget::scrollable::StyleSheet
+        + overlay::menu::StyleSheet
+        + widget::container::StyleSheet,
+{
+    widget::ComboBox::new(options, placeholder, selected, on_selected)
+}
+
+/// Creates a new [`ColorPicker`].
+///
+/// [`ColorPicker`]: widget::ColorPicker
+pub fn color_picker<'a, Message, Renderer>(
+    color: crate::Color,
+    on_change: impl Fn(crate::Color) -> Message + 'a,
+) -> widget::ColorPicker<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme:
+        widget::color_picker::StyleSheet + widget::text_input::StyleSheet,
+{
+    widget::ColorPicker::new(color, on_change)
+}
+
+/// Creates a new [`DatePicker`].
+///
+/// [`DatePicker`]: widget::DatePicker
+pub fn date_picker<'a, Message, Renderer>(
+    selected: Option<widget::date_picker::Date>,
+    on_select: impl Fn(widget::date_picker::Date) -> Message + 'a,
+) -> widget::DatePicker<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::date_picker::StyleSheet,
+{
+    widget::DatePicker::new(selected, on_select)
+}
+
+/// Creates a new [`TimePicker`].
+///
+/// [`TimePicker`]: widget::TimePicker
+pub fn time_picker<'a, Message, Renderer>(
+    selected: Option<widget::time_picker::Time>,
+    on_select: impl Fn(widget::time_picker::Time) -> Message + 'a,
+) -> widget::TimePicker<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::time_picker::StyleSheet,
+{
+    widget::TimePicker::new(selected, on_select)
+}
+
 /// Creates a new [`Image`].
 ///
 /// [`Image`]: widget::Image
-pub fn image<Handle>(handle: impl Into<Handle>) -> widget::Image<Handle> {
+pub fn image<'a, Message, Renderer>(
+    handle: impl Into<Renderer::Handle>,
+) -> widget::Image<'a, Message, Renderer>
+where
+    Renderer: crate::image::Renderer,
+{
     widget::Image::new(handle.into())
 }
 
@@ -324,3 +413,14 @@ where
 {
     widget::MouseArea::new(widget)
 }
+
+/// Draws the given widget with some opacity, from `0.0` to `1.0`.
+pub fn opacity<'a, Message, Renderer>(
+    widget: impl Into<Element<'a, Message, Renderer>>,
+    opacity: f32,
+) -> widget::Opacity<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    widget::Opacity::new(widget, opacity)
+}
@@ -1,16 +1,48 @@
 //! Display images in your user interface.
+pub mod animated;
+pub mod nine_slice;
 pub mod viewer;
+pub use animated::Animated;
+pub use nine_slice::NineSlice;
 pub use viewer::Viewer;
 
+#[cfg(test)]
+mod mock;
+
+use crate::event::{self, Event};
 use crate::image;
 use crate::layout;
+use crate::mouse;
 use crate::renderer;
-use crate::widget::Tree;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::window;
 use crate::{
-    ContentFit, Element, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+    Clipboard, ContentFit, Element, Hasher, Layout, Length, Point, Rectangle,
+    Shell, Size, Vector, Widget,
 };
 
-use std::hash::Hash;
+use std::hash::{Hash, Hasher as _};
+
+/// Creates a new [`Animated`] image with the given [`Animation`].
+///
+/// [`Animation`]: image::Animation
+pub fn animated<Message>(animation: image::Animation) -> Animated<Message> {
+    Animated::new(animation)
+}
+
+/// Creates a new [`NineSlice`] that draws the given image, delimited in
+/// slices by the given insets, behind some content.
+pub fn nine_slice<'a, Message, Renderer>(
+    handle: impl Into<Renderer::Handle>,
+    insets: impl Into<crate::Padding>,
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> NineSlice<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    NineSlice::new(handle, insets, content)
+}
 
 /// Creates a new [`Viewer`] with the given image `Handle`.
 pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
@@ -19,32 +51,59 @@ pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
 
 /// A frame that displays an image while keeping aspect ratio.
 ///
+/// Images may be decoded in the background by the renderer. In the meantime,
+/// an optional placeholder is displayed instead.
+///
 /// # Example
 ///
 /// ```
 /// # use iced_native::widget::Image;
 /// # use iced_native::image;
 /// #
-/// let image = Image::<image::Handle>::new("resources/ferris.png");
+/// # fn view<'a, Message, Renderer>() -> Image<'a, Message, Renderer>
+/// # where
+/// #     Renderer: image::Renderer<Handle = image::Handle>,
+/// # {
+/// let image = Image::new("resources/ferris.png");
+/// # image
+/// # }
 /// ```
 ///
 /// <img src="https://github.com/iced-rs/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
-#[derive(Debug)]
-pub struct Image<Handle> {
-    handle: Handle,
+#[allow(missing_debug_implementations)]
+pub struct Image<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    handle: Renderer::Handle,
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    filter_method: image::FilterMethod,
+    opacity: f32,
+    rotation: f32,
+    placeholder: Option<Element<'a, Message, Renderer>>,
+    on_load: Option<Message>,
+    on_error: Option<Box<dyn Fn(image::Error) -> Message + 'a>>,
 }
 
-impl<Handle> Image<Handle> {
+impl<'a, Message, Renderer> Image<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+{
     /// Creates a new [`Image`] with the given path.
-    pub fn new<T: Into<Handle>>(handle: T) -> Self {
+    pub fn new<T: Into<Renderer::Handle>>(handle: T) -> Self {
         Image {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
+            filter_method: image::FilterMethod::default(),
+            opacity: 1.0,
+            rotation: 0.0,
+            placeholder: None,
+            on_load: None,
+            on_error: None,
         }
     }
 
@@ -69,6 +128,87 @@ impl<Handle> Image<Handle> {
             ..self
         }
     }
+
+    /// Sets the [`FilterMethod`] of the [`Image`].
+    ///
+    /// Defaults to [`FilterMethod::Linear`]
+    ///
+    /// [`FilterMethod`]: image::FilterMethod
+    /// [`FilterMethod::Linear`]: image::FilterMethod::Linear
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the opacity of the [`Image`], between `0.0` and `1.0`.
+    ///
+    /// Defaults to `1.0`
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Sets the clockwise rotation of the [`Image`] around its center, in
+    /// radians.
+    ///
+    /// The rotation does not affect the layout of the [`Image`].
+    pub fn rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets the placeholder [`Element`] displayed while the [`Image`] is
+    /// loading.
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the message that should be produced once the [`Image`] has
+    /// loaded.
+    pub fn on_load(mut self, message: Message) -> Self {
+        self.on_load = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Image`] fails to
+    /// load.
+    pub fn on_error(
+        mut self,
+        on_error: impl Fn(image::Error) -> Message + 'a,
+    ) -> Self {
+        self.on_error = Some(Box::new(on_error));
+        self
+    }
+
+    fn is_loading(&self, renderer: &Renderer) -> bool {
+        self.placeholder.is_some()
+            && matches!(renderer.status(&self.handle), image::Status::Loading)
+    }
+}
+
+/// The local state of an [`Image`].
+#[derive(Debug)]
+struct State {
+    handle: u64,
+    is_loading: bool,
+    is_reported: bool,
+}
+
+impl State {
+    fn new<Handle: Hash>(handle: &Handle) -> Self {
+        let mut hasher = Hasher::default();
+        handle.hash(&mut hasher);
+
+        Self {
+            handle: hasher.finish(),
+            is_loading: false,
+            is_reported: false,
+        }
+    }
 }
 
 /// Computes the layout of an [`Image`].
@@ -117,6 +257,9 @@ pub fn draw<Renderer, Handle>(
     layout: Layout<'_>,
     handle: &Handle,
     content_fit: ContentFit,
+    filter_method: image::FilterMethod,
+    opacity: f32,
+    rotation: f32,
 ) where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone + Hash,
@@ -139,7 +282,13 @@ pub fn draw<Renderer, Handle>(
             ..bounds
         };
 
-        renderer.draw(handle.clone(), drawing_bounds + offset)
+        renderer.draw(
+            handle.clone(),
+            filter_method,
+            opacity,
+            rotation,
+            drawing_bounds + offset,
+        )
     };
 
     if adjusted_fit.width > bounds.width || adjusted_fit.height > bounds.height
@@ -150,11 +299,39 @@ pub fn draw<Renderer, Handle>(
     }
 }
 
-impl<Message, Renderer, Handle> Widget<Message, Renderer> for Image<Handle>
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Image<'a, Message, Renderer>
 where
-    Renderer: image::Renderer<Handle = Handle>,
-    Handle: Clone + Hash,
+    Renderer: image::Renderer,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(&self.handle))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.placeholder.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let new_state = State::new(&self.handle);
+
+        if state.handle != new_state.handle {
+            *state = new_state;
+        }
+
+        match &self.placeholder {
+            Some(placeholder) => {
+                tree.diff_children(std::slice::from_ref(placeholder))
+            }
+            None => tree.children.clear(),
+        }
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -168,37 +345,200 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(
-            renderer,
-            limits,
-            &self.handle,
-            self.width,
-            self.height,
-            self.content_fit,
-        )
+        match &self.placeholder {
+            Some(placeholder) if self.is_loading(renderer) => {
+                placeholder.as_widget().layout(renderer, limits)
+            }
+            _ => layout(
+                renderer,
+                limits,
+                &self.handle,
+                self.width,
+                self.height,
+                self.content_fit,
+            ),
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some(placeholder) = &self.placeholder {
+            if self.is_loading(renderer) {
+                placeholder.as_widget().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    operation,
+                );
+            }
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            match renderer.status(&self.handle) {
+                image::Status::Loading => {
+                    state.is_loading = true;
+                }
+                status => {
+                    // The first layout may happen before the image is ready
+                    if state.is_loading || !state.is_reported {
+                        state.is_loading = false;
+                        shell.invalidate_layout();
+                    }
+
+                    if !state.is_reported {
+                        state.is_reported = true;
+
+                        match status {
+                            image::Status::Failed(error) => {
+                                if let Some(on_error) = &self.on_error {
+                                    shell.publish(on_error(error));
+                                }
+                            }
+                            _ => {
+                                if let Some(on_load) = self.on_load.take() {
+                                    shell.publish(on_load);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        match &mut self.placeholder {
+            Some(placeholder)
+                if tree.state.downcast_ref::<State>().is_loading =>
+            {
+                placeholder.as_widget_mut().on_event(
+                    &mut tree.children[0],
+                    event,
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match &self.placeholder {
+            Some(placeholder) if self.is_loading(renderer) => {
+                placeholder.as_widget().mouse_interaction(
+                    &tree.children[0],
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            }
+            _ => mouse::Interaction::default(),
+        }
     }
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
-        _theme: &Renderer::Theme,
-        _style: &renderer::Style,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: Point,
-        _viewport: &Rectangle,
+        cursor_position: Point,
+        viewport: &Rectangle,
     ) {
-        draw(renderer, layout, &self.handle, self.content_fit)
+        match &self.placeholder {
+            Some(placeholder) if self.is_loading(renderer) => {
+                placeholder.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor_position,
+                    viewport,
+                );
+            }
+            _ => draw(
+                renderer,
+                layout,
+                &self.handle,
+                self.content_fit,
+                self.filter_method,
+                self.opacity,
+                self.rotation,
+            ),
+        }
     }
 }
 
-impl<'a, Message, Renderer, Handle> From<Image<Handle>>
+impl<'a, Message, Renderer> From<Image<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
-    Renderer: image::Renderer<Handle = Handle>,
-    Handle: Clone + Hash + 'a,
+    Message: 'a,
+    Renderer: image::Renderer + 'a,
 {
-    fn from(image: Image<Handle>) -> Element<'a, Message, Renderer> {
+    fn from(
+        image: Image<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
         Element::new(image)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::image::mock::{redraw, Renderer};
+    use crate::widget::Space;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Loaded;
+
+    #[test]
+    fn placeholder_is_replaced_once_the_image_is_loaded() {
+        let mut image: Image<'_, Loaded, Renderer> = Image::new(())
+            .placeholder(Space::new(10, 10))
+            .on_load(Loaded);
+        let mut tree = Tree::new(&image as &dyn Widget<Loaded, Renderer>);
+        let mut renderer = Renderer {
+            is_loading: true,
+            ..Renderer::default()
+        };
+
+        assert_eq!(redraw(&mut image, &mut tree, &renderer), (false, vec![]));
+
+        renderer.is_loading = false;
+
+        assert_eq!(
+            redraw(&mut image, &mut tree, &renderer),
+            (true, vec![Loaded])
+        );
+        assert_eq!(redraw(&mut image, &mut tree, &renderer), (false, vec![]));
+    }
+}
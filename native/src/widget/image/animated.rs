@@ -0,0 +1,257 @@
+//! Play animated images.
+use crate::event::{self, Event};
+use crate::image::{self, Animation};
+use crate::layout;
+use crate::renderer;
+use crate::widget::tree::{self, Tree};
+use crate::window;
+use crate::{
+    Clipboard, ContentFit, Element, Layout, Length, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+use std::time::Instant;
+
+/// A frame that plays an [`Animation`] while keeping aspect ratio.
+///
+/// The frames advance on their own, without the need of subscribing to
+/// window frames.
+#[allow(missing_debug_implementations)]
+pub struct Animated<Message> {
+    animation: Animation,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    playing: bool,
+    looping: bool,
+    on_complete: Option<Message>,
+}
+
+impl<Message> Animated<Message> {
+    /// Creates a new [`Animated`] image with the given [`Animation`].
+    pub fn new(animation: Animation) -> Self {
+        Animated {
+            animation,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            playing: true,
+            looping: true,
+            on_complete: None,
+        }
+    }
+
+    /// Sets the width of the [`Animated`] boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Animated`] boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Animated`] image.
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets whether the [`Animation`] is playing.
+    ///
+    /// A paused [`Animation`] keeps showing its current frame. It plays by
+    /// default.
+    pub fn playing(mut self, playing: bool) -> Self {
+        self.playing = playing;
+        self
+    }
+
+    /// Sets whether the [`Animation`] starts over after its last frame.
+    ///
+    /// Otherwise, the last frame is kept. It loops by default.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Sets the message that should be produced every time a run of the
+    /// [`Animation`] completes.
+    pub fn on_complete(mut self, message: Message) -> Self {
+        self.on_complete = Some(message);
+        self
+    }
+}
+
+/// The local state of an [`Animated`] image.
+#[derive(Debug)]
+struct State {
+    animation: Animation,
+    frame: usize,
+    next_frame: Option<Instant>,
+    is_complete: bool,
+}
+
+impl State {
+    fn new(animation: &Animation) -> Self {
+        Self {
+            animation: animation.clone(),
+            frame: 0,
+            next_frame: None,
+            is_complete: false,
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Animated<Message>
+where
+    Message: Clone,
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(&self.animation))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.animation.ptr_eq(&self.animation) {
+            *state = State::new(&self.animation);
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        match self.animation.frames().first() {
+            Some(frame) => super::layout(
+                renderer,
+                limits,
+                &frame.handle,
+                self.width,
+                self.height,
+                self.content_fit,
+            ),
+            None => layout::Node::new(
+                limits
+                    .width(self.width)
+                    .height(self.height)
+                    .resolve(Size::ZERO),
+            ),
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let frames = self.animation.frames();
+
+        if !self.playing || state.is_complete || frames.len() < 2 {
+            state.next_frame = None;
+
+            return event::Status::Ignored;
+        }
+
+        let next_frame = match state.next_frame {
+            Some(next_frame) if now >= next_frame => {
+                if state.frame + 1 < frames.len() {
+                    state.frame += 1;
+                } else {
+                    if let Some(on_complete) = self.on_complete.clone() {
+                        shell.publish(on_complete);
+                    }
+
+                    if !self.looping {
+                        state.is_complete = true;
+                        state.next_frame = None;
+
+                        return event::Status::Ignored;
+                    }
+
+                    state.frame = 0;
+                }
+
+                let delay = frames[state.frame].delay;
+
+                // Skip the lost time if we fell behind
+                if next_frame + delay < now {
+                    now + delay
+                } else {
+                    next_frame + delay
+                }
+            }
+            Some(next_frame) => next_frame,
+            None => now + frames[state.frame].delay,
+        };
+
+        state.next_frame = Some(next_frame);
+        shell.request_redraw(window::RedrawRequest::At(next_frame));
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(frame) = self.animation.frames().get(state.frame) {
+            super::draw(
+                renderer,
+                layout,
+                &frame.handle,
+                self.content_fit,
+                image::FilterMethod::default(),
+                1.0,
+                0.0,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Animated<Message>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn from(animated: Animated<Message>) -> Element<'a, Message, Renderer> {
+        Element::new(animated)
+    }
+}
@@ -0,0 +1,103 @@
+//! A renderer that pretends to load images, for testing the image widgets.
+use crate::clipboard;
+use crate::image;
+use crate::layout::{self, Layout};
+use crate::renderer::Quad;
+use crate::time::Instant;
+use crate::widget::Tree;
+use crate::window;
+use crate::{
+    Background, Event, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+/// A [`Renderer`] whose only image is `100x100`, unless it is loading.
+///
+/// It records the filter method of every image drawn.
+///
+/// [`Renderer`]: crate::Renderer
+#[derive(Debug, Default)]
+pub struct Renderer {
+    pub is_loading: bool,
+    pub filter_methods: Vec<image::FilterMethod>,
+}
+
+impl crate::Renderer for Renderer {
+    type Theme = Theme;
+
+    fn with_layer(&mut self, _bounds: Rectangle, f: impl FnOnce(&mut Self)) {
+        f(self);
+    }
+
+    fn with_translation(
+        &mut self,
+        _translation: Vector,
+        f: impl FnOnce(&mut Self),
+    ) {
+        f(self);
+    }
+
+    fn fill_quad(&mut self, _quad: Quad, _background: impl Into<Background>) {}
+
+    fn clear(&mut self) {}
+}
+
+impl image::Renderer for Renderer {
+    type Handle = ();
+
+    fn dimensions(&self, _handle: &()) -> Size<u32> {
+        if self.is_loading {
+            Size::new(1, 1)
+        } else {
+            Size::new(100, 100)
+        }
+    }
+
+    fn status(&self, handle: &()) -> image::Status {
+        if self.is_loading {
+            image::Status::Loading
+        } else {
+            image::Status::Loaded(self.dimensions(handle))
+        }
+    }
+
+    fn draw(
+        &mut self,
+        _handle: (),
+        filter_method: image::FilterMethod,
+        _opacity: f32,
+        _rotation: f32,
+        _bounds: Rectangle,
+    ) {
+        self.filter_methods.push(filter_method);
+    }
+}
+
+/// Lays out the given widget and sends it a redraw request.
+///
+/// Returns whether the widget invalidated the layout, along with the
+/// messages it produced.
+pub fn redraw<Message>(
+    widget: &mut dyn Widget<Message, Renderer>,
+    tree: &mut Tree,
+    renderer: &Renderer,
+) -> (bool, Vec<Message>) {
+    let node = widget
+        .layout(renderer, &layout::Limits::new(Size::ZERO, Size::INFINITY));
+
+    let mut messages = Vec::new();
+    let mut shell = Shell::new(&mut messages);
+
+    let _ = widget.on_event(
+        tree,
+        Event::Window(window::Event::RedrawRequested(Instant::now())),
+        Layout::new(&node),
+        Point::ORIGIN,
+        renderer,
+        &mut clipboard::Null,
+        &mut shell,
+    );
+
+    let is_layout_invalid = shell.is_layout_invalid();
+
+    (is_layout_invalid, messages)
+}
@@ -0,0 +1,236 @@
+//! Skin widgets with nine-slice images.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::image;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::container;
+use crate::widget::{Operation, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell,
+    Widget,
+};
+
+/// A widget that draws a nine-slice image behind its content.
+///
+/// The corners of the image keep their size, while its edges and center
+/// stretch to fill the bounds of the [`NineSlice`]. This makes it suitable
+/// as the background of skinned containers, buttons and window chrome.
+///
+/// By default, the content is padded by the insets of the image, so it sits
+/// inside of its borders.
+///
+/// Nine-slice images are not a kind of [`Background`], since a [`Background`]
+/// is plain data that is copied around freely and cannot own an image. To
+/// skin a container or a button, wrap it in a [`NineSlice`] and give it a
+/// transparent background instead; for instance, with a padding of zero and
+/// the `Text` style of the built-in button theme.
+///
+/// [`Background`]: crate::Background
+#[allow(missing_debug_implementations)]
+pub struct NineSlice<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    handle: Renderer::Handle,
+    insets: Padding,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> NineSlice<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    /// Creates a [`NineSlice`] with the given image, the insets delimiting its
+    /// slices in pixels, and the content drawn on top of it.
+    pub fn new(
+        handle: impl Into<Renderer::Handle>,
+        insets: impl Into<Padding>,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        let insets = insets.into();
+
+        NineSlice {
+            handle: handle.into(),
+            insets,
+            padding: insets,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the [`Padding`] of the [`NineSlice`].
+    ///
+    /// Defaults to the insets of the image.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`NineSlice`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`NineSlice`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for NineSlice<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        container::layout(
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            f32::INFINITY,
+            f32::INFINITY,
+            self.padding,
+            alignment::Horizontal::Left,
+            alignment::Vertical::Top,
+            |renderer, limits| {
+                self.content.as_widget().layout(renderer, limits)
+            },
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        renderer.draw_nine_slice(
+            self.handle.clone(),
+            self.insets,
+            layout.bounds(),
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<NineSlice<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: image::Renderer + 'a,
+{
+    fn from(
+        nine_slice: NineSlice<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(nine_slice)
+    }
+}
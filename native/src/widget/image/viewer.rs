@@ -5,6 +5,7 @@ use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::widget::tree::{self, Tree};
+use crate::window;
 use crate::{
     Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
     Vector, Widget,
@@ -21,6 +22,7 @@ pub struct Viewer<Handle> {
     min_scale: f32,
     max_scale: f32,
     scale_step: f32,
+    nearest_filter_above: f32,
     handle: Handle,
 }
 
@@ -34,6 +36,7 @@ impl<Handle> Viewer<Handle> {
             min_scale: 0.25,
             max_scale: 10.0,
             scale_step: 0.10,
+            nearest_filter_above: 2.0,
             handle,
         }
     }
@@ -80,6 +83,17 @@ impl<Handle> Viewer<Handle> {
         self.scale_step = scale_step;
         self
     }
+
+    /// Sets the magnification of the image of the [`Viewer`] past which
+    /// its pixels are drawn sharp, using [`FilterMethod::Nearest`].
+    ///
+    /// Default is `2.0`
+    ///
+    /// [`FilterMethod::Nearest`]: image::FilterMethod::Nearest
+    pub fn nearest_filter_above(mut self, magnification: f32) -> Self {
+        self.nearest_filter_above = magnification;
+        self
+    }
 }
 
 impl<Message, Renderer, Handle> Widget<Message, Renderer> for Viewer<Handle>
@@ -147,7 +161,7 @@ where
         cursor_position: Point,
         renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
-        _shell: &mut Shell<'_, Message>,
+        shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         let bounds = layout.bounds();
         let is_mouse_over = bounds.contains(cursor_position);
@@ -269,6 +283,22 @@ where
                     event::Status::Ignored
                 }
             }
+            Event::Window(window::Event::RedrawRequested(_)) => {
+                let state = tree.state.downcast_mut::<State>();
+                let is_loading = matches!(
+                    renderer.status(&self.handle),
+                    image::Status::Loading
+                );
+
+                // Images are laid out as a single pixel while they load
+                if state.is_loading && !is_loading {
+                    shell.invalidate_layout();
+                }
+
+                state.is_loading = is_loading;
+
+                event::Status::Ignored
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -319,11 +349,23 @@ where
             image_top_left - state.offset(bounds, image_size)
         };
 
+        let magnification = image_size.width
+            / renderer.dimensions(&self.handle).width.max(1) as f32;
+
+        let filter_method = if magnification > self.nearest_filter_above {
+            image::FilterMethod::Nearest
+        } else {
+            image::FilterMethod::Linear
+        };
+
         renderer.with_layer(bounds, |renderer| {
             renderer.with_translation(translation, |renderer| {
                 image::Renderer::draw(
                     renderer,
                     self.handle.clone(),
+                    filter_method,
+                    1.0,
+                    0.0,
                     Rectangle {
                         x: bounds.x,
                         y: bounds.y,
@@ -342,6 +384,7 @@ pub struct State {
     starting_offset: Vector,
     current_offset: Vector,
     cursor_grabbed_at: Option<Point>,
+    is_loading: bool,
 }
 
 impl Default for State {
@@ -351,6 +394,8 @@ impl Default for State {
             starting_offset: Vector::default(),
             current_offset: Vector::default(),
             cursor_grabbed_at: None,
+            // The first layout may happen before the image is ready
+            is_loading: true,
         }
     }
 }
@@ -426,3 +471,82 @@ where
 
     Size::new(width, height)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::image::mock::{redraw, Renderer};
+    use crate::Theme;
+
+    #[test]
+    fn layout_is_invalidated_once_the_image_is_loaded() {
+        let mut viewer = Viewer::new(());
+        let mut tree = Tree::new(&viewer as &dyn Widget<(), Renderer>);
+        let mut renderer = Renderer {
+            is_loading: true,
+            ..Renderer::default()
+        };
+
+        assert!(!redraw::<()>(&mut viewer, &mut tree, &renderer).0);
+        assert!(!redraw::<()>(&mut viewer, &mut tree, &renderer).0);
+
+        renderer.is_loading = false;
+
+        assert!(redraw::<()>(&mut viewer, &mut tree, &renderer).0);
+        assert!(!redraw::<()>(&mut viewer, &mut tree, &renderer).0);
+    }
+
+    #[test]
+    fn layout_is_invalidated_if_the_image_loads_before_the_first_redraw() {
+        let mut viewer = Viewer::new(());
+        let mut tree = Tree::new(&viewer as &dyn Widget<(), Renderer>);
+        let renderer = Renderer::default();
+
+        assert!(redraw::<()>(&mut viewer, &mut tree, &renderer).0);
+        assert!(!redraw::<()>(&mut viewer, &mut tree, &renderer).0);
+    }
+
+    fn filter_method(viewer: &Viewer<()>, scale: f32) -> image::FilterMethod {
+        let mut renderer = Renderer::default();
+        let mut tree = Tree::new(viewer as &dyn Widget<(), Renderer>);
+        tree.state.downcast_mut::<State>().scale = scale;
+
+        let node = Widget::<(), Renderer>::layout(
+            viewer,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::INFINITY),
+        );
+
+        Widget::<(), Renderer>::draw(
+            viewer,
+            &tree,
+            &mut renderer,
+            &Theme::default(),
+            &renderer::Style::default(),
+            Layout::new(&node),
+            Point::ORIGIN,
+            &node.bounds(),
+        );
+
+        assert_eq!(renderer.filter_methods.len(), 1);
+
+        renderer.filter_methods[0]
+    }
+
+    #[test]
+    fn images_zoomed_past_the_threshold_are_drawn_sharp() {
+        let viewer = Viewer::new(());
+
+        assert_eq!(filter_method(&viewer, 1.0), image::FilterMethod::Linear);
+        assert_eq!(filter_method(&viewer, 2.0), image::FilterMethod::Linear);
+        assert_eq!(filter_method(&viewer, 2.5), image::FilterMethod::Nearest);
+    }
+
+    #[test]
+    fn nearest_filter_threshold_is_configurable() {
+        let viewer = Viewer::new(()).nearest_filter_above(4.0);
+
+        assert_eq!(filter_method(&viewer, 2.5), image::FilterMethod::Linear);
+        assert_eq!(filter_method(&viewer, 4.5), image::FilterMethod::Nearest);
+    }
+}
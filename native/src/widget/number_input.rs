@@ -0,0 +1,949 @@
+//! Display fields that can be filled with numbers.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget;
+use crate::widget::operation::Operation;
+use crate::widget::text_input::{self, StyleSheet, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Vector, Widget,
+};
+
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// A field that can be filled with a number from a range of values.
+///
+/// The value can be typed directly, changed in steps with the spin buttons,
+/// the arrow keys or the mouse wheel, and scrubbed by dragging the label of
+/// the [`NumberInput`].
+///
+/// While the typed text does not represent a number in the range of the
+/// [`NumberInput`], it is drawn using the `invalid` style of the
+/// [`StyleSheet`].
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::number_input;
+/// # use iced_native::renderer::Null;
+/// #
+/// # type NumberInput<'a, T, Message> = number_input::NumberInput<'a, T, Message, Null>;
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     WidthChanged(f32),
+/// }
+///
+/// let width = 50.0;
+///
+/// NumberInput::new(0.0..=100.0, width, Message::WidthChanged)
+///     .step(0.5)
+///     .label("Width")
+///     .unit("px");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Option<widget::Id>,
+    range: RangeInclusive<T>,
+    step: T,
+    value: T,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    format: Box<dyn Fn(T) -> String + 'a>,
+    parse: Box<dyn Fn(&str) -> Option<T> + 'a>,
+    label: Option<String>,
+    unit: Option<String>,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd + Display + FromStr,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default padding of a [`NumberInput`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`NumberInput`]
+    ///   * a function that will be called when the value changes.
+    ///     It receives the new value of the [`NumberInput`] and must produce a
+    ///     `Message`.
+    pub fn new<F>(range: RangeInclusive<T>, value: T, on_change: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        let value = if value >= *range.start() {
+            value
+        } else {
+            *range.start()
+        };
+
+        let value = if value <= *range.end() {
+            value
+        } else {
+            *range.end()
+        };
+
+        NumberInput {
+            id: None,
+            range,
+            step: T::from(1),
+            value,
+            on_change: Box::new(on_change),
+            format: Box::new(|value| value.to_string()),
+            parse: Box::new(|text| text.trim().parse().ok()),
+            label: None,
+            unit: None,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 10.0,
+            size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`NumberInput`].
+    ///
+    /// The [`NumberInput`] can then be focused with [`text_input::focus`].
+    ///
+    /// [`Id`]: text_input::Id
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the function used to display the value of the [`NumberInput`].
+    ///
+    /// By default, the [`Display`] implementation of the value is used.
+    pub fn format(mut self, format: impl Fn(T) -> String + 'a) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets the function used to parse the text typed in the
+    /// [`NumberInput`].
+    ///
+    /// By default, the [`FromStr`] implementation of the value is used.
+    pub fn parse(mut self, parse: impl Fn(&str) -> Option<T> + 'a) -> Self {
+        self.parse = Box::new(parse);
+        self
+    }
+
+    /// Sets the label of the [`NumberInput`].
+    ///
+    /// Dragging the label horizontally changes the value of the
+    /// [`NumberInput`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the unit displayed after the value of the [`NumberInput`].
+    pub fn unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`NumberInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the label and the field of the
+    /// [`NumberInput`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the font of the [`NumberInput`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`NumberInput`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn text_size(&self, renderer: &Renderer) -> f32 {
+        self.size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn unit_width(&self, renderer: &Renderer) -> f32 {
+        self.unit.as_deref().map_or(0.0, |unit| {
+            renderer.measure_width(
+                unit,
+                self.text_size(renderer),
+                self.font.clone(),
+            ) + UNIT_SPACING
+        })
+    }
+
+    /// Returns the current value of the [`NumberInput`], taking into account
+    /// the text being typed.
+    fn current(&self, state: &State) -> f64 {
+        if state.text_input.is_focused() && !state.is_invalid {
+            (self.parse)(&state.value.to_string())
+                .map_or(self.value.into(), Into::into)
+        } else {
+            self.value.into()
+        }
+    }
+
+    /// Parses the given text, returning the value it represents only if it
+    /// is in the range of the [`NumberInput`].
+    fn parse_in_range(&self, text: &str) -> Option<T> {
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+
+        (self.parse)(text)
+            .filter(|value| (start..=end).contains(&(*value).into()))
+    }
+
+    /// Changes the value of the [`NumberInput`] to the given one, snapped to
+    /// its step and clamped to its range.
+    fn change(
+        &self,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+        value: f64,
+    ) {
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+        let step = self.step.into();
+
+        let value = if step > 0.0 {
+            ((value - start) / step).round() * step + start
+        } else {
+            value
+        };
+
+        let Some(value) = T::from_f64(value.max(start).min(end)) else {
+            return;
+        };
+
+        state.value = Value::new(&(self.format)(value));
+        state.is_invalid = false;
+
+        if (self.value.into() - value.into()).abs() > f64::EPSILON {
+            shell.publish((self.on_change)(value));
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(&(self.format)(self.value)))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Keep the text in sync with the value while the user is not typing
+        if !state.text_input.is_focused() {
+            state.value = Value::new(&(self.format)(self.value));
+            state.is_invalid = false;
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.text_size(renderer);
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let label_width = self.label.as_deref().map(|label| {
+            renderer.measure_width(label, text_size, self.font.clone())
+        });
+
+        let label_offset =
+            label_width.map_or(0.0, |label_width| label_width + self.spacing);
+
+        let padding = Padding {
+            right: self.padding.right
+                + self.unit_width(renderer)
+                + spin_buttons_width(text_size),
+            ..self.padding
+        };
+
+        let input = text_input::layout(
+            renderer,
+            &limits.shrink(Size::new(label_offset, 0.0)),
+            Length::Fill,
+            padding,
+            self.size,
+            None,
+        )
+        .translate(Vector::new(label_offset, 0.0));
+
+        let input_size = input.size();
+        let size =
+            Size::new(label_offset + input_size.width, input_size.height);
+
+        let mut children = vec![input];
+
+        if let Some(label_width) = label_width {
+            children.push(layout::Node::new(Size::new(
+                label_width,
+                input_size.height,
+            )));
+        }
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(id) = &self.id {
+            operation.bounds(id, layout.bounds());
+        }
+
+        operation.focusable(&mut state.text_input, self.id.as_ref());
+        operation.text_input(&mut state.text_input, self.id.as_ref());
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let label_bounds = children.next().map(|label| label.bounds());
+
+        let (increment, decrement) = spin_buttons(
+            input_layout.bounds(),
+            spin_buttons_width(self.text_size(renderer)),
+        );
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let steps = if increment.contains(cursor_position) {
+                    Some(1.0)
+                } else if decrement.contains(cursor_position) {
+                    Some(-1.0)
+                } else {
+                    None
+                };
+
+                if let Some(steps) = steps {
+                    let value = self.current(state);
+
+                    self.change(state, shell, value + steps * self.step.into());
+
+                    return event::Status::Captured;
+                }
+
+                if label_bounds
+                    .is_some_and(|bounds| bounds.contains(cursor_position))
+                {
+                    state.scrub = Some(Scrub {
+                        origin: cursor_position.x,
+                        value: self.current(state),
+                    });
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.scrub.is_some() =>
+            {
+                state.scrub = None;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(scrub) = state.scrub {
+                    let steps =
+                        ((position.x - scrub.origin) / SCRUB_DISTANCE).round();
+
+                    self.change(
+                        state,
+                        shell,
+                        scrub.value + f64::from(steps) * self.step.into(),
+                    );
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if state.text_input.is_focused()
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y != 0.0 {
+                    let value = self.current(state);
+
+                    self.change(
+                        state,
+                        shell,
+                        value + f64::from(y.signum()) * self.step.into(),
+                    );
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if state.text_input.is_focused() => {
+                let steps = match key_code {
+                    keyboard::KeyCode::Up => Some(1.0),
+                    keyboard::KeyCode::Down => Some(-1.0),
+                    keyboard::KeyCode::PageUp => Some(10.0),
+                    keyboard::KeyCode::PageDown => Some(-10.0),
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => Some(0.0),
+                    _ => None,
+                };
+
+                if let Some(steps) = steps {
+                    let steps = if modifiers.shift() {
+                        steps * 10.0
+                    } else {
+                        steps
+                    };
+
+                    if !(state.is_invalid && steps == 0.0) {
+                        let value = self.current(state);
+
+                        self.change(
+                            state,
+                            shell,
+                            value + steps * self.step.into(),
+                        );
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        let was_focused = state.text_input.is_focused();
+
+        let mut edits = Vec::new();
+        let mut local_shell = Shell::new(&mut edits);
+
+        let event_status = text_input::update(
+            event,
+            input_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            &mut state.value,
+            self.size,
+            &self.font,
+            false,
+            Some(&|text| text),
+            None,
+            &None,
+            || &mut state.text_input,
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        if let Some(text) = edits.pop() {
+            match self.parse_in_range(&text) {
+                Some(value) => {
+                    state.is_invalid = false;
+
+                    if (self.value.into() - value.into()).abs() > f64::EPSILON {
+                        shell.publish((self.on_change)(value));
+                    }
+                }
+                None => {
+                    state.is_invalid = true;
+                }
+            }
+        }
+
+        if was_focused && !state.text_input.is_focused() {
+            state.value = Value::new(&(self.format)(self.value));
+            state.is_invalid = false;
+        }
+
+        event_status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let text_size = self.text_size(renderer);
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let input_bounds = input_layout.bounds();
+
+        let appearance = if state.is_invalid {
+            theme.invalid(&self.style)
+        } else if state.text_input.is_focused() {
+            theme.focused(&self.style)
+        } else if input_bounds.contains(cursor_position) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        text_input::draw_with_appearance(
+            renderer,
+            theme,
+            input_layout,
+            &state.text_input,
+            &state.value,
+            "",
+            self.size,
+            &self.font,
+            false,
+            false,
+            None,
+            &self.style,
+            appearance,
+        );
+
+        if let Some(label_layout) = children.next() {
+            let bounds = label_layout.bounds();
+
+            renderer.fill_text(Text {
+                content: self.label.as_deref().unwrap_or_default(),
+                bounds: Rectangle {
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                color: style.text_color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+
+        let buttons_width = spin_buttons_width(text_size);
+
+        if let Some(unit) = &self.unit {
+            let text_bounds = input_layout.children().next().unwrap().bounds();
+
+            renderer.fill_text(Text {
+                content: unit,
+                bounds: Rectangle {
+                    x: text_bounds.x + text_bounds.width + UNIT_SPACING,
+                    y: text_bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..text_bounds
+                },
+                size: text_size,
+                color: theme.placeholder_color(&self.style),
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+
+        let (increment, decrement) = spin_buttons(input_bounds, buttons_width);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: increment.x,
+                    y: input_bounds.y,
+                    width: appearance.border_width,
+                    height: input_bounds.height,
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            appearance.border_color,
+        );
+
+        for (bounds, icon) in [(increment, '+'), (decrement, '-')] {
+            let color = if bounds.contains(cursor_position) {
+                theme.value_color(&self.style)
+            } else {
+                appearance.icon_color
+            };
+
+            renderer.fill_text(Text {
+                content: &icon.to_string(),
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: bounds.height.min(text_size),
+                color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let is_over_label = children
+            .next()
+            .is_some_and(|label| label.bounds().contains(cursor_position));
+
+        let (increment, decrement) = spin_buttons(
+            input_layout.bounds(),
+            spin_buttons_width(self.text_size(renderer)),
+        );
+
+        if state.scrub.is_some() || is_over_label {
+            mouse::Interaction::ResizingHorizontally
+        } else if increment.contains(cursor_position)
+            || decrement.contains(cursor_position)
+        {
+            mouse::Interaction::Pointer
+        } else {
+            text_input::mouse_interaction(input_layout, cursor_position, false)
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(number_input)
+    }
+}
+
+/// The local state of a [`NumberInput`].
+#[derive(Debug, Clone)]
+pub struct State {
+    text_input: text_input::State,
+    value: Value,
+    is_invalid: bool,
+    scrub: Option<Scrub>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Scrub {
+    origin: f32,
+    value: f64,
+}
+
+impl State {
+    /// Creates a new [`State`] for a [`NumberInput`] displaying the given
+    /// text.
+    pub fn new(text: &str) -> Self {
+        Self {
+            text_input: text_input::State::new(),
+            value: Value::new(text),
+            is_invalid: false,
+            scrub: None,
+        }
+    }
+
+    /// Returns whether the [`NumberInput`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.text_input.is_focused()
+    }
+
+    /// Returns whether the text typed in the [`NumberInput`] is invalid.
+    pub fn is_invalid(&self) -> bool {
+        self.is_invalid
+    }
+}
+
+/// The horizontal distance the cursor needs to travel while dragging the
+/// label of a [`NumberInput`] to change its value by one step.
+const SCRUB_DISTANCE: f32 = 5.0;
+
+/// The spacing between the value and the unit of a [`NumberInput`].
+const UNIT_SPACING: f32 = 4.0;
+
+fn spin_buttons_width(text_size: f32) -> f32 {
+    text_size
+}
+
+/// Returns the bounds of the increment and decrement buttons of a
+/// [`NumberInput`] with the given input bounds.
+fn spin_buttons(bounds: Rectangle, width: f32) -> (Rectangle, Rectangle) {
+    let half_height = bounds.height / 2.0;
+
+    let increment = Rectangle {
+        x: bounds.x + bounds.width - width,
+        y: bounds.y,
+        width,
+        height: half_height,
+    };
+
+    let decrement = Rectangle {
+        y: bounds.y + half_height,
+        ..increment
+    };
+
+    (increment, decrement)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    use std::fmt;
+
+    fn number_input<T>(
+        range: RangeInclusive<T>,
+        step: T,
+        value: T,
+    ) -> NumberInput<'static, T, T, Null>
+    where
+        T: Copy + From<u8> + PartialOrd + Display + FromStr,
+    {
+        NumberInput::new(range, value, |value| value).step(step)
+    }
+
+    fn change<T>(
+        number_input: &NumberInput<'static, T, T, Null>,
+        state: &mut State,
+        value: f64,
+    ) -> Vec<T>
+    where
+        T: Copy + Into<f64> + num_traits::FromPrimitive,
+    {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        number_input.change(state, &mut shell, value);
+
+        messages
+    }
+
+    #[test]
+    fn change_snaps_to_steps_from_range_start() {
+        let mut state = State::new("");
+
+        let halves = number_input(0.0..=10.0, 0.5, 0.0);
+        assert_eq!(change(&halves, &mut state, 3.3), [3.5]);
+        assert_eq!(state.value.to_string(), "3.5");
+
+        let odds = number_input(1..=9, 2, 1);
+        assert_eq!(change(&odds, &mut state, 4.2), [5]);
+        assert_eq!(change(&odds, &mut state, 3.8), [3]);
+    }
+
+    #[test]
+    fn change_clamps_to_range() {
+        let mut state = State::new("");
+        let percent = number_input(0u8..=100, 1, 50);
+
+        assert_eq!(change(&percent, &mut state, 250.0), [100]);
+        assert_eq!(change(&percent, &mut state, -3.0), [0]);
+        assert_eq!(change(&percent, &mut state, f64::NAN), [0]);
+    }
+
+    #[test]
+    fn change_only_publishes_different_values() {
+        let mut state = State::new("5.");
+        state.is_invalid = true;
+
+        let number_input = number_input(0..=10, 1, 5);
+
+        assert!(change(&number_input, &mut state, 5.2).is_empty());
+        assert_eq!(state.value.to_string(), "5");
+        assert!(!state.is_invalid());
+    }
+
+    #[test]
+    fn change_ignores_unrepresentable_values() {
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+        struct Even(u8);
+
+        impl From<u8> for Even {
+            fn from(n: u8) -> Self {
+                Even(n)
+            }
+        }
+
+        impl FromStr for Even {
+            type Err = std::num::ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map(Even)
+            }
+        }
+
+        impl fmt::Display for Even {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl From<Even> for f64 {
+            fn from(even: Even) -> f64 {
+                f64::from(even.0)
+            }
+        }
+
+        impl num_traits::FromPrimitive for Even {
+            fn from_i64(n: i64) -> Option<Self> {
+                u8::try_from(n).ok().filter(|n| n % 2 == 0).map(Even)
+            }
+
+            fn from_u64(n: u64) -> Option<Self> {
+                u8::try_from(n).ok().filter(|n| n % 2 == 0).map(Even)
+            }
+        }
+
+        let mut state = State::new("3");
+        state.is_invalid = true;
+
+        let number_input = number_input(Even(0)..=Even(10), Even(1), Even(2));
+
+        assert!(change(&number_input, &mut state, 3.0).is_empty());
+        assert_eq!(state.value.to_string(), "3");
+        assert!(state.is_invalid());
+
+        assert_eq!(change(&number_input, &mut state, 4.0), [Even(4)]);
+    }
+
+    #[test]
+    fn parse_accepts_trimmed_values_in_range() {
+        let number_input: NumberInput<'_, u8, u8, Null> =
+            NumberInput::new(1..=10, 5, |value| value);
+
+        assert_eq!(number_input.parse_in_range(" 7 "), Some(7));
+        assert_eq!(number_input.parse_in_range("10"), Some(10));
+        assert_eq!(number_input.parse_in_range("0"), None);
+        assert_eq!(number_input.parse_in_range("11"), None);
+        assert_eq!(number_input.parse_in_range("seven"), None);
+
+        let number_input = number_input.parse(|text| {
+            text.strip_suffix('%').and_then(|text| text.parse().ok())
+        });
+
+        assert_eq!(number_input.parse_in_range("7%"), Some(7));
+        assert_eq!(number_input.parse_in_range("7"), None);
+    }
+}
@@ -0,0 +1,183 @@
+//! Fade a widget in or out.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::{Operation, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+/// A widget that draws its content with some opacity.
+///
+/// The content is composited as a whole, which makes [`Opacity`] suitable for
+/// fade transitions. It stays interactive no matter its opacity.
+///
+/// Overlays of the content, like the menu of a [`PickList`], are drawn
+/// opaquely.
+///
+/// [`PickList`]: crate::widget::PickList
+#[allow(missing_debug_implementations)]
+pub struct Opacity<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    opacity: f32,
+}
+
+impl<'a, Message, Renderer> Opacity<'a, Message, Renderer> {
+    /// Creates an [`Opacity`] with the given content and opacity.
+    ///
+    /// The opacity is clamped between `0.0` (fully transparent) and `1.0`
+    /// (fully opaque).
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        opacity: f32,
+    ) -> Self {
+        Opacity {
+            content: content.into(),
+            opacity: opacity.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Opacity<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        if self.opacity <= 0.0 {
+            return;
+        }
+
+        let draw = |renderer: &mut Renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                renderer_style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        };
+
+        if self.opacity < 1.0 {
+            renderer.with_opacity(self.opacity, draw);
+        } else {
+            draw(renderer);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Opacity<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        opacity: Opacity<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(opacity)
+    }
+}
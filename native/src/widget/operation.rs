@@ -8,6 +8,7 @@ pub use scrollable::Scrollable;
 pub use text_input::TextInput;
 
 use crate::widget::Id;
+use crate::Rectangle;
 
 use std::any::Any;
 use std::fmt;
@@ -37,6 +38,19 @@ pub trait Operation<T> {
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
 
+    /// Operates on a widget with the given [`Id`] and bounds, before any
+    /// other operation on the widget.
+    fn bounds(&mut self, _id: &Id, _bounds: Rectangle) {}
+
+    /// Operates on a widget that displays some text within the given bounds.
+    fn text(&mut self, _id: Option<&Id>, _bounds: Rectangle, _text: &str) {}
+
+    /// Operates on a widget of the given type with the given bounds, before
+    /// any other operation on the widget.
+    ///
+    /// Widgets are only reported when the `debug` feature is enabled.
+    fn inspect(&mut self, _name: &'static str, _bounds: Rectangle) {}
+
     /// Finishes the [`Operation`] and returns its [`Outcome`].
     fn finish(&self) -> Outcome<T> {
         Outcome::None
@@ -42,8 +42,8 @@ use crate::widget;
 use crate::widget::container;
 use crate::widget::tree::{self, Tree};
 use crate::{
-    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
-    Size, Vector, Widget,
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle,
+    Shadow, Shell, Size, Vector, Widget,
 };
 
 /// A collection of panes distributed using either vertical or horizontal splits
@@ -877,6 +877,7 @@ pub fn draw<Renderer, T>(
                     border_radius: 0.0.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
                 },
                 highlight.color,
             );
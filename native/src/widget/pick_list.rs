@@ -8,13 +8,14 @@ use crate::overlay;
 use crate::overlay::menu::{self, Menu};
 use crate::renderer;
 use crate::text::{self, Text};
+use crate::time::{Duration, Instant};
 use crate::touch;
 use crate::widget::container;
 use crate::widget::scrollable;
 use crate::widget::tree::{self, Tree};
 use crate::{
     Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
-    Shell, Size, Widget,
+    Shadow, Shell, Size, Widget,
 };
 use std::borrow::Cow;
 
@@ -273,8 +274,10 @@ pub struct State<T> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    is_focused: bool,
     hovered_option: Option<usize>,
     last_selection: Option<T>,
+    type_ahead: TypeAhead,
 }
 
 impl<T> State<T> {
@@ -284,8 +287,10 @@ impl<T> State<T> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            is_focused: bool::default(),
             hovered_option: Option::default(),
             last_selection: Option::default(),
+            type_ahead: TypeAhead::default(),
         }
     }
 }
@@ -296,6 +301,61 @@ impl<T> Default for State<T> {
     }
 }
 
+/// The characters typed in quick succession while a [`PickList`] is focused.
+#[derive(Debug, Default)]
+struct TypeAhead {
+    query: String,
+    updated_at: Option<Instant>,
+}
+
+impl TypeAhead {
+    /// The time after which a new key press starts a new query.
+    const TIMEOUT: Duration = Duration::from_millis(1000);
+
+    fn push(&mut self, c: char) -> &str {
+        let now = Instant::now();
+
+        if !matches!(
+            self.updated_at,
+            Some(updated_at) if now - updated_at <= Self::TIMEOUT
+        ) {
+            self.query.clear();
+        }
+
+        self.query.extend(c.to_lowercase());
+        self.updated_at = Some(now);
+
+        &self.query
+    }
+
+    /// Finds the index of the option matching the given query, searching
+    /// after the `current` one.
+    ///
+    /// Typing the same character repeatedly cycles through the options
+    /// starting with it.
+    fn find<T: ToString>(
+        query: &str,
+        options: &[T],
+        current: Option<usize>,
+    ) -> Option<usize> {
+        let mut chars = query.chars();
+        let first = chars.next()?;
+
+        let (query, start) = if chars.all(|c| c == first) {
+            (&query[..first.len_utf8()], current.map_or(0, |i| i + 1))
+        } else {
+            (query, current.unwrap_or(0))
+        };
+
+        let matches =
+            |option: &T| option.to_string().to_lowercase().starts_with(query);
+
+        (start..options.len())
+            .chain(0..start.min(options.len()))
+            .find(|&index| matches(&options[index]))
+    }
+}
+
 /// The handle to the right side of the [`PickList`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Handle<Font> {
@@ -405,12 +465,15 @@ pub fn update<'a, T, Message>(
     state: impl FnOnce() -> &'a mut State<T>,
 ) -> event::Status
 where
-    T: PartialEq + Clone + 'a,
+    T: ToString + PartialEq + Clone + 'a,
 {
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             let state = state();
+            let is_clicked = layout.bounds().contains(cursor_position);
+
+            state.is_focused = is_clicked || state.last_selection.is_some();
 
             let event_status = if state.is_open {
                 // Event wasn't processed by overlay, so cursor was clicked either outside it's
@@ -418,7 +481,7 @@ where
                 state.is_open = false;
 
                 event::Status::Captured
-            } else if layout.bounds().contains(cursor_position) {
+            } else if is_clicked {
                 state.is_open = true;
                 state.hovered_option =
                     options.iter().position(|option| Some(option) == selected);
@@ -481,6 +544,110 @@ where
                 event::Status::Ignored
             }
         }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+            let state = state();
+
+            if !state.is_focused {
+                return event::Status::Ignored;
+            }
+
+            if state.is_open {
+                match key_code {
+                    keyboard::KeyCode::Up => {
+                        state.hovered_option = Some(
+                            state
+                                .hovered_option
+                                .map_or(0, |index| index.saturating_sub(1)),
+                        );
+                    }
+                    keyboard::KeyCode::Down => {
+                        let last = options.len().saturating_sub(1);
+
+                        state.hovered_option = Some(
+                            state
+                                .hovered_option
+                                .map_or(0, |index| (index + 1).min(last)),
+                        );
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        if let Some(option) = state
+                            .hovered_option
+                            .and_then(|index| options.get(index))
+                        {
+                            shell.publish((on_selected)(option.clone()));
+                        }
+
+                        state.is_open = false;
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.is_open = false;
+                    }
+                    _ => return event::Status::Ignored,
+                }
+            } else {
+                let current =
+                    options.iter().position(|option| Some(option) == selected);
+
+                let next = match key_code {
+                    keyboard::KeyCode::Up => current.map_or_else(
+                        || options.len().checked_sub(1),
+                        |index| index.checked_sub(1),
+                    ),
+                    keyboard::KeyCode::Down => {
+                        Some(current.map_or(0, |index| index + 1))
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        state.is_open = true;
+                        state.hovered_option = current;
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.is_focused = false;
+
+                        return event::Status::Ignored;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                if let Some(option) = next.and_then(|index| options.get(index))
+                {
+                    shell.publish((on_selected)(option.clone()));
+                }
+            }
+
+            event::Status::Captured
+        }
+        Event::Keyboard(keyboard::Event::CharacterReceived(c)) => {
+            let state = state();
+
+            if !state.is_focused
+                || state.keyboard_modifiers.command()
+                || c.is_control()
+            {
+                return event::Status::Ignored;
+            }
+
+            let current = if state.is_open {
+                state.hovered_option
+            } else {
+                options.iter().position(|option| Some(option) == selected)
+            };
+
+            let query = state.type_ahead.push(c);
+
+            if let Some(index) = TypeAhead::find(query, options, current) {
+                if state.is_open {
+                    state.hovered_option = Some(index);
+                } else if Some(index) != current {
+                    shell.publish((on_selected)(options[index].clone()));
+                }
+            }
+
+            event::Status::Captured
+        }
         Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
             let state = state();
 
@@ -587,6 +754,7 @@ pub fn draw<'a, T, Renderer>(
             border_color: style.border_color,
             border_width: style.border_width,
             border_radius: style.border_radius.into(),
+            shadow: Shadow::default(),
         },
         style.background,
     );
@@ -2,7 +2,9 @@
 use crate::layout;
 use crate::renderer;
 use crate::widget::Tree;
-use crate::{Color, Element, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::{
+    Color, Element, Layout, Length, Point, Rectangle, Shadow, Size, Widget,
+};
 
 use std::ops::RangeInclusive;
 
@@ -132,6 +134,7 @@ where
                 border_radius: style.border_radius.into(),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
             },
             style.background,
         );
@@ -146,6 +149,7 @@ where
                     border_radius: style.border_radius.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
                 },
                 style.bar,
             );
@@ -9,7 +9,7 @@ use crate::touch;
 use crate::widget::{self, Row, Text, Tree};
 use crate::{
     Alignment, Clipboard, Color, Element, Layout, Length, Pixels, Point,
-    Rectangle, Shell, Widget,
+    Rectangle, Shadow, Shell, Widget,
 };
 
 pub use iced_style::radio::{Appearance, StyleSheet};
@@ -271,6 +271,7 @@ where
                     border_radius: (size / 2.0).into(),
                     border_width: custom_style.border_width,
                     border_color: custom_style.border_color,
+                    shadow: Shadow::default(),
                 },
                 custom_style.background,
             );
@@ -287,6 +288,7 @@ where
                         border_radius: (dot_size / 2.0).into(),
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
+                        shadow: Shadow::default(),
                     },
                     custom_style.dot_color,
                 );
@@ -3,7 +3,8 @@ use crate::layout;
 use crate::renderer;
 use crate::widget::Tree;
 use crate::{
-    Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Widget,
+    Color, Element, Layout, Length, Pixels, Point, Rectangle, Shadow, Size,
+    Widget,
 };
 
 pub use iced_style::rule::{Appearance, FillMode, StyleSheet};
@@ -128,6 +129,7 @@ where
                 border_radius: style.radius.into(),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
             },
             style.color,
         );
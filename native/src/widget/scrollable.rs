@@ -11,7 +11,7 @@ use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
 use crate::{
     Background, Clipboard, Color, Command, Element, Layout, Length, Pixels,
-    Point, Rectangle, Shell, Size, Vector, Widget,
+    Point, Rectangle, Shadow, Shell, Size, Vector, Widget,
 };
 
 pub use iced_style::scrollable::StyleSheet;
@@ -210,6 +210,10 @@ where
     ) {
         let state = tree.state.downcast_mut::<State>();
 
+        if let Some(id) = &self.id {
+            operation.bounds(&id.0, layout.bounds());
+        }
+
         operation.scrollable(state, self.id.as_ref().map(|id| &id.0));
 
         operation.container(
@@ -827,6 +831,7 @@ pub fn draw<Renderer>(
                             border_radius: style.border_radius.into(),
                             border_width: style.border_width,
                             border_color: style.border_color,
+                            shadow: Shadow::default(),
                         },
                         style
                             .background
@@ -845,6 +850,7 @@ pub fn draw<Renderer>(
                             border_radius: style.scroller.border_radius.into(),
                             border_width: style.scroller.border_width,
                             border_color: style.scroller.border_color,
+                            shadow: Shadow::default(),
                         },
                         style.scroller.color,
                     );
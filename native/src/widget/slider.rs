@@ -8,8 +8,8 @@ use crate::renderer;
 use crate::touch;
 use crate::widget::tree::{self, Tree};
 use crate::{
-    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
-    Size, Widget,
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle,
+    Shadow, Shell, Size, Widget,
 };
 
 use std::ops::RangeInclusive;
@@ -408,6 +408,7 @@ pub fn draw<T, R>(
             border_radius: Default::default(),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
         },
         style.rail.colors.0,
     );
@@ -423,6 +424,7 @@ pub fn draw<T, R>(
             border_radius: Default::default(),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
         },
         style.rail.colors.1,
     );
@@ -438,6 +440,7 @@ pub fn draw<T, R>(
             border_radius: handle_border_radius.into(),
             border_width: style.handle.border_width,
             border_color: style.handle.border_color,
+            shadow: Shadow::default(),
         },
         style.handle.color,
     );
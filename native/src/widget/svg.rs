@@ -10,7 +10,7 @@ use crate::{
 use std::path::PathBuf;
 
 pub use iced_style::svg::{Appearance, StyleSheet};
-pub use svg::Handle;
+pub use svg::{Handle, Stylesheet};
 
 /// A vector graphics image.
 ///
@@ -29,6 +29,7 @@ where
     height: Length,
     content_fit: ContentFit,
     style: <Renderer::Theme as StyleSheet>::Style,
+    stylesheet: Option<Box<dyn Fn(&Renderer::Theme) -> Stylesheet>>,
 }
 
 impl<Renderer> Svg<Renderer>
@@ -44,6 +45,7 @@ where
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
             style: Default::default(),
+            stylesheet: None,
         }
     }
 
@@ -88,6 +90,19 @@ where
         self.style = style;
         self
     }
+
+    /// Sets the function producing the [`Stylesheet`] of this [`Svg`] from
+    /// the current theme.
+    ///
+    /// This lets multi-color icons follow the theme of the application.
+    #[must_use]
+    pub fn stylesheet(
+        mut self,
+        f: impl Fn(&Renderer::Theme) -> Stylesheet + 'static,
+    ) -> Self {
+        self.stylesheet = Some(Box::new(f));
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Svg<Renderer>
@@ -165,10 +180,12 @@ where
             };
 
             let appearance = theme.appearance(&self.style);
+            let stylesheet = self.stylesheet.as_ref().map(|f| f(theme));
 
             renderer.draw(
                 self.handle.clone(),
                 appearance.color,
+                stylesheet,
                 drawing_bounds + offset,
             );
         };
@@ -26,7 +26,7 @@ use crate::widget::tree::{self, Tree};
 use crate::window;
 use crate::{
     Clipboard, Color, Command, Element, Layout, Length, Padding, Pixels, Point,
-    Rectangle, Shell, Size, Vector, Widget,
+    Rectangle, Shadow, Shell, Size, Vector, Widget,
 };
 
 pub use iced_style::text_input::{Appearance, StyleSheet};
@@ -267,12 +267,16 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State>();
 
+        if let Some(id) = &self.id {
+            operation.bounds(&id.0, layout.bounds());
+        }
+
         operation.focusable(state, self.id.as_ref().map(|id| &id.0));
         operation.text_input(state, self.id.as_ref().map(|id| &id.0));
     }
@@ -932,15 +936,7 @@ pub fn draw<Renderer>(
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
-    let secure_value = is_secure.then(|| value.secure());
-    let value = secure_value.as_ref().unwrap_or(value);
-
-    let bounds = layout.bounds();
-
-    let mut children_layout = layout.children();
-    let text_bounds = children_layout.next().unwrap().bounds();
-
-    let is_mouse_over = bounds.contains(cursor_position);
+    let is_mouse_over = layout.bounds().contains(cursor_position);
 
     let appearance = if is_disabled {
         theme.disabled(style)
@@ -952,12 +948,58 @@ pub fn draw<Renderer>(
         theme.active(style)
     };
 
+    draw_with_appearance(
+        renderer,
+        theme,
+        layout,
+        state,
+        value,
+        placeholder,
+        size,
+        font,
+        is_disabled,
+        is_secure,
+        icon,
+        style,
+        appearance,
+    );
+}
+
+/// Draws a [`TextInput`] using the given [`Appearance`], instead of the one
+/// matching its current status.
+pub(crate) fn draw_with_appearance<Renderer>(
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    layout: Layout<'_>,
+    state: &State,
+    value: &Value,
+    placeholder: &str,
+    size: Option<f32>,
+    font: &Renderer::Font,
+    is_disabled: bool,
+    is_secure: bool,
+    icon: Option<&Icon<Renderer::Font>>,
+    style: &<Renderer::Theme as StyleSheet>::Style,
+    appearance: Appearance,
+) where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    let secure_value = is_secure.then(|| value.secure());
+    let value = secure_value.as_ref().unwrap_or(value);
+
+    let bounds = layout.bounds();
+
+    let mut children_layout = layout.children();
+    let text_bounds = children_layout.next().unwrap().bounds();
+
     renderer.fill_quad(
         renderer::Quad {
             bounds,
             border_radius: appearance.border_radius.into(),
             border_width: appearance.border_width,
             border_color: appearance.border_color,
+            shadow: Shadow::default(),
         },
         appearance.background,
     );
@@ -1013,6 +1055,7 @@ pub fn draw<Renderer>(
                             border_radius: 0.0.into(),
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
+                            shadow: Shadow::default(),
                         },
                         theme.value_color(style),
                     ))
@@ -1060,6 +1103,7 @@ pub fn draw<Renderer>(
                             border_radius: 0.0.into(),
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
+                            shadow: Shadow::default(),
                         },
                         theme.selection_color(style),
                     )),
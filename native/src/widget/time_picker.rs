@@ -0,0 +1,726 @@
+//! Let your users pick a time of the day.
+//!
+//! A [`TimePicker`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::date_picker::{
+    cell_size, draw_field, layout_field, place_overlay, Cells,
+};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shadow, Shell, Size, Widget,
+};
+
+use std::fmt;
+
+pub use iced_style::time_picker::{Appearance, Grid, StyleSheet};
+
+/// A time of the day, with a precision of minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u32,
+    minute: u32,
+}
+
+impl Time {
+    /// The first minute of the day.
+    pub const MIDNIGHT: Self = Self { hour: 0, minute: 0 };
+
+    /// Creates a new [`Time`] from the given hour (`0..24`) and minute
+    /// (`0..60`).
+    ///
+    /// Returns `None` if the time does not exist.
+    pub fn new(hour: u32, minute: u32) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    /// Returns the hour of the [`Time`], from 0 to 23.
+    pub fn hour(self) -> u32 {
+        self.hour
+    }
+
+    /// Returns the minute of the [`Time`], from 0 to 59.
+    pub fn minute(self) -> u32 {
+        self.minute
+    }
+
+    /// Returns the hour of the [`Time`] in the 12-hour clock, from 1 to 12,
+    /// and whether it is after noon.
+    pub fn hour12(self) -> (u32, bool) {
+        let hour = match self.hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+
+        (hour, self.hour >= 12)
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// A widget for picking a [`Time`] of the day.
+///
+/// The hours and minutes are displayed as an overlay under the field when it
+/// is clicked. Picking an hour and then a minute produces the message.
+///
+/// # Example
+/// ```
+/// # pub type TimePicker<'a, Message> = iced_native::widget::TimePicker<'a, Message, iced_native::renderer::Null>;
+/// use iced_native::widget::time_picker::Time;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TimeSelected(Time),
+/// }
+///
+/// let time_picker = TimePicker::new(Time::new(9, 30), Message::TimeSelected)
+///     .minute_step(15)
+///     .twelve_hour(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TimePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    selected: Option<Time>,
+    on_select: Box<dyn Fn(Time) -> Message + 'a>,
+    placeholder: Option<String>,
+    format: Option<Box<dyn Fn(Time) -> String + 'a>>,
+    twelve_hour: bool,
+    minute_step: u32,
+    min: Option<Time>,
+    max: Option<Time>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> TimePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default padding of a [`TimePicker`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// The default amount of minutes between the minutes that can be picked.
+    pub const DEFAULT_MINUTE_STEP: u32 = 5;
+
+    /// Creates a new [`TimePicker`] with the currently selected [`Time`] and
+    /// the message to produce when a time is picked.
+    pub fn new(
+        selected: Option<Time>,
+        on_select: impl Fn(Time) -> Message + 'a,
+    ) -> Self {
+        Self {
+            selected,
+            on_select: Box::new(on_select),
+            placeholder: None,
+            format: None,
+            twelve_hour: false,
+            minute_step: Self::DEFAULT_MINUTE_STEP,
+            min: None,
+            max: None,
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the placeholder of the [`TimePicker`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the function used to display a [`Time`] in the field of the
+    /// [`TimePicker`].
+    pub fn format(mut self, format: impl Fn(Time) -> String + 'a) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets whether the [`TimePicker`] uses the 12-hour clock.
+    pub fn twelve_hour(mut self, twelve_hour: bool) -> Self {
+        self.twelve_hour = twelve_hour;
+        self
+    }
+
+    /// Sets the amount of minutes between the minutes that can be picked.
+    ///
+    /// The step is clamped between 1 and 30 minutes.
+    pub fn minute_step(mut self, minute_step: u32) -> Self {
+        self.minute_step = minute_step.clamp(1, 30);
+        self
+    }
+
+    /// Sets the earliest [`Time`] that can be picked.
+    pub fn min(mut self, min: Time) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Time`] that can be picked.
+    pub fn max(mut self, max: Time) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the width of the [`TimePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TimePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TimePicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the font of the [`TimePicker`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`TimePicker`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn display(&self, time: Time) -> String {
+        if let Some(format) = &self.format {
+            format(time)
+        } else if self.twelve_hour {
+            let (hour, is_pm) = time.hour12();
+
+            format!(
+                "{}:{:02} {}",
+                hour,
+                time.minute,
+                if is_pm { "PM" } else { "AM" }
+            )
+        } else {
+            time.to_string()
+        }
+    }
+
+    fn is_selectable(&self, time: Time) -> bool {
+        !matches!(self.min, Some(min) if time < min)
+            && !matches!(self.max, Some(max) if time > max)
+    }
+
+    fn is_hour_selectable(&self, hour: u32) -> bool {
+        !matches!(self.min, Some(min) if hour < min.hour)
+            && !matches!(self.max, Some(max) if hour > max.hour)
+    }
+
+    fn minutes(&self) -> impl Iterator<Item = u32> {
+        (0..60).step_by(self.minute_step as usize)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TimePicker<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let sample = self.display(Time {
+            hour: 20,
+            minute: 48,
+        });
+
+        layout_field(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.text_size,
+            &self.font,
+            &[Some(sample.as_str()), self.placeholder.as_deref()],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if state.is_open {
+                    // The overlay captures the clicks inside of it, so the
+                    // cursor was clicked outside of the grid.
+                    state.is_open = false;
+
+                    event::Status::Captured
+                } else if layout.bounds().contains(cursor_position) {
+                    state.is_open = true;
+                    state.hour = self.selected.map(Time::hour);
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let appearance = if bounds.contains(cursor_position) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        draw_field(
+            renderer,
+            bounds,
+            appearance,
+            self.padding,
+            self.text_size,
+            &self.font,
+            self.selected.map(|time| self.display(time)).as_deref(),
+            self.placeholder.as_deref(),
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.is_open {
+            Some(overlay::Element::new(
+                layout.position(),
+                Box::new(Clock {
+                    picker: self,
+                    state,
+                    target_height: layout.bounds().height,
+                }),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<TimePicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(time_picker: TimePicker<'a, Message, Renderer>) -> Self {
+        Self::new(time_picker)
+    }
+}
+
+/// The local state of a [`TimePicker`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    is_open: bool,
+    hour: Option<u32>,
+}
+
+impl State {
+    /// Creates a new [`State`] for a [`TimePicker`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the grid of the [`TimePicker`] is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+/// The hour and minute grids of an open [`TimePicker`].
+struct Clock<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    picker: &'a TimePicker<'b, Message, Renderer>,
+    state: &'a mut State,
+    target_height: f32,
+}
+
+/// A part of a [`Clock`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Hour(u32),
+    Minute(u32),
+}
+
+impl<'a, 'b, Message, Renderer> Clock<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The amount of columns of each grid.
+    const COLUMNS: usize = 6;
+
+    /// The width of the grids, in cells, including the gap between them.
+    const WIDTH: f32 = 2.0 * Self::COLUMNS as f32 + 0.5;
+
+    fn minute_rows(&self) -> usize {
+        self.picker.minutes().count().div_ceil(Self::COLUMNS)
+    }
+
+    fn grids(&self, bounds: Rectangle) -> (Cells, Cells) {
+        let padding = self.picker.padding;
+        let size = (bounds.width - padding.horizontal()) / Self::WIDTH;
+        let origin =
+            Point::new(bounds.x + padding.left, bounds.y + padding.top);
+
+        (
+            Cells::new(origin, size, Self::COLUMNS, 24 / Self::COLUMNS),
+            Cells::new(
+                Point::new(
+                    origin.x + (Self::COLUMNS as f32 + 0.5) * size,
+                    origin.y,
+                ),
+                size,
+                Self::COLUMNS,
+                self.minute_rows(),
+            ),
+        )
+    }
+
+    fn target(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<Target> {
+        let (hours, minutes) = self.grids(layout.bounds());
+
+        if let Some((row, column)) = hours.position(cursor_position) {
+            return Some(Target::Hour((row * Self::COLUMNS + column) as u32));
+        }
+
+        let (row, column) = minutes.position(cursor_position)?;
+
+        self.picker
+            .minutes()
+            .nth(row * Self::COLUMNS + column)
+            .map(Target::Minute)
+    }
+
+    fn hour(&self) -> u32 {
+        self.state
+            .hour
+            .unwrap_or_else(|| self.picker.min.map_or(0, Time::hour))
+    }
+
+    fn is_selectable(&self, target: Target) -> bool {
+        match target {
+            Target::Hour(hour) => self.picker.is_hour_selectable(hour),
+            Target::Minute(minute) => self.picker.is_selectable(Time {
+                hour: self.hour(),
+                minute,
+            }),
+        }
+    }
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Clock<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let cell = cell_size(renderer, self.picker.text_size);
+        let padding = self.picker.padding;
+        let rows = self.minute_rows().max(24 / Self::COLUMNS);
+
+        let size = Size::new(
+            Self::WIDTH * cell + padding.horizontal(),
+            rows as f32 * cell + padding.vertical(),
+        );
+
+        let mut node = layout::Node::new(size);
+
+        node.move_to(place_overlay(bounds, position, self.target_height, size));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !layout.bounds().contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+
+                match self.target(layout, cursor_position) {
+                    Some(target) if self.is_selectable(target) => {
+                        match target {
+                            Target::Hour(hour) => {
+                                self.state.hour = Some(hour);
+                            }
+                            Target::Minute(minute) => {
+                                shell.publish((self.picker.on_select)(Time {
+                                    hour: self.hour(),
+                                    minute,
+                                }));
+
+                                self.state.is_open = false;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                self.state.is_open = false;
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match self.target(layout, cursor_position) {
+            Some(target) if self.is_selectable(target) => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let picker = self.picker;
+        let bounds = layout.bounds();
+        let appearance = theme.grid(&picker.style);
+        let (hours, minutes) = self.grids(bounds);
+        let hovered = self.target(layout, cursor_position);
+        let hour = self.hour();
+        let text_size =
+            picker.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_color: appearance.border_color,
+                border_width: appearance.border_width,
+                border_radius: appearance.border_radius.into(),
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        let cells = (0..24).map(|hour| {
+            let index = hour as usize;
+
+            (
+                Target::Hour(hour),
+                hours.bounds(index / Self::COLUMNS, index % Self::COLUMNS),
+            )
+        });
+
+        let cells =
+            cells.chain(picker.minutes().enumerate().map(|(index, minute)| {
+                (
+                    Target::Minute(minute),
+                    minutes
+                        .bounds(index / Self::COLUMNS, index % Self::COLUMNS),
+                )
+            }));
+
+        for (target, bounds) in cells {
+            let is_selectable = self.is_selectable(target);
+
+            let (label, is_selected) = match target {
+                Target::Hour(value) => {
+                    let label = if picker.twelve_hour {
+                        let (hour, is_pm) = Time {
+                            hour: value,
+                            minute: 0,
+                        }
+                        .hour12();
+
+                        format!("{hour}{}", if is_pm { "p" } else { "a" })
+                    } else {
+                        value.to_string()
+                    };
+
+                    (label, self.state.hour == Some(value))
+                }
+                Target::Minute(value) => (
+                    format!("{value:02}"),
+                    picker.selected
+                        == Some(Time {
+                            hour,
+                            minute: value,
+                        }),
+                ),
+            };
+
+            let background = if is_selected && is_selectable {
+                Some(appearance.selected_background)
+            } else if is_selectable && hovered == Some(target) {
+                Some(appearance.hovered_background)
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.cell_border_radius.into(),
+                        border_width: 0.0,
+                        border_color: appearance.highlight_color,
+                        shadow: Shadow::default(),
+                    },
+                    background,
+                );
+            }
+
+            renderer.fill_text(Text {
+                content: &label,
+                size: text_size,
+                font: picker.font.clone(),
+                color: if !is_selectable {
+                    appearance.disabled_color
+                } else if is_selected {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                },
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+}
@@ -8,7 +8,7 @@ use crate::text;
 use crate::widget::{self, Row, Text, Tree};
 use crate::{
     Alignment, Clipboard, Element, Event, Layout, Length, Pixels, Point,
-    Rectangle, Shell, Widget,
+    Rectangle, Shadow, Shell, Widget,
 };
 
 pub use iced_style::toggler::{Appearance, StyleSheet};
@@ -279,6 +279,7 @@ where
                 border_color: style
                     .background_border
                     .unwrap_or(style.background),
+                shadow: Shadow::default(),
             },
             style.background,
         );
@@ -303,6 +304,7 @@ where
                 border_color: style
                     .foreground_border
                     .unwrap_or(style.foreground),
+                shadow: Shadow::default(),
             },
             style.foreground,
         );
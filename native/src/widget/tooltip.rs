@@ -28,7 +28,7 @@ where
     gap: f32,
     padding: f32,
     snap_within_viewport: bool,
-    style: <Renderer::Theme as container::StyleSheet>::Style,
+    style: Option<<Renderer::Theme as container::StyleSheet>::Style>,
 }
 
 impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
@@ -54,7 +54,7 @@ where
             gap: 0.0,
             padding: Self::DEFAULT_PADDING,
             snap_within_viewport: true,
-            style: Default::default(),
+            style: None,
         }
     }
 
@@ -91,11 +91,13 @@ where
     }
 
     /// Sets the style of the [`Tooltip`].
+    ///
+    /// By default, the tooltip style of the theme is used.
     pub fn style(
         mut self,
         style: impl Into<<Renderer::Theme as container::StyleSheet>::Style>,
     ) -> Self {
-        self.style = style.into();
+        self.style = Some(style.into());
         self
     }
 }
@@ -189,6 +191,16 @@ where
         );
 
         let tooltip = &self.tooltip;
+        let default_style;
+
+        let style = match &self.style {
+            Some(style) => style,
+            None => {
+                default_style = container::StyleSheet::tooltip(theme);
+
+                &default_style
+            }
+        };
 
         draw(
             renderer,
@@ -201,7 +213,7 @@ where
             self.gap,
             self.padding,
             self.snap_within_viewport,
-            &self.style,
+            style,
             |renderer, limits| {
                 Widget::<(), Renderer>::layout(tooltip, renderer, limits)
             },
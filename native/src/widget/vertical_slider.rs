@@ -9,7 +9,7 @@ use crate::event::{self, Event};
 use crate::widget::tree::{self, Tree};
 use crate::{
     layout, mouse, renderer, touch, Clipboard, Color, Element, Layout, Length,
-    Pixels, Point, Rectangle, Shell, Size, Widget,
+    Pixels, Point, Rectangle, Shadow, Shell, Size, Widget,
 };
 
 /// An vertical bar and a handle that selects a single value from a range of
@@ -401,6 +401,7 @@ pub fn draw<T, R>(
             border_radius: Default::default(),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
         },
         style.rail.colors.1,
     );
@@ -416,6 +417,7 @@ pub fn draw<T, R>(
             border_radius: Default::default(),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
         },
         style.rail.colors.0,
     );
@@ -431,6 +433,7 @@ pub fn draw<T, R>(
             border_radius: handle_border_radius.into(),
             border_width: style.handle.border_width,
             border_color: style.handle.border_color,
+            shadow: Shadow::default(),
         },
         style.handle.color,
     );
@@ -1,3 +1,4 @@
+use crate::theme;
 use crate::time::Instant;
 
 use std::path::PathBuf;
@@ -55,4 +56,7 @@ pub enum Event {
     /// There will be a single `FilesHoveredLeft` event triggered even if
     /// multiple files were hovered.
     FilesHoveredLeft,
+
+    /// The light or dark preference of the system has changed.
+    ThemeChanged(theme::Mode),
 }
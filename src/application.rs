@@ -18,7 +18,9 @@ pub use iced_native::application::{Appearance, StyleSheet};
 /// interface.
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`. Pressing `Shift+F12` toggles a widget
+/// inspector that highlights the widget under the cursor, and `Ctrl+F12`
+/// outlines the bounds of every widget.
 ///
 /// # Examples
 /// [The repository has a bunch of examples] that use the [`Application`] trait:
@@ -203,6 +205,7 @@ pub trait Application: Sized {
             } else {
                 None
             },
+            image_cache_budget: settings.image_cache_budget,
             ..crate::renderer::Settings::from_env()
         };
 
@@ -217,5 +217,5 @@ pub use runtime::{
     Padding, Point, Rectangle, Size, Vector,
 };
 
-#[cfg(feature = "system")]
+pub use runtime::metrics;
 pub use runtime::system;
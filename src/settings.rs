@@ -1,6 +1,8 @@
 //! Configure your application.
 use crate::window;
 
+pub use iced_graphics::image::Budget;
+
 /// The settings of an application.
 #[derive(Debug, Clone)]
 pub struct Settings<Flags> {
@@ -48,6 +50,12 @@ pub struct Settings<Flags> {
     /// [`Canvas`]: crate::widget::Canvas
     pub antialiasing: bool,
 
+    /// The memory budget of the image caches of the renderer.
+    ///
+    /// Images that are no longer drawn stay cached until the budget is
+    /// exceeded, in which case the least recently used ones are evicted.
+    pub image_cache_budget: Budget,
+
     /// Whether the [`Application`] should exit when the user requests the
     /// window to close (e.g. the user presses the close button).
     ///
@@ -81,6 +89,7 @@ impl<Flags> Settings<Flags> {
             default_text_size: default_settings.default_text_size,
             text_multithreading: default_settings.text_multithreading,
             antialiasing: default_settings.antialiasing,
+            image_cache_budget: default_settings.image_cache_budget,
             exit_on_close_request: default_settings.exit_on_close_request,
             try_opengles_first: default_settings.try_opengles_first,
         }
@@ -100,6 +109,7 @@ where
             default_text_size: 20.0,
             text_multithreading: false,
             antialiasing: false,
+            image_cache_budget: Budget::default(),
             exit_on_close_request: true,
             try_opengles_first: false,
         }
@@ -38,6 +38,27 @@ pub mod checkbox {
         iced_native::widget::Checkbox<'a, Message, Renderer>;
 }
 
+pub mod color_picker {
+    //! Let your users pick a color.
+    pub use iced_native::widget::color_picker::{
+        from_hex, to_hex, Appearance, StyleSheet,
+    };
+
+    /// A widget for picking a color.
+    pub type ColorPicker<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::ColorPicker<'a, Message, Renderer>;
+}
+
+pub mod combo_box {
+    //! Display a text input that filters a dropdown list of options.
+    pub use iced_native::widget::combo_box::Filter;
+
+    /// A widget for searching and selecting a single value from a list of
+    /// options.
+    pub type ComboBox<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::ComboBox<'a, T, Message, Renderer>;
+}
+
 pub mod container {
     //! Decorate content and apply alignment.
     pub use iced_native::widget::container::{Appearance, StyleSheet};
@@ -47,6 +68,18 @@ pub mod container {
         iced_native::widget::Container<'a, Message, Renderer>;
 }
 
+pub mod date_picker {
+    //! Let your users pick a date, or a range of dates, from a calendar.
+    pub use iced_native::widget::date_picker::{
+        Appearance, Date, DateRange, Grid, StyleSheet, Weekday, MONTH_NAMES,
+        WEEKDAY_NAMES,
+    };
+
+    /// A widget for picking a [`Date`], or a [`DateRange`], from a calendar.
+    pub type DatePicker<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::DatePicker<'a, Message, Renderer>;
+}
+
 pub mod mouse_area {
     //! Intercept mouse events on a widget.
 
@@ -55,6 +88,23 @@ pub mod mouse_area {
         iced_native::widget::MouseArea<'a, Message, Renderer>;
 }
 
+pub mod opacity {
+    //! Fade a widget in or out.
+
+    /// A widget that draws its content with some opacity.
+    pub type Opacity<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Opacity<'a, Message, Renderer>;
+}
+
+pub mod transform {
+    //! Rotate, scale or skew a widget.
+    pub use iced_graphics::widget::transform::Transformation;
+
+    /// A widget that applies a [`Transformation`] to its content.
+    pub type Transform<'a, Message, Renderer = crate::Renderer> =
+        iced_graphics::widget::Transform<'a, Message, Renderer>;
+}
+
 pub mod pane_grid {
     //! Let your users split regions of your application and organize layout dynamically.
     //!
@@ -140,6 +190,17 @@ pub mod text_input {
         iced_native::widget::TextInput<'a, Message, Renderer>;
 }
 
+pub mod time_picker {
+    //! Let your users pick a time of the day.
+    pub use iced_native::widget::time_picker::{
+        Appearance, Grid, StyleSheet, Time,
+    };
+
+    /// A widget for picking a [`Time`] of the day.
+    pub type TimePicker<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::TimePicker<'a, Message, Renderer>;
+}
+
 pub mod tooltip {
     //! Display a widget over another.
     pub use iced_native::widget::tooltip::Position;
@@ -149,6 +210,7 @@ pub mod tooltip {
         iced_native::widget::Tooltip<'a, Message, Renderer>;
 }
 
+pub use iced_native::widget::number_input;
 pub use iced_native::widget::progress_bar;
 pub use iced_native::widget::rule;
 pub use iced_native::widget::slider;
@@ -157,7 +219,12 @@ pub use iced_native::widget::Space;
 
 pub use button::Button;
 pub use checkbox::Checkbox;
+pub use color_picker::ColorPicker;
+pub use combo_box::ComboBox;
 pub use container::Container;
+pub use date_picker::DatePicker;
+pub use number_input::NumberInput;
+pub use opacity::Opacity;
 pub use pane_grid::PaneGrid;
 pub use pick_list::PickList;
 pub use progress_bar::ProgressBar;
@@ -167,8 +234,10 @@ pub use scrollable::Scrollable;
 pub use slider::Slider;
 pub use text::Text;
 pub use text_input::TextInput;
+pub use time_picker::TimePicker;
 pub use toggler::Toggler;
 pub use tooltip::Tooltip;
+pub use transform::Transform;
 pub use vertical_slider::VerticalSlider;
 
 #[cfg(feature = "canvas")]
@@ -185,16 +254,48 @@ where
     Canvas::new(program)
 }
 
+#[cfg(all(not(feature = "glow"), feature = "wgpu"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "wgpu")))]
+pub use iced_wgpu::widget::shader;
+
+#[cfg(all(not(feature = "glow"), feature = "wgpu"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "wgpu")))]
+/// Creates a new [`Shader`].
+pub fn shader<Message, P>(program: P) -> Shader<Message, P>
+where
+    P: shader::Program<Message>,
+{
+    Shader::new(program)
+}
+
+/// Applies a [`Transformation`] to the given widget.
+///
+/// [`Transformation`]: transform::Transformation
+pub fn transform<'a, Message, Renderer>(
+    content: impl Into<crate::Element<'a, Message, Renderer>>,
+    transformation: transform::Transformation,
+) -> Transform<'a, Message, Renderer> {
+    Transform::new(content, transformation)
+}
+
 #[cfg(feature = "image")]
 #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
 pub mod image {
     //! Display images in your user interface.
-    pub use iced_native::image::Handle;
+    pub use iced_graphics::image::raster::{load_animation, preload};
+    pub use iced_native::image::{
+        Animation, Bytes, Data, Error, FilterMethod, Frame, Handle, Id,
+        Statistics, Status,
+    };
 
     /// A frame that displays an image.
-    pub type Image = iced_native::widget::Image<Handle>;
+    pub type Image<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Image<'a, Message, Renderer>;
 
+    pub use animated::Animated;
+    pub use iced_native::widget::image::animated;
     pub use iced_native::widget::image::viewer;
+    pub use iced_native::widget::image::{nine_slice, NineSlice};
     pub use viewer::Viewer;
 }
 
@@ -206,7 +307,7 @@ pub use iced_graphics::widget::qr_code;
 #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
 pub mod svg {
     //! Display vector graphics in your application.
-    pub use iced_native::svg::Handle;
+    pub use iced_native::svg::{Handle, Stylesheet};
     pub use iced_native::widget::svg::{Appearance, StyleSheet, Svg};
 }
 
@@ -218,6 +319,10 @@ pub use canvas::Canvas;
 #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
 pub use image::Image;
 
+#[cfg(all(not(feature = "glow"), feature = "wgpu"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "wgpu")))]
+pub use shader::Shader;
+
 #[cfg(feature = "qr_code")]
 #[cfg_attr(docsrs, doc(cfg(feature = "qr_code")))]
 pub use qr_code::QRCode;
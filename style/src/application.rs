@@ -1,4 +1,6 @@
 //! Change the appearance of an application.
+use crate::theme::Mode;
+
 use iced_core::Color;
 
 /// A set of rules that dictate the style of an application.
@@ -10,10 +12,35 @@ pub trait StyleSheet {
     ///
     /// [`Style`]: Self::Style
     fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Returns whether the [`StyleSheet`] follows the light or dark
+    /// preference of the system.
+    ///
+    /// Runtimes only detect the preference of the system when this is
+    /// `true`.
+    fn follows_system(&self) -> bool {
+        false
+    }
+
+    /// Returns the [`StyleSheet`] to use while the system prefers the given
+    /// [`Mode`], if it [follows the system].
+    ///
+    /// [follows the system]: Self::follows_system
+    fn with_system_mode(&self, _mode: Mode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 /// The appearance of an application.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Appearance {
     /// The background [`Color`] of the application.
     pub background_color: Color,
@@ -1,8 +1,14 @@
 //! Change the apperance of a button.
-use iced_core::{Background, Color, Vector};
+use iced_core::{Background, Color, Shadow, Vector};
 
 /// The appearance of a button.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Appearance {
     /// The amount of offset to apply to the shadow of the button.
     pub shadow_offset: Vector,
@@ -16,6 +22,8 @@ pub struct Appearance {
     pub border_color: Color,
     /// The text [`Color`] of the button.
     pub text_color: Color,
+    /// The [`Shadow`] of the button.
+    pub shadow: Shadow,
 }
 
 impl std::default::Default for Appearance {
@@ -27,6 +35,7 @@ impl std::default::Default for Appearance {
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
             text_color: Color::BLACK,
+            shadow: Shadow::default(),
         }
     }
 }
@@ -53,6 +62,7 @@ pub trait StyleSheet {
     fn pressed(&self, style: &Self::Style) -> Appearance {
         Appearance {
             shadow_offset: Vector::default(),
+            shadow: Shadow::default(),
             ..self.active(style)
         }
     }
@@ -63,6 +73,7 @@ pub trait StyleSheet {
 
         Appearance {
             shadow_offset: Vector::default(),
+            shadow: Shadow::default(),
             background: active.background.map(|background| match background {
                 Background::Color(color) => Background::Color(Color {
                     a: color.a * 0.5,
@@ -0,0 +1,39 @@
+//! Change the appearance of a color picker.
+use iced_core::{Background, Color};
+
+/// The appearance of a color picker.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+pub struct Appearance {
+    /// The text [`Color`] of the labels of the color picker.
+    pub text_color: Color,
+    /// The border radius of the color areas of the color picker.
+    pub border_radius: f32,
+    /// The border width of the color areas of the color picker.
+    pub border_width: f32,
+    /// The border [`Color`] of the color areas of the color picker.
+    pub border_color: Color,
+    /// The [`Color`] of the handles marking the current color.
+    pub handle_color: Color,
+    /// The [`Color`] of the outline of the handles.
+    pub handle_border_color: Color,
+    /// The two colors of the checkerboard drawn behind translucent colors.
+    pub checkerboard: [Color; 2],
+    /// The [`Background`] of the eyedropper button.
+    pub button_background: Background,
+    /// The text [`Color`] of the eyedropper button.
+    pub button_text_color: Color,
+}
+
+/// A set of rules that dictate the style of a color picker.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default + Clone;
+
+    /// Produces the [`Appearance`] of a color picker.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -1,8 +1,14 @@
 //! Change the appearance of a container.
-use iced_core::{Background, Color};
+use iced_core::{Background, Color, Shadow};
 
 /// The appearance of a container.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Appearance {
     /// The text [`Color`] of the container.
     pub text_color: Option<Color>,
@@ -14,6 +20,16 @@ pub struct Appearance {
     pub border_width: f32,
     /// The border [`Color`] of the container.
     pub border_color: Color,
+    /// The [`Shadow`] of the container.
+    pub shadow: Shadow,
+    /// The blur radius of the contents of the container.
+    ///
+    /// Only the `Container` widget applies it.
+    pub blur: f32,
+    /// The blur radius of what is drawn behind the container.
+    ///
+    /// Only the `Container` widget applies it.
+    pub backdrop_blur: f32,
 }
 
 impl std::default::Default for Appearance {
@@ -24,6 +40,9 @@ impl std::default::Default for Appearance {
             border_radius: 0.0,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
+            blur: 0.0,
+            backdrop_blur: 0.0,
         }
     }
 }
@@ -35,4 +54,11 @@ pub trait StyleSheet {
 
     /// Produces the [`Appearance`] of a container.
     fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Returns the [`Style`] of the tooltips that do not set one.
+    ///
+    /// [`Style`]: Self::Style
+    fn tooltip(&self) -> Self::Style {
+        Self::Style::default()
+    }
 }
@@ -0,0 +1,78 @@
+//! Change the appearance of a date picker.
+use iced_core::{Background, Color};
+
+/// The appearance of the field of a date picker.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+pub struct Appearance {
+    /// The text [`Color`] of the date picker.
+    pub text_color: Color,
+    /// The placeholder [`Color`] of the date picker.
+    pub placeholder_color: Color,
+    /// The handle [`Color`] of the date picker.
+    pub handle_color: Color,
+    /// The [`Background`] of the date picker.
+    pub background: Background,
+    /// The border radius of the date picker.
+    pub border_radius: f32,
+    /// The border width of the date picker.
+    pub border_width: f32,
+    /// The border color of the date picker.
+    pub border_color: Color,
+}
+
+/// The appearance of the grid of cells shown when a picker is open.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+pub struct Grid {
+    /// The [`Background`] of the grid.
+    pub background: Background,
+    /// The border radius of the grid.
+    pub border_radius: f32,
+    /// The border width of the grid.
+    pub border_width: f32,
+    /// The border color of the grid.
+    pub border_color: Color,
+    /// The text [`Color`] of the cells and the title.
+    pub text_color: Color,
+    /// The text [`Color`] of the headers and of the cells outside of the
+    /// current page, like the days of the adjacent months.
+    pub muted_color: Color,
+    /// The text [`Color`] of the cells that cannot be selected.
+    pub disabled_color: Color,
+    /// The border radius of a cell.
+    pub cell_border_radius: f32,
+    /// The [`Background`] of a hovered cell.
+    pub hovered_background: Background,
+    /// The text [`Color`] of a selected cell.
+    pub selected_text_color: Color,
+    /// The [`Background`] of a selected cell.
+    pub selected_background: Background,
+    /// The [`Background`] of the cells inside of a selected range.
+    pub range_background: Background,
+    /// The border [`Color`] of the highlighted cell, like today's date.
+    pub highlight_color: Color,
+}
+
+/// A set of rules that dictate the style of a date picker.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default + Clone;
+
+    /// Produces the active [`Appearance`] of a date picker.
+    fn active(&self, style: &<Self as StyleSheet>::Style) -> Appearance;
+
+    /// Produces the hovered [`Appearance`] of a date picker.
+    fn hovered(&self, style: &<Self as StyleSheet>::Style) -> Appearance;
+
+    /// Produces the [`Grid`] of the calendar of a date picker.
+    fn grid(&self, style: &<Self as StyleSheet>::Style) -> Grid;
+}
@@ -23,7 +23,9 @@ pub use iced_core::{Background, Color};
 pub mod application;
 pub mod button;
 pub mod checkbox;
+pub mod color_picker;
 pub mod container;
+pub mod date_picker;
 pub mod menu;
 pub mod pane_grid;
 pub mod pick_list;
@@ -36,6 +38,7 @@ pub mod svg;
 pub mod text;
 pub mod text_input;
 pub mod theme;
+pub mod time_picker;
 pub mod toggler;
 
 pub use theme::Theme;
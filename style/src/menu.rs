@@ -1,8 +1,13 @@
 //! Change the appearance of menus.
-use iced_core::{Background, Color};
+use iced_core::{Background, Color, Shadow};
 
 /// The appearance of a menu.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Appearance {
     /// The text [`Color`] of the menu.
     pub text_color: Color,
@@ -18,6 +23,9 @@ pub struct Appearance {
     pub selected_text_color: Color,
     /// The background [`Color`] of a selected option in the menu.
     pub selected_background: Background,
+    /// The [`Shadow`] of the menu.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub shadow: Shadow,
 }
 
 /// The style sheet of a menu.
@@ -17,6 +17,11 @@ pub trait StyleSheet {
 ///
 /// It is normally used to define the highlight of something, like a split.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Line {
     /// The [`Color`] of the [`Line`].
     pub color: Color,
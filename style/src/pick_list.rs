@@ -2,7 +2,12 @@
 use iced_core::{Background, Color};
 
 /// The appearance of a pick list.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Appearance {
     /// The text [`Color`] of the pick list.
     pub text_color: Color,
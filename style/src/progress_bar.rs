@@ -2,7 +2,12 @@
 use iced_core::Background;
 
 /// The appearance of a progress bar.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Appearance {
     /// The [`Background`] of the progress bar.
     pub background: Background,
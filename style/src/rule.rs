@@ -3,6 +3,11 @@ use iced_core::Color;
 
 /// The appearance of a rule.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Appearance {
     /// The color of the rule.
     pub color: Color,
@@ -25,6 +30,11 @@ pub trait StyleSheet {
 
 /// The fill mode of a rule.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum FillMode {
     /// Fill the whole length of the container.
     Full,
@@ -3,6 +3,11 @@ use iced_core::{Background, Color};
 
 /// The appearance of a scrollable.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Scrollbar {
     /// The [`Background`] of a scrollable.
     pub background: Option<Background>,
@@ -18,6 +23,11 @@ pub struct Scrollbar {
 
 /// The appearance of the scroller of a scrollable.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Scroller {
     /// The [`Color`] of the scroller.
     pub color: Color,
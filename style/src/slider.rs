@@ -3,6 +3,11 @@ use iced_core::Color;
 
 /// The appearance of a slider.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Appearance {
     /// The colors of the rail of the slider.
     pub rail: Rail,
@@ -12,6 +17,11 @@ pub struct Appearance {
 
 /// The appearance of a slider rail
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Rail {
     /// The colors of the rail of the slider.
     pub colors: (Color, Color),
@@ -21,6 +31,11 @@ pub struct Rail {
 
 /// The appearance of the handle of a slider.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Handle {
     /// The shape of the handle.
     pub shape: HandleShape,
@@ -34,6 +49,11 @@ pub struct Handle {
 
 /// The shape of the handle of a slider.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum HandleShape {
     /// A circular handle.
     Circle {
@@ -4,6 +4,11 @@ use iced_core::Color;
 
 /// The appearance of an SVG.
 #[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Appearance {
     /// The [`Color`] filter of an SVG.
     ///
@@ -12,6 +12,11 @@ pub trait StyleSheet {
 
 /// The apperance of some text.
 #[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Appearance {
     /// The [`Color`] of the text.
     ///
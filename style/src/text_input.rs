@@ -2,7 +2,12 @@
 use iced_core::{Background, Color};
 
 /// The appearance of a text input.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Appearance {
     /// The [`Background`] of the text input.
     pub background: Background,
@@ -46,4 +51,9 @@ pub trait StyleSheet {
 
     /// Produces the style of a disabled text input.
     fn disabled(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a text input whose value is invalid.
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        self.focused(style)
+    }
 }
@@ -1,13 +1,23 @@
 //! Use the built-in theme and styles.
+pub mod overrides;
 pub mod palette;
 
+#[cfg(feature = "serde")]
+mod definition;
+
+pub use self::overrides::Overrides;
 use self::palette::Extended;
 pub use self::palette::Palette;
 
+#[cfg(feature = "serde")]
+pub use self::definition::{Definition, Error, Format};
+
 use crate::application;
 use crate::button;
 use crate::checkbox;
+use crate::color_picker;
 use crate::container;
+use crate::date_picker;
 use crate::menu;
 use crate::pane_grid;
 use crate::pick_list;
@@ -19,9 +29,10 @@ use crate::slider;
 use crate::svg;
 use crate::text;
 use crate::text_input;
+use crate::time_picker;
 use crate::toggler;
 
-use iced_core::{Background, Color, Vector};
+use iced_core::{Background, Color, Shadow, Vector};
 
 use std::rc::Rc;
 
@@ -33,6 +44,13 @@ pub enum Theme {
     Light,
     /// The built-in dark variant.
     Dark,
+    /// The built-in variant that follows the [`Mode`] preferred by the
+    /// system.
+    ///
+    /// Runtimes detect the preference of the system for applications using
+    /// this variant, and draw them with [`Theme::Light`] or [`Theme::Dark`]
+    /// accordingly. Anywhere else, it looks like [`Theme::Light`].
+    System,
     /// A [`Theme`] that uses a [`Custom`] palette.
     Custom(Box<Custom>),
 }
@@ -46,7 +64,7 @@ impl Theme {
     /// Returns the [`Palette`] of the [`Theme`].
     pub fn palette(&self) -> Palette {
         match self {
-            Self::Light => Palette::LIGHT,
+            Self::Light | Self::System => Palette::LIGHT,
             Self::Dark => Palette::DARK,
             Self::Custom(custom) => custom.palette,
         }
@@ -55,11 +73,29 @@ impl Theme {
     /// Returns the [`palette::Extended`] of the [`Theme`].
     pub fn extended_palette(&self) -> &palette::Extended {
         match self {
-            Self::Light => &palette::EXTENDED_LIGHT,
+            Self::Light | Self::System => &palette::EXTENDED_LIGHT,
             Self::Dark => &palette::EXTENDED_DARK,
             Self::Custom(custom) => &custom.extended,
         }
     }
+
+    /// Returns the [`Overrides`] of the [`Theme`].
+    pub fn overrides(&self) -> &Overrides {
+        match self {
+            Self::Light | Self::Dark | Self::System => &Overrides::NONE,
+            Self::Custom(custom) => &custom.overrides,
+        }
+    }
+}
+
+/// The light or dark preference of a user interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Mode {
+    /// A light background with dark text.
+    #[default]
+    Light,
+    /// A dark background with light text.
+    Dark,
 }
 
 /// A [`Theme`] with a customized [`Palette`].
@@ -67,6 +103,7 @@ impl Theme {
 pub struct Custom {
     palette: Palette,
     extended: Extended,
+    overrides: Overrides,
 }
 
 impl Custom {
@@ -75,8 +112,22 @@ impl Custom {
         Self {
             palette,
             extended: Extended::generate(palette),
+            overrides: Overrides::NONE,
         }
     }
+
+    /// Sets the [`palette::Extended`] of the [`Custom`] theme, instead of
+    /// generating it from its [`Palette`].
+    pub fn extended(mut self, extended: Extended) -> Self {
+        self.extended = extended;
+        self
+    }
+
+    /// Sets the [`Overrides`] of the [`Custom`] theme.
+    pub fn overrides(mut self, overrides: Overrides) -> Self {
+        self.overrides = overrides;
+        self
+    }
 }
 
 /// The style of an application.
@@ -93,6 +144,12 @@ impl application::StyleSheet for Theme {
     type Style = Application;
 
     fn appearance(&self, style: &Self::Style) -> application::Appearance {
+        if let (Application::Default, Some(appearance)) =
+            (style, self.overrides().application)
+        {
+            return appearance;
+        }
+
         let palette = self.extended_palette();
 
         match style {
@@ -103,6 +160,17 @@ impl application::StyleSheet for Theme {
             Application::Custom(custom) => custom.appearance(self),
         }
     }
+
+    fn follows_system(&self) -> bool {
+        matches!(self, Self::System)
+    }
+
+    fn with_system_mode(&self, mode: Mode) -> Option<Self> {
+        self.follows_system().then_some(match mode {
+            Mode::Light => Self::Light,
+            Mode::Dark => Self::Dark,
+        })
+    }
 }
 
 impl application::StyleSheet for fn(&Theme) -> application::Appearance {
@@ -143,6 +211,12 @@ impl button::StyleSheet for Theme {
     type Style = Button;
 
     fn active(&self, style: &Self::Style) -> button::Appearance {
+        if let (Button::Primary, Some(appearance)) =
+            (style, self.overrides().button.active)
+        {
+            return appearance;
+        }
+
         let palette = self.extended_palette();
 
         let appearance = button::Appearance {
@@ -176,6 +250,12 @@ impl button::StyleSheet for Theme {
             return custom.hovered(self);
         }
 
+        if let (Button::Primary, Some(appearance)) =
+            (style, self.overrides().button.hovered)
+        {
+            return appearance;
+        }
+
         let active = self.active(style);
 
         let background = match style {
@@ -197,8 +277,15 @@ impl button::StyleSheet for Theme {
             return custom.pressed(self);
         }
 
+        if let (Button::Primary, Some(appearance)) =
+            (style, self.overrides().button.pressed)
+        {
+            return appearance;
+        }
+
         button::Appearance {
             shadow_offset: Vector::default(),
+            shadow: Shadow::default(),
             ..self.active(style)
         }
     }
@@ -208,10 +295,17 @@ impl button::StyleSheet for Theme {
             return custom.disabled(self);
         }
 
+        if let (Button::Primary, Some(appearance)) =
+            (style, self.overrides().button.disabled)
+        {
+            return appearance;
+        }
+
         let active = self.active(style);
 
         button::Appearance {
             shadow_offset: Vector::default(),
+            shadow: Shadow::default(),
             background: active.background.map(|background| match background {
                 Background::Color(color) => Background::Color(Color {
                     a: color.a * 0.5,
@@ -339,6 +433,47 @@ fn checkbox_appearance(
     }
 }
 
+/// The style of a color picker.
+#[derive(Clone, Default)]
+pub enum ColorPicker {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Rc<dyn color_picker::StyleSheet<Style = Theme>>),
+}
+
+impl color_picker::StyleSheet for Theme {
+    type Style = ColorPicker;
+
+    fn appearance(&self, style: &Self::Style) -> color_picker::Appearance {
+        match style {
+            ColorPicker::Default => {
+                let palette = self.extended_palette();
+
+                color_picker::Appearance {
+                    text_color: palette.background.base.text,
+                    border_radius: 2.0,
+                    border_width: 1.0,
+                    border_color: palette.background.strong.color,
+                    handle_color: Color::WHITE,
+                    handle_border_color: Color {
+                        a: 0.6,
+                        ..Color::BLACK
+                    },
+                    checkerboard: [
+                        Color::WHITE,
+                        Color::from_rgb(0.8, 0.8, 0.8),
+                    ],
+                    button_background: palette.background.weak.color.into(),
+                    button_text_color: palette.background.weak.text,
+                }
+            }
+            ColorPicker::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
 /// The style of a container.
 #[derive(Default)]
 pub enum Container {
@@ -347,6 +482,8 @@ pub enum Container {
     Transparent,
     /// A simple box.
     Box,
+    /// A box that floats over other content, like a tooltip.
+    Tooltip,
     /// A custom style.
     Custom(Box<dyn container::StyleSheet<Style = Theme>>),
 }
@@ -362,7 +499,9 @@ impl container::StyleSheet for Theme {
 
     fn appearance(&self, style: &Self::Style) -> container::Appearance {
         match style {
-            Container::Transparent => Default::default(),
+            Container::Transparent => {
+                self.overrides().container.unwrap_or_default()
+            }
             Container::Box => {
                 let palette = self.extended_palette();
 
@@ -372,11 +511,36 @@ impl container::StyleSheet for Theme {
                     border_radius: 2.0,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
+                    blur: 0.0,
+                    backdrop_blur: 0.0,
+                }
+            }
+            Container::Tooltip => {
+                let palette = self.extended_palette();
+
+                container::Appearance {
+                    text_color: Some(palette.background.base.text),
+                    background: palette.background.base.color.into(),
+                    border_radius: 4.0,
+                    border_width: 1.0,
+                    border_color: palette.background.strong.color,
+                    shadow: Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.25),
+                        offset: Vector::new(0.0, 2.0),
+                        blur_radius: 8.0,
+                    },
+                    blur: 0.0,
+                    backdrop_blur: 0.0,
                 }
             }
             Container::Custom(custom) => custom.appearance(self),
         }
     }
+
+    fn tooltip(&self) -> Self::Style {
+        Container::Tooltip
+    }
 }
 
 impl container::StyleSheet for fn(&Theme) -> container::Appearance {
@@ -485,6 +649,12 @@ impl menu::StyleSheet for Theme {
     type Style = Menu;
 
     fn appearance(&self, style: &Self::Style) -> menu::Appearance {
+        if let (Menu::Default, Some(appearance)) =
+            (style, self.overrides().menu)
+        {
+            return appearance;
+        }
+
         match style {
             Menu::Default => {
                 let palette = self.extended_palette();
@@ -497,6 +667,7 @@ impl menu::StyleSheet for Theme {
                     border_color: palette.background.strong.color,
                     selected_text_color: palette.primary.strong.text,
                     selected_background: palette.primary.strong.color.into(),
+                    shadow: Shadow::default(),
                 }
             }
             Menu::Custom(custom) => custom.appearance(self),
@@ -530,6 +701,12 @@ impl pick_list::StyleSheet for Theme {
     type Style = PickList;
 
     fn active(&self, style: &Self::Style) -> pick_list::Appearance {
+        if let (PickList::Default, Some(appearance)) =
+            (style, self.overrides().pick_list.active)
+        {
+            return appearance;
+        }
+
         match style {
             PickList::Default => {
                 let palette = self.extended_palette();
@@ -549,6 +726,12 @@ impl pick_list::StyleSheet for Theme {
     }
 
     fn hovered(&self, style: &Self::Style) -> pick_list::Appearance {
+        if let (PickList::Default, Some(appearance)) =
+            (style, self.overrides().pick_list.hovered)
+        {
+            return appearance;
+        }
+
         match style {
             PickList::Default => {
                 let palette = self.extended_palette();
@@ -568,6 +751,124 @@ impl pick_list::StyleSheet for Theme {
     }
 }
 
+/// The style of a date picker.
+#[derive(Clone, Default)]
+pub enum DatePicker {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Rc<dyn date_picker::StyleSheet<Style = Theme>>),
+}
+
+impl date_picker::StyleSheet for Theme {
+    type Style = DatePicker;
+
+    fn active(&self, style: &Self::Style) -> date_picker::Appearance {
+        match style {
+            DatePicker::Default => picker_appearance(
+                self.extended_palette(),
+                self.extended_palette().background.strong.color,
+            ),
+            DatePicker::Custom(custom) => custom.active(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> date_picker::Appearance {
+        match style {
+            DatePicker::Default => picker_appearance(
+                self.extended_palette(),
+                self.extended_palette().primary.strong.color,
+            ),
+            DatePicker::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn grid(&self, style: &Self::Style) -> date_picker::Grid {
+        match style {
+            DatePicker::Default => picker_grid(self.extended_palette()),
+            DatePicker::Custom(custom) => custom.grid(self),
+        }
+    }
+}
+
+/// The style of a time picker.
+#[derive(Clone, Default)]
+pub enum TimePicker {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Rc<dyn time_picker::StyleSheet<Style = Theme>>),
+}
+
+impl time_picker::StyleSheet for Theme {
+    type Style = TimePicker;
+
+    fn active(&self, style: &Self::Style) -> time_picker::Appearance {
+        match style {
+            TimePicker::Default => picker_appearance(
+                self.extended_palette(),
+                self.extended_palette().background.strong.color,
+            ),
+            TimePicker::Custom(custom) => custom.active(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> time_picker::Appearance {
+        match style {
+            TimePicker::Default => picker_appearance(
+                self.extended_palette(),
+                self.extended_palette().primary.strong.color,
+            ),
+            TimePicker::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn grid(&self, style: &Self::Style) -> time_picker::Grid {
+        match style {
+            TimePicker::Default => picker_grid(self.extended_palette()),
+            TimePicker::Custom(custom) => custom.grid(self),
+        }
+    }
+}
+
+fn picker_appearance(
+    palette: &Extended,
+    border_color: Color,
+) -> date_picker::Appearance {
+    date_picker::Appearance {
+        text_color: palette.background.weak.text,
+        background: palette.background.weak.color.into(),
+        placeholder_color: palette.background.strong.color,
+        handle_color: palette.background.weak.text,
+        border_radius: 2.0,
+        border_width: 1.0,
+        border_color,
+    }
+}
+
+fn picker_grid(palette: &Extended) -> date_picker::Grid {
+    date_picker::Grid {
+        background: palette.background.base.color.into(),
+        border_radius: 2.0,
+        border_width: 1.0,
+        border_color: palette.background.strong.color,
+        text_color: palette.background.base.text,
+        muted_color: palette.background.strong.color,
+        disabled_color: Color {
+            a: 0.3,
+            ..palette.background.base.text
+        },
+        cell_border_radius: 2.0,
+        hovered_background: palette.background.weak.color.into(),
+        selected_text_color: palette.primary.strong.text,
+        selected_background: palette.primary.strong.color.into(),
+        range_background: palette.primary.weak.color.into(),
+        highlight_color: palette.primary.base.color,
+    }
+}
+
 /// The style of a radio button.
 #[derive(Default)]
 pub enum Radio {
@@ -760,6 +1061,12 @@ impl progress_bar::StyleSheet for Theme {
             return custom.appearance(self);
         }
 
+        if let (ProgressBar::Primary, Some(appearance)) =
+            (style, self.overrides().progress_bar)
+        {
+            return appearance;
+        }
+
         let palette = self.extended_palette();
 
         let from_palette = |bar: Color| progress_bar::Appearance {
@@ -1024,6 +1331,10 @@ impl text_input::StyleSheet for Theme {
             return custom.active(self);
         }
 
+        if let Some(appearance) = self.overrides().text_input.active {
+            return appearance;
+        }
+
         let palette = self.extended_palette();
 
         text_input::Appearance {
@@ -1040,6 +1351,10 @@ impl text_input::StyleSheet for Theme {
             return custom.hovered(self);
         }
 
+        if let Some(appearance) = self.overrides().text_input.hovered {
+            return appearance;
+        }
+
         let palette = self.extended_palette();
 
         text_input::Appearance {
@@ -1056,6 +1371,10 @@ impl text_input::StyleSheet for Theme {
             return custom.focused(self);
         }
 
+        if let Some(appearance) = self.overrides().text_input.focused {
+            return appearance;
+        }
+
         let palette = self.extended_palette();
 
         text_input::Appearance {
@@ -1102,6 +1421,10 @@ impl text_input::StyleSheet for Theme {
             return custom.disabled(self);
         }
 
+        if let Some(appearance) = self.overrides().text_input.disabled {
+            return appearance;
+        }
+
         let palette = self.extended_palette();
 
         text_input::Appearance {
@@ -1120,4 +1443,24 @@ impl text_input::StyleSheet for Theme {
 
         self.placeholder_color(style)
     }
+
+    fn invalid(&self, style: &Self::Style) -> text_input::Appearance {
+        if let TextInput::Custom(custom) = style {
+            return custom.invalid(self);
+        }
+
+        if let Some(appearance) = self.overrides().text_input.invalid {
+            return appearance;
+        }
+
+        let palette = self.extended_palette();
+
+        text_input::Appearance {
+            background: palette.background.base.color.into(),
+            border_radius: 2.0,
+            border_width: 1.0,
+            border_color: palette.danger.base.color,
+            icon_color: palette.background.weak.text,
+        }
+    }
 }
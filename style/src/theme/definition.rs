@@ -0,0 +1,362 @@
+use crate::theme::palette::Extended;
+use crate::theme::{Custom, Overrides, Palette, Theme};
+
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// A serializable description of a [`Theme`].
+///
+/// A [`Definition`] can be written by hand as a TOML or JSON file:
+///
+/// ```toml
+/// [palette]
+/// background = "#1e1e2e"
+/// text = "#cdd6f4"
+/// primary = "#89b4fa"
+/// success = "#a6e3a1"
+/// danger = "#f38ba8"
+///
+/// [overrides.button.active]
+/// background = "#89b4fa"
+/// text_color = "#1e1e2e"
+/// border_radius = 6.0
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(crate = "serde")]
+pub struct Definition {
+    /// The [`Palette`] of the theme.
+    pub palette: Palette,
+
+    /// The [`Extended`] palette of the theme.
+    ///
+    /// If missing, it is generated from the [`Palette`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extended: Option<Extended>,
+
+    /// The per-widget [`Overrides`] of the theme.
+    #[serde(default)]
+    pub overrides: Overrides,
+}
+
+impl Definition {
+    /// Creates a new [`Definition`] with the given [`Palette`].
+    pub fn new(palette: Palette) -> Self {
+        Self {
+            palette,
+            extended: None,
+            overrides: Overrides::NONE,
+        }
+    }
+
+    /// Parses a [`Definition`] from the given contents in the given
+    /// [`Format`].
+    pub fn parse(contents: &str, format: Format) -> Result<Self, Error> {
+        match format {
+            #[cfg(feature = "toml")]
+            Format::Toml => {
+                toml::from_str(contents).map_err(|error| Error::Parse {
+                    format,
+                    message: error.to_string(),
+                })
+            }
+            #[cfg(feature = "json")]
+            Format::Json => {
+                serde_json::from_str(contents).map_err(|error| Error::Parse {
+                    format,
+                    message: error.to_string(),
+                })
+            }
+            #[allow(unreachable_patterns)]
+            _ => {
+                let _ = contents;
+
+                Err(Error::UnsupportedFormat(Some(format)))
+            }
+        }
+    }
+
+    /// Serializes the [`Definition`] in the given [`Format`].
+    pub fn serialize(&self, format: Format) -> Result<String, Error> {
+        match format {
+            #[cfg(feature = "toml")]
+            Format::Toml => toml::to_string_pretty(self)
+                .map_err(|error| Error::Serialize(error.to_string())),
+            #[cfg(feature = "json")]
+            Format::Json => serde_json::to_string_pretty(self)
+                .map_err(|error| Error::Serialize(error.to_string())),
+            #[allow(unreachable_patterns)]
+            _ => Err(Error::UnsupportedFormat(Some(format))),
+        }
+    }
+
+    /// Loads a [`Definition`] from the file at the given path.
+    ///
+    /// The [`Format`] of the file is guessed from its extension.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let format =
+            Format::from_path(path).ok_or(Error::UnsupportedFormat(None))?;
+
+        let contents = std::fs::read_to_string(path)
+            .map_err(|error| Error::Io(Arc::new(error)))?;
+
+        Self::parse(&contents, format)
+    }
+}
+
+impl From<&Theme> for Definition {
+    fn from(theme: &Theme) -> Self {
+        Self {
+            palette: theme.palette(),
+            extended: None,
+            overrides: *theme.overrides(),
+        }
+    }
+}
+
+impl From<Definition> for Theme {
+    fn from(definition: Definition) -> Self {
+        let custom = Custom::new(definition.palette);
+
+        let custom = match definition.extended {
+            Some(extended) => custom.extended(extended),
+            None => custom,
+        };
+
+        Theme::Custom(Box::new(custom.overrides(definition.overrides)))
+    }
+}
+
+/// The file format of a [`Definition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// TOML. Needs the `toml` feature.
+    Toml,
+    /// JSON. Needs the `json` feature.
+    Json,
+}
+
+impl Format {
+    /// Guesses the [`Format`] of the file at the given path from its
+    /// extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?;
+
+        if extension.eq_ignore_ascii_case("toml") {
+            Some(Self::Toml)
+        } else if extension.eq_ignore_ascii_case("json") {
+            Some(Self::Json)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Toml => write!(f, "TOML"),
+            Self::Json => write!(f, "JSON"),
+        }
+    }
+}
+
+/// An error that occurred while loading a [`Definition`].
+#[derive(Debug, Clone)]
+pub enum Error {
+    /// The file could not be read.
+    Io(Arc<io::Error>),
+    /// The contents are not a valid [`Definition`].
+    Parse {
+        /// The [`Format`] of the contents.
+        format: Format,
+        /// A description of the problem.
+        message: String,
+    },
+    /// The [`Definition`] could not be serialized.
+    Serialize(String),
+    /// The [`Format`] is unknown or its feature is disabled.
+    UnsupportedFormat(Option<Format>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "theme could not be read: {error}"),
+            Self::Parse { format, message } => {
+                write!(f, "invalid {format} theme: {message}")
+            }
+            Self::Serialize(message) => {
+                write!(f, "theme could not be serialized: {message}")
+            }
+            Self::UnsupportedFormat(Some(format)) => {
+                write!(f, "{format} themes are not supported")
+            }
+            Self::UnsupportedFormat(None) => {
+                write!(f, "unknown theme format")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error.as_ref()),
+            Self::Parse { .. }
+            | Self::Serialize(_)
+            | Self::UnsupportedFormat(_) => None,
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "toml", feature = "json")))]
+mod tests {
+    use super::*;
+    use crate::button;
+    use crate::{Background, Color};
+
+    fn definition() -> Definition {
+        let mut definition = Definition::new(Palette::LIGHT);
+
+        definition.overrides.button.active = Some(button::Appearance {
+            background: Some(Background::Color(Palette::LIGHT.primary)),
+            border_radius: 6.0,
+            ..button::Appearance::default()
+        });
+
+        definition
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_with_overrides() {
+        let definition = Definition::parse(
+            r##"
+            [palette]
+            background = "#1e1e2e"
+            text = "#cdd6f4"
+            primary = "#89b4fa"
+            success = "#a6e3a1"
+            danger = "#f38ba8"
+
+            [overrides.button.active]
+            text_color = "#1e1e2e"
+            border_radius = 6.0
+            "##,
+            Format::Toml,
+        )
+        .unwrap();
+
+        let theme = Theme::from(definition.clone());
+        let active = theme.overrides().button.active.unwrap();
+
+        assert_eq!(active.border_radius, 6.0);
+        assert_eq!(active.background, None);
+        assert_eq!(theme.palette(), definition.palette);
+
+        let serialized = definition.serialize(Format::Toml).unwrap();
+
+        assert_eq!(
+            Definition::parse(&serialized, Format::Toml).unwrap(),
+            definition
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_round_trips() {
+        let definition = definition();
+        let serialized = definition.serialize(Format::Toml).unwrap();
+
+        assert_eq!(
+            Definition::parse(&serialized, Format::Toml).unwrap(),
+            definition
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_round_trips() {
+        let definition = definition();
+        let serialized = definition.serialize(Format::Json).unwrap();
+
+        assert_eq!(
+            Definition::parse(&serialized, Format::Json).unwrap(),
+            definition
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_accepts_colors_as_hex_or_channels() {
+        let definition = Definition::parse(
+            r##"{
+                "palette": {
+                    "background": "#000000",
+                    "text": { "r": 1.0, "g": 1.0, "b": 1.0 },
+                    "primary": "#00f",
+                    "success": "#0f0",
+                    "danger": "#f00"
+                }
+            }"##,
+            Format::Json,
+        )
+        .unwrap();
+
+        assert_eq!(definition.palette.text, Color::WHITE);
+        assert_eq!(definition.palette.primary, Color::from_rgb8(0, 0, 255));
+        assert_eq!(definition.extended, None);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn malformed_toml_is_a_parse_error() {
+        for contents in [
+            "[palette",
+            "[palette]\nbackground = \"#000\"",
+            "[palette]\nbackground = \"#nothex\"\ntext = \"#fff\"\n\
+            primary = \"#fff\"\nsuccess = \"#fff\"\ndanger = \"#fff\"",
+        ] {
+            assert!(matches!(
+                Definition::parse(contents, Format::Toml),
+                Err(Error::Parse {
+                    format: Format::Toml,
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn malformed_json_is_a_parse_error() {
+        for contents in [
+            "{",
+            r##"{ "palette": { "background": "#000" } }"##,
+            r#"{ "palette": [] }"#,
+        ] {
+            assert!(matches!(
+                Definition::parse(contents, Format::Json),
+                Err(Error::Parse {
+                    format: Format::Json,
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn unknown_extensions_are_unsupported() {
+        assert_eq!(Format::from_path("theme.TOML"), Some(Format::Toml));
+        assert_eq!(Format::from_path("theme.json"), Some(Format::Json));
+        assert_eq!(Format::from_path("theme.yaml"), None);
+
+        assert!(matches!(
+            Definition::load("theme.yaml"),
+            Err(Error::UnsupportedFormat(None))
+        ));
+    }
+}
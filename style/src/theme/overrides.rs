@@ -0,0 +1,124 @@
+//! Replace the default appearance of some widgets in a [`Theme`].
+//!
+//! [`Theme`]: super::Theme
+use crate::application;
+use crate::button;
+use crate::container;
+use crate::menu;
+use crate::pick_list;
+use crate::progress_bar;
+use crate::text_input;
+
+/// A set of appearances that replace the ones a [`Theme`] would produce
+/// for the default style of a widget.
+///
+/// Any missing appearance falls back to the one generated from the
+/// [`Palette`] of the [`Theme`].
+///
+/// [`Theme`]: super::Theme
+/// [`Palette`]: super::Palette
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Overrides {
+    /// The appearance of the application.
+    pub application: Option<application::Appearance>,
+    /// The appearances of a primary button.
+    pub button: Button,
+    /// The appearance of a transparent container.
+    pub container: Option<container::Appearance>,
+    /// The appearance of a menu.
+    pub menu: Option<menu::Appearance>,
+    /// The appearances of a pick list.
+    pub pick_list: PickList,
+    /// The appearance of a progress bar.
+    pub progress_bar: Option<progress_bar::Appearance>,
+    /// The appearances of a text input.
+    pub text_input: TextInput,
+}
+
+impl Overrides {
+    /// No overrides at all.
+    pub const NONE: Self = Self {
+        application: None,
+        button: Button {
+            active: None,
+            hovered: None,
+            pressed: None,
+            disabled: None,
+        },
+        container: None,
+        menu: None,
+        pick_list: PickList {
+            active: None,
+            hovered: None,
+        },
+        progress_bar: None,
+        text_input: TextInput {
+            active: None,
+            focused: None,
+            hovered: None,
+            disabled: None,
+            invalid: None,
+        },
+    };
+}
+
+/// The overrides of a button.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Button {
+    /// The active appearance.
+    pub active: Option<button::Appearance>,
+    /// The hovered appearance.
+    pub hovered: Option<button::Appearance>,
+    /// The pressed appearance.
+    pub pressed: Option<button::Appearance>,
+    /// The disabled appearance.
+    pub disabled: Option<button::Appearance>,
+}
+
+/// The overrides of a pick list.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct PickList {
+    /// The active appearance.
+    pub active: Option<pick_list::Appearance>,
+    /// The hovered appearance.
+    pub hovered: Option<pick_list::Appearance>,
+}
+
+/// The overrides of a text input.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TextInput {
+    /// The active appearance.
+    pub active: Option<text_input::Appearance>,
+    /// The focused appearance.
+    pub focused: Option<text_input::Appearance>,
+    /// The hovered appearance.
+    pub hovered: Option<text_input::Appearance>,
+    /// The disabled appearance.
+    pub disabled: Option<text_input::Appearance>,
+    /// The invalid appearance.
+    pub invalid: Option<text_input::Appearance>,
+}
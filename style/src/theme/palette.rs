@@ -6,6 +6,11 @@ use palette::{FromColor, Hsl, Mix, RelativeContrast, Srgb};
 
 /// A color palette.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Palette {
     /// The background [`Color`] of the [`Palette`].
     pub background: Color,
@@ -69,6 +74,11 @@ impl Palette {
 
 /// An extended set of colors generated from a [`Palette`].
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Extended {
     /// The set of background colors.
     pub background: Background,
@@ -117,6 +127,11 @@ impl Extended {
 
 /// A pair of background and text colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Pair {
     /// The background color.
     pub color: Color,
@@ -141,6 +156,11 @@ impl Pair {
 
 /// A set of background colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Background {
     /// The base background color.
     pub base: Pair,
@@ -166,6 +186,11 @@ impl Background {
 
 /// A set of primary colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Primary {
     /// The base primary color.
     pub base: Pair,
@@ -191,6 +216,11 @@ impl Primary {
 
 /// A set of secondary colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Secondary {
     /// The base secondary color.
     pub base: Pair,
@@ -217,6 +247,11 @@ impl Secondary {
 
 /// A set of success colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Success {
     /// The base success color.
     pub base: Pair,
@@ -242,6 +277,11 @@ impl Success {
 
 /// A set of danger colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Danger {
     /// The base danger color.
     pub base: Pair,
@@ -0,0 +1,17 @@
+//! Change the appearance of a time picker.
+pub use crate::date_picker::{Appearance, Grid};
+
+/// A set of rules that dictate the style of a time picker.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default + Clone;
+
+    /// Produces the active [`Appearance`] of a time picker.
+    fn active(&self, style: &<Self as StyleSheet>::Style) -> Appearance;
+
+    /// Produces the hovered [`Appearance`] of a time picker.
+    fn hovered(&self, style: &<Self as StyleSheet>::Style) -> Appearance;
+
+    /// Produces the [`Grid`] of the hours and minutes of a time picker.
+    fn grid(&self, style: &<Self as StyleSheet>::Style) -> Grid;
+}
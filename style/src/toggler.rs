@@ -3,6 +3,11 @@ use iced_core::Color;
 
 /// The appearance of a toggler.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Appearance {
     /// The background [`Color`] of the toggler.
     pub background: Color,
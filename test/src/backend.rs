@@ -0,0 +1,216 @@
+use iced_graphics::backend;
+use iced_graphics::font;
+use iced_native::text::Hit;
+use iced_native::{Font, Point, Rectangle, Size};
+
+use glyph_brush::ab_glyph;
+use glyph_brush::GlyphCruncher;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// A headless backend that measures text like the graphics backends do, but
+/// never draws anything.
+///
+/// Text is measured with the built-in fallback font, unless another default
+/// font is provided.
+#[derive(Debug)]
+pub struct Backend {
+    measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    font_map: RefCell<HashMap<String, glyph_brush::FontId>>,
+    default_text_size: f32,
+}
+
+impl Backend {
+    /// Creates a new [`Backend`] using the built-in fallback font.
+    pub fn new() -> Self {
+        Self::with_default_font(font::FALLBACK)
+    }
+
+    /// Creates a new [`Backend`] using the given default font.
+    pub fn with_default_font(default_font: &[u8]) -> Self {
+        let font = ab_glyph::FontArc::try_from_vec(default_font.to_vec())
+            .unwrap_or_else(|_| {
+                ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+                    .expect("Load fallback font")
+            });
+
+        Self {
+            measure_brush: RefCell::new(
+                glyph_brush::GlyphBrushBuilder::using_font(font).build(),
+            ),
+            font_map: RefCell::new(HashMap::new()),
+            default_text_size: 20.0,
+        }
+    }
+
+    /// Sets the default text size of the [`Backend`].
+    ///
+    /// Defaults to 20, like the graphics backends.
+    pub fn default_text_size(mut self, size: f32) -> Self {
+        self.default_text_size = size;
+        self
+    }
+
+    fn find_font(&self, font: Font) -> glyph_brush::FontId {
+        match font {
+            Font::Default => glyph_brush::FontId(0),
+            Font::External { name, bytes } => {
+                if let Some(font_id) = self.font_map.borrow().get(name) {
+                    return *font_id;
+                }
+
+                let font = ab_glyph::FontArc::try_from_slice(bytes)
+                    .expect("Load font");
+
+                let font_id = self.measure_brush.borrow_mut().add_font(font);
+
+                let _ = self
+                    .font_map
+                    .borrow_mut()
+                    .insert(String::from(name), font_id);
+
+                font_id
+            }
+        }
+    }
+
+    fn section<'a>(
+        &self,
+        content: &'a str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> glyph_brush::Section<'a> {
+        glyph_brush::Section {
+            bounds: (bounds.width, bounds.height),
+            text: vec![glyph_brush::Text {
+                text: content,
+                scale: size.into(),
+                font_id: self.find_font(font),
+                extra: glyph_brush::Extra::default(),
+            }],
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl iced_graphics::Backend for Backend {
+    fn trim_measurements(&mut self) {
+        let measure_brush = self.measure_brush.get_mut();
+
+        loop {
+            match measure_brush.process_queued(|_, _| {}, |_| {}) {
+                Ok(_) => break,
+                Err(glyph_brush::BrushError::TextureTooSmall { suggested }) => {
+                    let (width, height) = suggested;
+
+                    measure_brush.resize_texture(width, height);
+                }
+            }
+        }
+    }
+}
+
+impl backend::Text for Backend {
+    const ICON_FONT: Font = font::ICONS;
+    const CHECKMARK_ICON: char = font::CHECKMARK_ICON;
+    const ARROW_DOWN_ICON: char = font::ARROW_DOWN_ICON;
+
+    fn default_size(&self) -> f32 {
+        self.default_text_size
+    }
+
+    fn measure(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> (f32, f32) {
+        let section = self.section(contents, size, font, bounds);
+
+        if let Some(bounds) =
+            self.measure_brush.borrow_mut().glyph_bounds(section)
+        {
+            (bounds.width().ceil(), bounds.height().ceil())
+        } else {
+            (0.0, 0.0)
+        }
+    }
+
+    fn hit_test(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        point: Point,
+        nearest_only: bool,
+    ) -> Option<Hit> {
+        use ab_glyph::{Font as _, ScaleFont};
+
+        let section = self.section(contents, size, font, bounds);
+        let font_id = section.text[0].font_id;
+
+        let mut measure_brush = self.measure_brush.borrow_mut();
+
+        // The underlying type is FontArc, so clones are cheap.
+        let font = measure_brush.fonts()[font_id.0].clone().into_scaled(size);
+
+        let bounds = measure_brush.glyphs(section).map(
+            |glyph_brush::SectionGlyph {
+                 byte_index, glyph, ..
+             }| {
+                (
+                    *byte_index,
+                    Rectangle::new(
+                        Point::new(
+                            glyph.position.x - font.h_side_bearing(glyph.id),
+                            glyph.position.y - font.ascent(),
+                        ),
+                        Size::new(
+                            font.h_advance(glyph.id),
+                            font.ascent() - font.descent(),
+                        ),
+                    ),
+                )
+            },
+        );
+
+        let char_index = |byte_index| {
+            contents
+                .char_indices()
+                .take_while(|(index, _)| *index <= byte_index)
+                .count()
+                .saturating_sub(1)
+        };
+
+        if !nearest_only {
+            for (index, bounds) in bounds.clone() {
+                if bounds.contains(point) {
+                    return Some(Hit::CharOffset(char_index(index)));
+                }
+            }
+        }
+
+        let nearest = bounds
+            .map(|(index, bounds)| (index, bounds.center()))
+            .min_by(|(_, center_a), (_, center_b)| {
+                center_a
+                    .distance(point)
+                    .partial_cmp(&center_b.distance(point))
+                    .unwrap_or(std::cmp::Ordering::Greater)
+            });
+
+        nearest.map(|(index, center)| {
+            Hit::NearestCharOffset(char_index(index), point - center)
+        })
+    }
+}
@@ -0,0 +1,152 @@
+//! Test the user interfaces of [Iced] without a window.
+//!
+//! A [`Simulator`] lays out an [`Element`] with a headless [`Renderer`] that
+//! measures text for real. Then, it lets you find its widgets with a
+//! [`Selector`], interact with them using simulated input, and assert on the
+//! messages they produce and their resulting layout.
+//!
+//! # Example
+//! ```
+//! use iced_native::column;
+//! use iced_native::widget::{button, text_input};
+//! use iced_test::{selector, Simulator};
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     NameChanged(String),
+//!     Save,
+//! }
+//!
+//! let name = text_input::Id::new("name");
+//!
+//! let mut ui: Simulator<'_, Message> = Simulator::new(column![
+//!     text_input("Name", "")
+//!         .id(name.clone())
+//!         .on_input(Message::NameChanged),
+//!     button("Save").on_press(Message::Save),
+//! ]);
+//!
+//! let _ = ui.type_text(name, "F")?;
+//! let _ = ui.click(selector::text("Save"))?;
+//!
+//! assert_eq!(
+//!     ui.messages(),
+//!     [Message::NameChanged(String::from("F")), Message::Save]
+//! );
+//! # Ok::<(), iced_test::Error>(())
+//! ```
+//!
+//! [Iced]: https://github.com/iced-rs/iced
+//! [`Element`]: iced_native::Element
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
+)]
+#![deny(
+    missing_debug_implementations,
+    missing_docs,
+    unsafe_code,
+    unused_results,
+    clippy::extra_unused_lifetimes,
+    clippy::from_over_into,
+    clippy::needless_borrow,
+    clippy::new_without_default,
+    clippy::useless_conversion
+)]
+#![forbid(rust_2018_idioms)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+pub mod selector;
+
+mod backend;
+mod simulator;
+
+pub use backend::Backend;
+pub use selector::{Selector, Target};
+pub use simulator::Simulator;
+
+/// A headless [`iced_graphics::Renderer`] that measures text for real.
+pub type Renderer<Theme = iced_native::Theme> =
+    iced_graphics::Renderer<Backend, Theme>;
+
+/// An error produced by a [`Simulator`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// No widget matches the [`Selector`].
+    #[error("no widget matches the selector {0:?}")]
+    NotFound(Selector),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_native::column;
+    use iced_native::keyboard::KeyCode;
+    use iced_native::widget::{button, text, text_input};
+    use iced_native::Element;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        NameChanged(String),
+        Submit,
+        Save,
+    }
+
+    fn form<'a>(name: &text_input::Id) -> Element<'a, Message, Renderer> {
+        column![
+            text("Profile"),
+            text_input("Name", "")
+                .id(name.clone())
+                .on_input(Message::NameChanged)
+                .on_submit(Message::Submit),
+            button("Save").on_press(Message::Save),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn click_by_text_produces_message() {
+        let name = text_input::Id::new("name");
+        let mut ui = Simulator::new(form(&name));
+
+        let target = ui.click(selector::text("Save")).unwrap();
+
+        assert!(target.bounds.width > 0.0 && target.bounds.height > 0.0);
+        assert_eq!(ui.messages(), [Message::Save]);
+    }
+
+    #[test]
+    fn type_text_and_press_submit() {
+        let name = text_input::Id::new("name");
+        let mut ui = Simulator::new(form(&name));
+
+        let _ = ui.type_text(name, "Hi").unwrap();
+        ui.press(KeyCode::Enter);
+
+        // The view is not rebuilt, but the text input keeps what was typed
+        assert_eq!(
+            ui.messages(),
+            [
+                Message::NameChanged(String::from("H")),
+                Message::NameChanged(String::from("Hi")),
+                Message::Submit,
+            ]
+        );
+    }
+
+    #[test]
+    fn find_reports_layout_and_missing_widgets() {
+        let name = text_input::Id::new("name");
+        let mut ui = Simulator::new(form(&name));
+
+        let title = ui.find(selector::text("Profile")).unwrap();
+        let input = ui.find(selector::id(name)).unwrap();
+
+        assert_eq!(title.bounds.y, 0.0);
+        assert!(input.bounds.y >= title.bounds.y + title.bounds.height);
+
+        assert_eq!(
+            ui.find(selector::text("Cancel")),
+            Err(Error::NotFound(selector::text("Cancel")))
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Select widgets of a user interface.
+use iced_native::widget::{self, Operation};
+use iced_native::Rectangle;
+
+use std::borrow::Cow;
+
+/// A query that matches some widget of a user interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selector {
+    /// Matches the widget with the given [`widget::Id`].
+    Id(widget::Id),
+
+    /// Matches a widget displaying exactly the given text.
+    Text(Cow<'static, str>),
+}
+
+/// Creates a [`Selector`] that matches the widget with the given id.
+pub fn id(id: impl Into<widget::Id>) -> Selector {
+    Selector::Id(id.into())
+}
+
+/// Creates a [`Selector`] that matches a widget displaying exactly the given
+/// text.
+pub fn text(text: impl Into<Cow<'static, str>>) -> Selector {
+    Selector::Text(text.into())
+}
+
+impl From<widget::Id> for Selector {
+    fn from(id: widget::Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+/// A widget matched by a [`Selector`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Target {
+    /// The bounds of the widget.
+    pub bounds: Rectangle,
+}
+
+/// An [`Operation`] that finds the first widget matching a [`Selector`].
+#[derive(Debug)]
+pub(crate) struct Find<'a> {
+    selector: &'a Selector,
+    target: Option<Target>,
+}
+
+impl<'a> Find<'a> {
+    pub fn new(selector: &'a Selector) -> Self {
+        Self {
+            selector,
+            target: None,
+        }
+    }
+
+    pub fn target(&self) -> Option<Target> {
+        self.target
+    }
+}
+
+impl<'a, T> Operation<T> for Find<'a> {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.target.is_none() {
+            operate_on_children(self);
+        }
+    }
+
+    fn bounds(&mut self, id: &widget::Id, bounds: Rectangle) {
+        if self.target.is_some() {
+            return;
+        }
+
+        if let Selector::Id(target) = self.selector {
+            if target == id {
+                self.target = Some(Target { bounds });
+            }
+        }
+    }
+
+    fn text(&mut self, id: Option<&widget::Id>, bounds: Rectangle, text: &str) {
+        if self.target.is_some() {
+            return;
+        }
+
+        match self.selector {
+            Selector::Id(target) if id == Some(target) => {
+                self.target = Some(Target { bounds });
+            }
+            Selector::Text(target) if target == text => {
+                self.target = Some(Target { bounds });
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use crate::selector::{Find, Selector, Target};
+use crate::{Error, Renderer};
+
+use iced_native::application;
+use iced_native::clipboard;
+use iced_native::event::{self, Event};
+use iced_native::keyboard;
+use iced_native::mouse;
+use iced_native::user_interface::{self, UserInterface};
+use iced_native::widget::{self, operation};
+use iced_native::{Element, Point, Size};
+
+/// A user interface that can be driven by simulated input.
+///
+/// The [`Simulator`] never rebuilds its view. The messages it produces reflect
+/// the [`Element`] it was created with, as if the application ignored them.
+/// However, widgets still change as they process events; for instance, a text
+/// input keeps the text typed into it.
+#[allow(missing_debug_implementations)]
+pub struct Simulator<'a, Message, Theme = iced_native::Theme> {
+    raw: UserInterface<'a, Message, Renderer<Theme>>,
+    renderer: Renderer<Theme>,
+    cursor_position: Point,
+    modifiers: keyboard::Modifiers,
+    messages: Vec<Message>,
+}
+
+impl<'a, Message, Theme> Simulator<'a, Message, Theme>
+where
+    Theme: application::StyleSheet,
+{
+    /// The default size of the window of a [`Simulator`].
+    pub const DEFAULT_SIZE: Size = Size::new(1024.0, 768.0);
+
+    /// Creates a new [`Simulator`] of the given [`Element`] with the
+    /// [`Simulator::DEFAULT_SIZE`].
+    pub fn new(
+        element: impl Into<Element<'a, Message, Renderer<Theme>>>,
+    ) -> Self {
+        Self::with_size(Self::DEFAULT_SIZE, element)
+    }
+
+    /// Creates a new [`Simulator`] of the given [`Element`] laid out in a
+    /// window of the given [`Size`].
+    pub fn with_size(
+        size: Size,
+        element: impl Into<Element<'a, Message, Renderer<Theme>>>,
+    ) -> Self {
+        let mut renderer = Renderer::new(crate::Backend::new());
+
+        let raw = UserInterface::build(
+            element,
+            size,
+            user_interface::Cache::default(),
+            &mut renderer,
+        );
+
+        Self {
+            raw,
+            renderer,
+            cursor_position: Point::new(-1.0, -1.0),
+            modifiers: keyboard::Modifiers::default(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Finds the first widget matching the given [`Selector`].
+    pub fn find(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let selector = selector.into();
+        let mut find = Find::new(&selector);
+
+        self.raw.operate(&self.renderer, &mut find);
+
+        find.target().ok_or(Error::NotFound(selector))
+    }
+
+    /// Moves the cursor to the given position.
+    pub fn point_at(&mut self, position: impl Into<Point>) {
+        self.cursor_position = position.into();
+
+        let _ = self.simulate([Event::Mouse(mouse::Event::CursorMoved {
+            position: self.cursor_position,
+        })]);
+    }
+
+    /// Clicks the center of the first widget matching the given [`Selector`].
+    pub fn click(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let target = self.find(selector)?;
+
+        self.point_at(target.bounds.center());
+
+        let _ = self.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        Ok(target)
+    }
+
+    /// Focuses the widget with the given [`widget::Id`].
+    pub fn focus(
+        &mut self,
+        id: impl Into<widget::Id>,
+    ) -> Result<Target, Error> {
+        let id = id.into();
+        let target = self.find(id.clone())?;
+
+        self.raw
+            .operate(&self.renderer, &mut operation::focusable::focus(id));
+
+        Ok(target)
+    }
+
+    /// Focuses the widget with the given [`widget::Id`] and types the given
+    /// text into it.
+    ///
+    /// Every character is processed in its own update, like keystrokes
+    /// received by a window.
+    pub fn type_text(
+        &mut self,
+        id: impl Into<widget::Id>,
+        text: impl AsRef<str>,
+    ) -> Result<Target, Error> {
+        let target = self.focus(id)?;
+
+        for c in text.as_ref().chars() {
+            let _ = self.simulate([Event::Keyboard(
+                keyboard::Event::CharacterReceived(c),
+            )]);
+        }
+
+        Ok(target)
+    }
+
+    /// Sets the [`keyboard::Modifiers`] held down while pressing keys.
+    pub fn hold(&mut self, modifiers: keyboard::Modifiers) {
+        self.modifiers = modifiers;
+
+        let _ = self.simulate([Event::Keyboard(
+            keyboard::Event::ModifiersChanged(modifiers),
+        )]);
+    }
+
+    /// Presses and releases the given key.
+    pub fn press(&mut self, key_code: keyboard::KeyCode) {
+        let modifiers = self.modifiers;
+
+        let _ = self.simulate([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }),
+        ]);
+    }
+
+    /// Processes the given events, returning whether each of them was
+    /// captured.
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        let events: Vec<Event> = events.into_iter().collect();
+
+        let (_, statuses) = self.raw.update(
+            &events,
+            self.cursor_position,
+            &mut self.renderer,
+            &mut clipboard::Null,
+            &mut self.messages,
+        );
+
+        statuses
+    }
+
+    /// Takes the messages produced so far.
+    pub fn messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+}
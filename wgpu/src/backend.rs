@@ -1,6 +1,9 @@
+use crate::blur;
+use crate::opacity;
 use crate::quad;
 use crate::text;
 use crate::triangle;
+use crate::widget::shader;
 use crate::{Settings, Transformation};
 
 use iced_graphics::backend;
@@ -8,7 +11,7 @@ use iced_graphics::font;
 use iced_graphics::layer::Layer;
 use iced_graphics::{Primitive, Viewport};
 use iced_native::alignment;
-use iced_native::{Font, Size};
+use iced_native::{Font, Rectangle, Size};
 
 #[cfg(feature = "tracing")]
 use tracing::info_span;
@@ -23,6 +26,10 @@ use crate::image;
 #[derive(Debug)]
 pub struct Backend {
     quad_pipeline: quad::Pipeline,
+    opacity_pipeline: opacity::Pipeline,
+    blur_pipeline: blur::Pipeline,
+    format: wgpu::TextureFormat,
+    custom_storage: shader::Storage,
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
 
@@ -47,14 +54,27 @@ impl Backend {
         );
 
         let quad_pipeline = quad::Pipeline::new(device, format);
+        let opacity_pipeline = opacity::Pipeline::new(device, format);
+        let blur_pipeline = blur::Pipeline::new(device, format);
         let triangle_pipeline =
             triangle::Pipeline::new(device, format, settings.antialiasing);
 
         #[cfg(any(feature = "image", feature = "svg"))]
-        let image_pipeline = image::Pipeline::new(device, format);
+        let image_pipeline =
+            image::Pipeline::new(device, format, settings.image_cache_budget);
+
+        // Share the default font with the text of svgs
+        #[cfg(feature = "svg")]
+        if let Some(font) = settings.default_font {
+            image_pipeline.load_font(font);
+        }
 
         Self {
             quad_pipeline,
+            opacity_pipeline,
+            blur_pipeline,
+            format,
+            custom_storage: shader::Storage::default(),
             text_pipeline,
             triangle_pipeline,
 
@@ -72,6 +92,7 @@ impl Backend {
     pub fn present<T: AsRef<str>>(
         &mut self,
         device: &wgpu::Device,
+        queue: &wgpu::Queue,
         staging_belt: &mut wgpu::util::StagingBelt,
         encoder: &mut wgpu::CommandEncoder,
         frame: &wgpu::TextureView,
@@ -90,12 +111,45 @@ impl Backend {
         let mut layers = Layer::generate(primitives, viewport);
         layers.push(Layer::overlay(overlay_text, viewport));
 
-        for layer in layers {
-            self.flush(
+        if layers.iter().any(|layer| layer.backdrop.is_some()) {
+            // Backdrops need to read what is behind them, which is not
+            // possible with the frame; so we render offscreen first
+            let target =
+                self.opacity_pipeline.target(device, encoder, target_size);
+
+            self.draw_layers(
+                device,
+                queue,
+                scale_factor,
+                transformation,
+                &layers,
+                staging_belt,
+                encoder,
+                target.view(),
+                target_size,
+            );
+
+            self.opacity_pipeline.composite(
+                device,
+                staging_belt,
+                encoder,
+                target,
+                1.0,
+                Rectangle {
+                    x: 0,
+                    y: 0,
+                    width: target_size.width,
+                    height: target_size.height,
+                },
+                frame,
+            );
+        } else {
+            self.draw_layers(
                 device,
+                queue,
                 scale_factor,
                 transformation,
-                &layer,
+                &layers,
                 staging_belt,
                 encoder,
                 frame,
@@ -107,9 +161,160 @@ impl Backend {
         self.image_pipeline.trim_cache(device, encoder);
     }
 
+    /// Draws the given layers in the `target`.
+    ///
+    /// The `target` must be readable if any of the layers has a backdrop.
+    fn draw_layers(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        scale_factor: f32,
+        transformation: Transformation,
+        layers: &[Layer<'_>],
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        target_size: Size<u32>,
+    ) {
+        let mut i = 0;
+
+        while i < layers.len() {
+            let layer = &layers[i];
+
+            if let Some(backdrop) = layer.backdrop {
+                let bounds = (layer.bounds * scale_factor).snap();
+
+                self.blur_pipeline.backdrop(
+                    device,
+                    staging_belt,
+                    encoder,
+                    &mut self.opacity_pipeline,
+                    backdrop.bounds * scale_factor,
+                    backdrop.border_radius.map(|radius| radius * scale_factor),
+                    backdrop.blur_radius * scale_factor,
+                    bounds,
+                    target,
+                    target_size,
+                );
+            }
+
+            let group = match layer.group {
+                Some(group) if group.is_offscreen() => group,
+                _ => {
+                    self.flush(
+                        device,
+                        queue,
+                        scale_factor,
+                        transformation,
+                        layer,
+                        staging_belt,
+                        encoder,
+                        target,
+                        target_size,
+                    );
+
+                    i += 1;
+                    continue;
+                }
+            };
+
+            let bounds = (layer.bounds * scale_factor).snap();
+
+            if bounds.width >= 1 && bounds.height >= 1 {
+                // Translucent, blurred and masked groups are rendered
+                // offscreen first, so they can be composited as a whole
+                let group_target =
+                    self.opacity_pipeline.target(device, encoder, target_size);
+
+                let content = group.mask.unwrap_or(group.layers);
+
+                self.flush(
+                    device,
+                    queue,
+                    scale_factor,
+                    transformation,
+                    layer,
+                    staging_belt,
+                    encoder,
+                    group_target.view(),
+                    target_size,
+                );
+
+                self.draw_layers(
+                    device,
+                    queue,
+                    scale_factor,
+                    transformation,
+                    &layers[i + 1..i + content],
+                    staging_belt,
+                    encoder,
+                    group_target.view(),
+                    target_size,
+                );
+
+                if let Some(mask) = group.mask {
+                    let mask_target = self.opacity_pipeline.target(
+                        device,
+                        encoder,
+                        target_size,
+                    );
+
+                    self.draw_layers(
+                        device,
+                        queue,
+                        scale_factor,
+                        transformation,
+                        &layers[i + mask..i + group.layers],
+                        staging_belt,
+                        encoder,
+                        mask_target.view(),
+                        target_size,
+                    );
+
+                    self.opacity_pipeline.composite_masked(
+                        device,
+                        staging_belt,
+                        encoder,
+                        group_target,
+                        mask_target,
+                        group.opacity,
+                        bounds,
+                        target,
+                    );
+                } else if group.blur_radius > 0.0 {
+                    self.blur_pipeline.composite(
+                        device,
+                        staging_belt,
+                        encoder,
+                        &mut self.opacity_pipeline,
+                        group_target,
+                        group.blur_radius * scale_factor,
+                        group.opacity,
+                        bounds,
+                        target,
+                        target_size,
+                    );
+                } else {
+                    self.opacity_pipeline.composite(
+                        device,
+                        staging_belt,
+                        encoder,
+                        group_target,
+                        group.opacity,
+                        bounds,
+                        target,
+                    );
+                }
+            }
+
+            i += group.layers;
+        }
+    }
+
     fn flush(
         &mut self,
         device: &wgpu::Device,
+        queue: &wgpu::Queue,
         scale_factor: f32,
         transformation: Transformation,
         layer: &Layer<'_>,
@@ -124,6 +329,18 @@ impl Backend {
             return;
         }
 
+        // The transformation of a layer is defined in logical coordinates
+        let transformation = if layer.transformation
+            == Transformation::identity()
+        {
+            transformation
+        } else {
+            transformation
+                * Transformation::scale(scale_factor, scale_factor)
+                * layer.transformation
+                * Transformation::scale(1.0 / scale_factor, 1.0 / scale_factor)
+        };
+
         if !layer.quads.is_empty() {
             self.quad_pipeline.draw(
                 device,
@@ -153,6 +370,44 @@ impl Backend {
             );
         }
 
+        for custom in &layer.custom {
+            let Some(primitive) = shader::primitive(custom) else {
+                continue;
+            };
+
+            let transformed_bounds =
+                layer.transformation.transform_rectangle(custom.bounds);
+
+            let Some(clip_bounds) = layer
+                .bounds
+                .intersection(&transformed_bounds)
+                .map(|clip_bounds| (clip_bounds * scale_factor).snap())
+                .filter(|clip_bounds| {
+                    clip_bounds.width >= 1 && clip_bounds.height >= 1
+                })
+            else {
+                continue;
+            };
+
+            primitive.prepare(
+                self.format,
+                device,
+                queue,
+                transformed_bounds,
+                target_size,
+                scale_factor,
+                &mut self.custom_storage,
+            );
+
+            primitive.render(
+                &self.custom_storage,
+                target,
+                target_size,
+                clip_bounds,
+                encoder,
+            );
+        }
+
         #[cfg(any(feature = "image", feature = "svg"))]
         {
             if !layer.images.is_empty() {
@@ -258,6 +513,11 @@ impl iced_graphics::Backend for Backend {
     fn trim_measurements(&mut self) {
         self.text_pipeline.trim_measurement_cache()
     }
+
+    #[cfg(any(feature = "image", feature = "svg"))]
+    fn image_statistics(&self) -> iced_graphics::image::Statistics {
+        self.image_pipeline.statistics()
+    }
 }
 
 impl backend::Text for Backend {
@@ -304,6 +564,13 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn status(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> iced_native::image::Status {
+        self.image_pipeline.status(handle)
+    }
 }
 
 #[cfg(feature = "svg")]
@@ -0,0 +1,429 @@
+//! Blur groups of layers and the backdrop of layers.
+use crate::opacity;
+
+use iced_native::{Rectangle, Size};
+
+use bytemuck::{Pod, Zeroable};
+use std::mem;
+
+#[cfg(feature = "tracing")]
+use tracing::info_span;
+
+/// The maximum amount of samples taken on each side of a fragment.
+///
+/// Larger blurs skip texels and rely on linear filtering.
+const MAX_SAMPLES: f32 = 32.0;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    replace: wgpu::RenderPipeline,
+    composite: wgpu::RenderPipeline,
+    erase: wgpu::RenderPipeline,
+    backdrop: wgpu::RenderPipeline,
+    constants: wgpu::BindGroup,
+    texture_layout: wgpu::BindGroupLayout,
+    uniforms: wgpu::Buffer,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Pipeline {
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let constant_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::blur constants layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(
+                        wgpu::SamplerBindingType::Filtering,
+                    ),
+                    count: None,
+                }],
+            });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::blur constants bind group"),
+            layout: &constant_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Sampler(&sampler),
+            }],
+        });
+
+        let texture_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::blur texture layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float {
+                                filterable: true,
+                            },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: wgpu::BufferSize::new(
+                                mem::size_of::<Uniforms>() as u64,
+                            ),
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::blur pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&constant_layout, &texture_layout],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu blur shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("shader/blur.wgsl"),
+                )),
+            });
+
+        let pipeline = |label, entry_point, blend| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point,
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+            })
+        };
+
+        let replace =
+            pipeline("iced_wgpu::blur replace pipeline", "fs_blur", None);
+
+        let composite = pipeline(
+            "iced_wgpu::blur composite pipeline",
+            "fs_blur",
+            Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+        );
+
+        // Scales the destination down by the coverage of the backdrop...
+        let erase_component = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::Zero,
+            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+            operation: wgpu::BlendOperation::Add,
+        };
+
+        let erase = pipeline(
+            "iced_wgpu::blur erase pipeline",
+            "fs_erase",
+            Some(wgpu::BlendState {
+                color: erase_component,
+                alpha: erase_component,
+            }),
+        );
+
+        // ...and then adds the blurred backdrop in its place
+        let add_component = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::One,
+            operation: wgpu::BlendOperation::Add,
+        };
+
+        let backdrop = pipeline(
+            "iced_wgpu::blur backdrop pipeline",
+            "fs_backdrop",
+            Some(wgpu::BlendState {
+                color: add_component,
+                alpha: add_component,
+            }),
+        );
+
+        let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::blur uniforms buffer"),
+            size: mem::size_of::<Uniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Pipeline {
+            replace,
+            composite,
+            erase,
+            backdrop,
+            constants,
+            texture_layout,
+            uniforms,
+        }
+    }
+
+    /// Blurs the contents of the given [`opacity::Target`] and blends them
+    /// into the `frame` with the given opacity.
+    ///
+    /// The `radius` is in physical pixels.
+    pub fn composite(
+        &mut self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        opacity_pipeline: &mut opacity::Pipeline,
+        target: opacity::Target,
+        radius: f32,
+        opacity: f32,
+        bounds: Rectangle<u32>,
+        frame: &wgpu::TextureView,
+        frame_size: Size<u32>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Wgpu::Blur", "COMPOSITE").entered();
+
+        let scratch = opacity_pipeline.target(device, encoder, frame_size);
+
+        self.pass(
+            device,
+            staging_belt,
+            encoder,
+            Uniforms::horizontal(radius, frame_size),
+            target.view(),
+            scratch.view(),
+            &[&self.replace],
+            expand_vertically(bounds, radius, frame_size),
+        );
+
+        self.pass(
+            device,
+            staging_belt,
+            encoder,
+            Uniforms {
+                opacity,
+                ..Uniforms::vertical(radius, frame_size)
+            },
+            scratch.view(),
+            frame,
+            &[&self.composite],
+            bounds,
+        );
+
+        opacity_pipeline.recycle(scratch);
+        opacity_pipeline.recycle(target);
+    }
+
+    /// Blurs the contents of the `frame` inside the given rounded region.
+    ///
+    /// The `region`, `border_radius` and `radius` are in physical pixels.
+    pub fn backdrop(
+        &mut self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        opacity_pipeline: &mut opacity::Pipeline,
+        region: Rectangle,
+        border_radius: [f32; 4],
+        radius: f32,
+        bounds: Rectangle<u32>,
+        frame: &wgpu::TextureView,
+        frame_size: Size<u32>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Wgpu::Blur", "BACKDROP").entered();
+
+        let scratch = opacity_pipeline.target(device, encoder, frame_size);
+
+        self.pass(
+            device,
+            staging_belt,
+            encoder,
+            Uniforms::horizontal(radius, frame_size),
+            frame,
+            scratch.view(),
+            &[&self.replace],
+            expand_vertically(bounds, radius, frame_size),
+        );
+
+        self.pass(
+            device,
+            staging_belt,
+            encoder,
+            Uniforms {
+                bounds: [region.x, region.y, region.width, region.height],
+                border_radius,
+                ..Uniforms::vertical(radius, frame_size)
+            },
+            scratch.view(),
+            frame,
+            &[&self.erase, &self.backdrop],
+            bounds,
+        );
+
+        opacity_pipeline.recycle(scratch);
+    }
+
+    fn pass(
+        &self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        uniforms: Uniforms,
+        source: &wgpu::TextureView,
+        destination: &wgpu::TextureView,
+        pipelines: &[&wgpu::RenderPipeline],
+        bounds: Rectangle<u32>,
+    ) {
+        if bounds.width < 1 || bounds.height < 1 {
+            return;
+        }
+
+        staging_belt
+            .write_buffer(
+                encoder,
+                &self.uniforms,
+                0,
+                wgpu::BufferSize::new(mem::size_of::<Uniforms>() as u64)
+                    .unwrap(),
+                device,
+            )
+            .copy_from_slice(bytemuck::bytes_of(&uniforms));
+
+        let texture = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::blur texture bind group"),
+            layout: &self.texture_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(source),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: self.uniforms.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("iced_wgpu::blur render pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: destination,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+            });
+
+        render_pass.set_bind_group(0, &self.constants, &[]);
+        render_pass.set_bind_group(1, &texture, &[]);
+        render_pass.set_scissor_rect(
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+        );
+
+        for pipeline in pipelines {
+            render_pass.set_pipeline(pipeline);
+            render_pass.draw(0..6, 0..1);
+        }
+    }
+}
+
+/// Expands the given bounds vertically by the given amount, keeping them
+/// inside of a frame of the given size.
+///
+/// The vertical pass of a blur samples this region of the horizontal one.
+fn expand_vertically(
+    bounds: Rectangle<u32>,
+    amount: f32,
+    frame_size: Size<u32>,
+) -> Rectangle<u32> {
+    let amount = amount.ceil() as u32;
+
+    let y = bounds.y.saturating_sub(amount);
+    let bottom = (bounds.y + bounds.height + amount).min(frame_size.height);
+
+    Rectangle {
+        y,
+        height: bottom.saturating_sub(y),
+        ..bounds
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Uniforms {
+    step: [f32; 2],
+    sigma: f32,
+    samples: f32,
+    bounds: [f32; 4],
+    border_radius: [f32; 4],
+    opacity: f32,
+    _padding: [f32; 3],
+}
+
+impl Uniforms {
+    fn horizontal(radius: f32, frame_size: Size<u32>) -> Uniforms {
+        Self::new(radius, [1.0 / frame_size.width as f32, 0.0])
+    }
+
+    fn vertical(radius: f32, frame_size: Size<u32>) -> Uniforms {
+        Self::new(radius, [0.0, 1.0 / frame_size.height as f32])
+    }
+
+    fn new(radius: f32, texel: [f32; 2]) -> Uniforms {
+        let samples = radius.ceil().clamp(1.0, MAX_SAMPLES);
+        let spacing = radius.max(1.0) / samples;
+
+        Uniforms {
+            step: [texel[0] * spacing, texel[1] * spacing],
+            // The blur radius covers two standard deviations
+            sigma: samples / 2.0,
+            samples,
+            bounds: [0.0; 4],
+            border_radius: [0.0; 4],
+            opacity: 1.0,
+            _padding: [0.0; 3],
+        }
+    }
+}
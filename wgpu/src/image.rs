@@ -1,7 +1,7 @@
 mod atlas;
 
 #[cfg(feature = "image")]
-use iced_graphics::image::raster;
+use iced_graphics::image::{nine_slice, raster};
 
 #[cfg(feature = "svg")]
 use iced_graphics::image::vector;
@@ -9,6 +9,7 @@ use iced_graphics::image::vector;
 use crate::Transformation;
 use atlas::Atlas;
 
+use iced_graphics::image::{Budget, Statistics};
 use iced_graphics::layer;
 use iced_native::{Rectangle, Size};
 
@@ -39,6 +40,7 @@ pub struct Pipeline {
     indices: wgpu::Buffer,
     instances: wgpu::Buffer,
     constants: wgpu::BindGroup,
+    nearest_constants: wgpu::BindGroup,
     texture: wgpu::BindGroup,
     texture_version: usize,
     texture_layout: wgpu::BindGroupLayout,
@@ -46,7 +48,11 @@ pub struct Pipeline {
 }
 
 impl Pipeline {
-    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        budget: Budget,
+    ) -> Self {
         use wgpu::util::DeviceExt;
 
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
@@ -59,6 +65,16 @@ impl Pipeline {
             ..Default::default()
         });
 
+        let nearest_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: Some("iced_wgpu::image constants layout"),
@@ -93,9 +109,9 @@ impl Pipeline {
             mapped_at_creation: false,
         });
 
-        let constant_bind_group =
+        let create_constant_bind_group = |label, sampler| {
             device.create_bind_group(&wgpu::BindGroupDescriptor {
-                label: Some("iced_wgpu::image constants bind group"),
+                label: Some(label),
                 layout: &constant_layout,
                 entries: &[
                     wgpu::BindGroupEntry {
@@ -110,10 +126,21 @@ impl Pipeline {
                     },
                     wgpu::BindGroupEntry {
                         binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&sampler),
+                        resource: wgpu::BindingResource::Sampler(sampler),
                     },
                 ],
-            });
+            })
+        };
+
+        let constant_bind_group = create_constant_bind_group(
+            "iced_wgpu::image constants bind group",
+            &sampler,
+        );
+
+        let nearest_constant_bind_group = create_constant_bind_group(
+            "iced_wgpu::image nearest constants bind group",
+            &nearest_sampler,
+        );
 
         let texture_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -173,6 +200,9 @@ impl Pipeline {
                                 3 => Float32x2,
                                 4 => Float32x2,
                                 5 => Sint32,
+                                6 => Float32x2,
+                                7 => Float32,
+                                8 => Float32,
                             ),
                         },
                     ],
@@ -232,7 +262,10 @@ impl Pipeline {
             mapped_at_creation: false,
         });
 
-        let texture_atlas = Atlas::new(device);
+        let texture_atlas = Atlas::new(
+            device,
+            (budget.device + atlas::LAYER_BYTES - 1) / atlas::LAYER_BYTES,
+        );
 
         let texture = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("iced_wgpu::image texture atlas bind group"),
@@ -247,10 +280,10 @@ impl Pipeline {
 
         Pipeline {
             #[cfg(feature = "image")]
-            raster_cache: RefCell::new(raster::Cache::default()),
+            raster_cache: RefCell::new(raster::Cache::new(budget)),
 
             #[cfg(feature = "svg")]
-            vector_cache: RefCell::new(vector::Cache::default()),
+            vector_cache: RefCell::new(vector::Cache::new(budget)),
 
             pipeline,
             uniforms: uniforms_buffer,
@@ -258,6 +291,7 @@ impl Pipeline {
             indices,
             instances,
             constants: constant_bind_group,
+            nearest_constants: nearest_constant_bind_group,
             texture,
             texture_version: texture_atlas.layer_count(),
             texture_layout,
@@ -273,6 +307,19 @@ impl Pipeline {
         memory.dimensions()
     }
 
+    #[cfg(feature = "image")]
+    pub fn status(&self, handle: &image::Handle) -> image::Status {
+        let mut cache = self.raster_cache.borrow_mut();
+        let memory = cache.load(handle);
+
+        memory.status()
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn load_font(&self, bytes: &'static [u8]) {
+        self.vector_cache.borrow_mut().load_font(bytes);
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(&self, handle: &svg::Handle) -> Size<u32> {
         let mut cache = self.vector_cache.borrow_mut();
@@ -281,6 +328,21 @@ impl Pipeline {
         svg.viewport_dimensions()
     }
 
+    pub fn statistics(&self) -> Statistics {
+        let statistics = Statistics {
+            atlas_layers: self.texture_atlas.layer_count(),
+            ..Statistics::default()
+        };
+
+        #[cfg(feature = "image")]
+        let statistics = statistics + self.raster_cache.borrow().statistics();
+
+        #[cfg(feature = "svg")]
+        let statistics = statistics + self.vector_cache.borrow().statistics();
+
+        statistics
+    }
+
     pub fn draw(
         &mut self,
         device: &wgpu::Device,
@@ -295,7 +357,8 @@ impl Pipeline {
         #[cfg(feature = "tracing")]
         let _ = info_span!("Wgpu::Image", "DRAW").entered();
 
-        let instances: &mut Vec<Instance> = &mut Vec::new();
+        let mut linear_instances: Vec<Instance> = Vec::new();
+        let mut nearest_instances: Vec<Instance> = Vec::new();
 
         #[cfg(feature = "image")]
         let mut raster_cache = self.raster_cache.borrow_mut();
@@ -306,7 +369,13 @@ impl Pipeline {
         for image in images {
             match &image {
                 #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    filter_method,
+                    opacity,
+                    rotation,
+                    bounds,
+                } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
                         &mut (device, encoder),
@@ -315,35 +384,84 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
+                            *opacity,
+                            *rotation,
                             atlas_entry,
-                            instances,
+                            match filter_method {
+                                image::FilterMethod::Linear => {
+                                    &mut linear_instances
+                                }
+                                image::FilterMethod::Nearest => {
+                                    &mut nearest_instances
+                                }
+                            },
                         );
                     }
                 }
                 #[cfg(not(feature = "image"))]
                 layer::Image::Raster { .. } => {}
 
+                #[cfg(feature = "image")]
+                layer::Image::NineSlice {
+                    handle,
+                    insets,
+                    bounds,
+                } => {
+                    use iced_graphics::image::storage::Entry;
+
+                    if let Some(atlas_entry) = raster_cache.upload(
+                        handle,
+                        &mut (device, encoder),
+                        &mut self.texture_atlas,
+                    ) {
+                        for (source, destination) in
+                            nine_slice(atlas_entry.size(), *insets, *bounds)
+                        {
+                            add_slice_instances(
+                                source,
+                                destination,
+                                atlas_entry,
+                                &mut linear_instances,
+                            );
+                        }
+                    }
+                }
+                #[cfg(not(feature = "image"))]
+                layer::Image::NineSlice { .. } => {}
+
                 #[cfg(feature = "svg")]
                 layer::Image::Vector {
                     handle,
                     color,
+                    stylesheet,
                     bounds,
                 } => {
+                    use iced_graphics::image::storage::Entry;
+
                     let size = [bounds.width, bounds.height];
 
                     if let Some(atlas_entry) = vector_cache.upload(
                         handle,
                         *color,
+                        stylesheet.as_ref(),
                         size,
                         _scale,
                         &mut (device, encoder),
                         &mut self.texture_atlas,
                     ) {
+                        let bounds = vector::pixel_bounds(
+                            *bounds,
+                            atlas_entry.size(),
+                            _scale,
+                        );
+
                         add_instances(
                             [bounds.x, bounds.y],
-                            size,
+                            [bounds.width, bounds.height],
+                            1.0,
+                            0.0,
                             atlas_entry,
-                            instances,
+                            &mut linear_instances,
                         );
                     }
                 }
@@ -352,7 +470,7 @@ impl Pipeline {
             }
         }
 
-        if instances.is_empty() {
+        if linear_instances.is_empty() && nearest_instances.is_empty() {
             return;
         }
 
@@ -391,68 +509,73 @@ impl Pipeline {
             }));
         }
 
-        let mut i = 0;
-        let total = instances.len();
-
-        while i < total {
-            let end = (i + Instance::MAX).min(total);
-            let amount = end - i;
-
-            let mut instances_buffer = staging_belt.write_buffer(
-                encoder,
-                &self.instances,
-                0,
-                wgpu::BufferSize::new(
-                    (amount * std::mem::size_of::<Instance>()) as u64,
-                )
-                .unwrap(),
-                device,
-            );
-
-            instances_buffer.copy_from_slice(bytemuck::cast_slice(
-                &instances[i..i + amount],
-            ));
-
-            let mut render_pass =
-                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: Some("iced_wgpu::image render pass"),
-                    color_attachments: &[Some(
-                        wgpu::RenderPassColorAttachment {
-                            view: target,
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Load,
-                                store: true,
+        for (instances, constants) in [
+            (&linear_instances, &self.constants),
+            (&nearest_instances, &self.nearest_constants),
+        ] {
+            let mut i = 0;
+            let total = instances.len();
+
+            while i < total {
+                let end = (i + Instance::MAX).min(total);
+                let amount = end - i;
+
+                let mut instances_buffer = staging_belt.write_buffer(
+                    encoder,
+                    &self.instances,
+                    0,
+                    wgpu::BufferSize::new(
+                        (amount * std::mem::size_of::<Instance>()) as u64,
+                    )
+                    .unwrap(),
+                    device,
+                );
+
+                instances_buffer.copy_from_slice(bytemuck::cast_slice(
+                    &instances[i..i + amount],
+                ));
+
+                let mut render_pass =
+                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                        label: Some("iced_wgpu::image render pass"),
+                        color_attachments: &[Some(
+                            wgpu::RenderPassColorAttachment {
+                                view: target,
+                                resolve_target: None,
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Load,
+                                    store: true,
+                                },
                             },
-                        },
-                    )],
-                    depth_stencil_attachment: None,
-                });
-
-            render_pass.set_pipeline(&self.pipeline);
-            render_pass.set_bind_group(0, &self.constants, &[]);
-            render_pass.set_bind_group(1, &self.texture, &[]);
-            render_pass.set_index_buffer(
-                self.indices.slice(..),
-                wgpu::IndexFormat::Uint16,
-            );
-            render_pass.set_vertex_buffer(0, self.vertices.slice(..));
-            render_pass.set_vertex_buffer(1, self.instances.slice(..));
-
-            render_pass.set_scissor_rect(
-                bounds.x,
-                bounds.y,
-                bounds.width,
-                bounds.height,
-            );
-
-            render_pass.draw_indexed(
-                0..QUAD_INDICES.len() as u32,
-                0,
-                0..amount as u32,
-            );
-
-            i += Instance::MAX;
+                        )],
+                        depth_stencil_attachment: None,
+                    });
+
+                render_pass.set_pipeline(&self.pipeline);
+                render_pass.set_bind_group(0, constants, &[]);
+                render_pass.set_bind_group(1, &self.texture, &[]);
+                render_pass.set_index_buffer(
+                    self.indices.slice(..),
+                    wgpu::IndexFormat::Uint16,
+                );
+                render_pass.set_vertex_buffer(0, self.vertices.slice(..));
+                render_pass.set_vertex_buffer(1, self.instances.slice(..));
+
+                render_pass.set_scissor_rect(
+                    bounds.x,
+                    bounds.y,
+                    bounds.width,
+                    bounds.height,
+                );
+
+                render_pass.draw_indexed(
+                    0..QUAD_INDICES.len() as u32,
+                    0,
+                    0..amount as u32,
+                );
+
+                i += Instance::MAX;
+            }
         }
     }
 
@@ -504,6 +627,9 @@ struct Instance {
     _position_in_atlas: [f32; 2],
     _size_in_atlas: [f32; 2],
     _layer: u32,
+    _center: [f32; 2],
+    _rotation: f32,
+    _opacity: f32,
 }
 
 impl Instance {
@@ -519,12 +645,28 @@ struct Uniforms {
 fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
+    opacity: f32,
+    rotation: f32,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
+    let center = [
+        image_position[0] + image_size[0] / 2.0,
+        image_position[1] + image_size[1] / 2.0,
+    ];
+
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(image_position, image_size, allocation, instances);
+            add_instance(
+                image_position,
+                image_size,
+                center,
+                opacity,
+                rotation,
+                allocation,
+                whole(allocation),
+                instances,
+            );
         }
         atlas::Entry::Fragmented { fragments, size } => {
             let scaling_x = image_size[0] / size.width as f32;
@@ -550,35 +692,127 @@ fn add_instances(
                     fragment_height as f32 * scaling_y,
                 ];
 
-                add_instance(position, size, allocation, instances);
+                add_instance(
+                    position,
+                    size,
+                    center,
+                    opacity,
+                    rotation,
+                    allocation,
+                    whole(allocation),
+                    instances,
+                );
             }
         }
     }
 }
 
+#[cfg(feature = "image")]
+fn add_slice_instances(
+    source: Rectangle,
+    destination: Rectangle,
+    entry: &atlas::Entry,
+    instances: &mut Vec<Instance>,
+) {
+    let center = destination.center();
+
+    match entry {
+        atlas::Entry::Contiguous(allocation) => {
+            add_instance(
+                [destination.x, destination.y],
+                [destination.width, destination.height],
+                [center.x, center.y],
+                1.0,
+                0.0,
+                allocation,
+                source,
+                instances,
+            );
+        }
+        atlas::Entry::Fragmented { fragments, .. } => {
+            let scaling_x = destination.width / source.width;
+            let scaling_y = destination.height / source.height;
+
+            for fragment in fragments {
+                let allocation = &fragment.allocation;
+
+                let (fragment_x, fragment_y) = fragment.position;
+                let fragment_region = Rectangle {
+                    x: fragment_x as f32,
+                    y: fragment_y as f32,
+                    ..whole(allocation)
+                };
+
+                let Some(part) = source.intersection(&fragment_region) else {
+                    continue;
+                };
+
+                let position = [
+                    destination.x + (part.x - source.x) * scaling_x,
+                    destination.y + (part.y - source.y) * scaling_y,
+                ];
+
+                let size = [part.width * scaling_x, part.height * scaling_y];
+
+                add_instance(
+                    position,
+                    size,
+                    [center.x, center.y],
+                    1.0,
+                    0.0,
+                    allocation,
+                    Rectangle {
+                        x: part.x - fragment_x as f32,
+                        y: part.y - fragment_y as f32,
+                        ..part
+                    },
+                    instances,
+                );
+            }
+        }
+    }
+}
+
+fn whole(allocation: &atlas::Allocation) -> Rectangle {
+    let Size { width, height } = allocation.size();
+
+    Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: width as f32,
+        height: height as f32,
+    }
+}
+
 #[inline]
 fn add_instance(
     position: [f32; 2],
     size: [f32; 2],
+    center: [f32; 2],
+    opacity: f32,
+    rotation: f32,
     allocation: &atlas::Allocation,
+    region: Rectangle,
     instances: &mut Vec<Instance>,
 ) {
     let (x, y) = allocation.position();
-    let Size { width, height } = allocation.size();
     let layer = allocation.layer();
 
     let instance = Instance {
         _position: position,
         _size: size,
         _position_in_atlas: [
-            (x as f32 + 0.5) / atlas::SIZE as f32,
-            (y as f32 + 0.5) / atlas::SIZE as f32,
+            (x as f32 + region.x + 0.5) / atlas::SIZE as f32,
+            (y as f32 + region.y + 0.5) / atlas::SIZE as f32,
         ],
         _size_in_atlas: [
-            (width as f32 - 1.0) / atlas::SIZE as f32,
-            (height as f32 - 1.0) / atlas::SIZE as f32,
+            (region.width - 1.0) / atlas::SIZE as f32,
+            (region.height - 1.0) / atlas::SIZE as f32,
         ],
         _layer: layer as u32,
+        _center: center,
+        _rotation: rotation,
+        _opacity: opacity,
     };
 
     instances.push(instance);
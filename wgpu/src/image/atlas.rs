@@ -12,6 +12,9 @@ use allocator::Allocator;
 
 pub const SIZE: u32 = 2048;
 
+/// The amount of bytes of a single layer of the [`Atlas`].
+pub const LAYER_BYTES: usize = SIZE as usize * SIZE as usize * 4;
+
 use iced_graphics::image;
 use iced_graphics::Size;
 
@@ -20,10 +23,13 @@ pub struct Atlas {
     texture: wgpu::Texture,
     texture_view: wgpu::TextureView,
     layers: Vec<Layer>,
+    max_layers: usize,
 }
 
 impl Atlas {
-    pub fn new(device: &wgpu::Device) -> Self {
+    /// Creates a new [`Atlas`] that never grows beyond the given amount of
+    /// layers.
+    pub fn new(device: &wgpu::Device, max_layers: usize) -> Self {
         let extent = wgpu::Extent3d {
             width: SIZE,
             height: SIZE,
@@ -52,6 +58,7 @@ impl Atlas {
             texture,
             texture_view,
             layers: vec![Layer::Empty],
+            max_layers: max_layers.max(1),
         }
     }
 
@@ -78,6 +85,10 @@ impl Atlas {
                 return Some(Entry::Contiguous(Allocation::Full { layer: i }));
             }
 
+            if self.layers.len() >= self.max_layers {
+                return None;
+            }
+
             self.layers.push(Layer::Full);
 
             return Some(Entry::Contiguous(Allocation::Full {
@@ -97,7 +108,14 @@ impl Atlas {
                 while x < width {
                     let width = std::cmp::min(width - x, SIZE);
 
-                    let allocation = self.allocate(width, height)?;
+                    let Some(allocation) = self.allocate(width, height) else {
+                        // Give back the fragments allocated so far
+                        for fragment in &fragments {
+                            self.deallocate(&fragment.allocation);
+                        }
+
+                        return None;
+                    };
 
                     if let Entry::Contiguous(allocation) = allocation {
                         fragments.push(entry::Fragment {
@@ -145,6 +163,10 @@ impl Atlas {
             }
         }
 
+        if self.layers.len() >= self.max_layers {
+            return None;
+        }
+
         // Create new layer with atlas allocator
         let mut allocator = Allocator::new(SIZE);
 
@@ -182,6 +204,68 @@ impl Atlas {
         }
     }
 
+    fn upload_entry(
+        &mut self,
+        entry: &Entry,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        use wgpu::util::DeviceExt;
+
+        // It is a webgpu requirement that:
+        //   BufferCopyView.layout.bytes_per_row % wgpu::COPY_BYTES_PER_ROW_ALIGNMENT == 0
+        // So we calculate padded_width by rounding width up to the next
+        // multiple of wgpu::COPY_BYTES_PER_ROW_ALIGNMENT.
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padding = (align - (4 * width) % align) % align;
+        let padded_width = (4 * width + padding) as usize;
+        let padded_data_size = padded_width * height as usize;
+
+        let mut padded_data = vec![0; padded_data_size];
+
+        for row in 0..height as usize {
+            let offset = row * padded_width;
+
+            padded_data[offset..offset + 4 * width as usize].copy_from_slice(
+                &data[row * 4 * width as usize..(row + 1) * 4 * width as usize],
+            )
+        }
+
+        let buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("iced_wgpu::image staging buffer"),
+                contents: &padded_data,
+                usage: wgpu::BufferUsages::COPY_SRC,
+            });
+
+        match entry {
+            Entry::Contiguous(allocation) => {
+                self.upload_allocation(
+                    &buffer, width, height, padding, 0, allocation, encoder,
+                );
+            }
+            Entry::Fragmented { fragments, .. } => {
+                for fragment in fragments {
+                    let (x, y) = fragment.position;
+                    let offset = (y * padded_width as u32 + 4 * x) as usize;
+
+                    self.upload_allocation(
+                        &buffer,
+                        width,
+                        height,
+                        padding,
+                        offset,
+                        &fragment.allocation,
+                        encoder,
+                    );
+                }
+            }
+        }
+    }
+
     fn upload_allocation(
         &mut self,
         buffer: &wgpu::Buffer,
@@ -310,11 +394,15 @@ impl image::Storage for Atlas {
         data: &[u8],
         (device, encoder): &mut Self::State<'_>,
     ) -> Option<Self::Entry> {
-        use wgpu::util::DeviceExt;
-
         let entry = {
             let current_size = self.layers.len();
-            let entry = self.allocate(width, height)?;
+
+            let Some(entry) = self.allocate(width, height) else {
+                // A failed allocation leaves its new layers empty
+                self.layers.truncate(current_size);
+
+                return None;
+            };
 
             // We grow the internal texture after allocating if necessary
             let new_layers = self.layers.len() - current_size;
@@ -325,59 +413,24 @@ impl image::Storage for Atlas {
 
         log::info!("Allocated atlas entry: {:?}", entry);
 
-        // It is a webgpu requirement that:
-        //   BufferCopyView.layout.bytes_per_row % wgpu::COPY_BYTES_PER_ROW_ALIGNMENT == 0
-        // So we calculate padded_width by rounding width up to the next
-        // multiple of wgpu::COPY_BYTES_PER_ROW_ALIGNMENT.
-        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
-        let padding = (align - (4 * width) % align) % align;
-        let padded_width = (4 * width + padding) as usize;
-        let padded_data_size = padded_width * height as usize;
+        self.upload_entry(&entry, width, height, data, device, encoder);
 
-        let mut padded_data = vec![0; padded_data_size];
-
-        for row in 0..height as usize {
-            let offset = row * padded_width;
-
-            padded_data[offset..offset + 4 * width as usize].copy_from_slice(
-                &data[row * 4 * width as usize..(row + 1) * 4 * width as usize],
-            )
-        }
-
-        let buffer =
-            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some("iced_wgpu::image staging buffer"),
-                contents: &padded_data,
-                usage: wgpu::BufferUsages::COPY_SRC,
-            });
+        log::info!("Current atlas: {:?}", self);
 
-        match &entry {
-            Entry::Contiguous(allocation) => {
-                self.upload_allocation(
-                    &buffer, width, height, padding, 0, allocation, encoder,
-                );
-            }
-            Entry::Fragmented { fragments, .. } => {
-                for fragment in fragments {
-                    let (x, y) = fragment.position;
-                    let offset = (y * padded_width as u32 + 4 * x) as usize;
+        Some(entry)
+    }
 
-                    self.upload_allocation(
-                        &buffer,
-                        width,
-                        height,
-                        padding,
-                        offset,
-                        &fragment.allocation,
-                        encoder,
-                    );
-                }
-            }
-        }
+    fn update(
+        &mut self,
+        entry: &Entry,
+        data: &[u8],
+        (device, encoder): &mut Self::State<'_>,
+    ) {
+        log::info!("Updating atlas entry: {:?}", entry);
 
-        log::info!("Current atlas: {:?}", self);
+        let Size { width, height } = image::storage::Entry::size(entry);
 
-        Some(entry)
+        self.upload_entry(entry, width, height, data, device, encoder);
     }
 
     fn remove(&mut self, entry: &Entry, _: &mut Self::State<'_>) {
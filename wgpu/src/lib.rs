@@ -12,14 +12,19 @@
 //! - Text, which is rendered using [`wgpu_glyph`]. No shaping at all.
 //! - Quads or rectangles, with rounded borders and a solid background color.
 //! - Clip areas, useful to implement scrollables or hide overflowing content.
+//! - Opacity groups, useful to fade whole widgets in or out.
+//! - Blur effects, applied to whole widgets or to what is behind them.
+//! - Arbitrary 2D transformations, like rotation, scaling and skewing.
 //! - Images and SVG, loaded from memory or the file system.
 //! - Meshes of triangles, useful to draw geometry freely.
+//! - Custom primitives, drawn with your own pipelines by a [`Shader`].
 //!
 //! [Iced]: https://github.com/iced-rs/iced
 //! [`iced_native`]: https://github.com/iced-rs/iced/tree/0.9/native
 //! [`wgpu`]: https://github.com/gfx-rs/wgpu-rs
 //! [WebGPU API]: https://gpuweb.github.io/gpuweb/
 //! [`wgpu_glyph`]: https://github.com/hecrj/wgpu_glyph
+//! [`Shader`]: crate::widget::Shader
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
@@ -39,10 +44,13 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod settings;
+pub mod widget;
 pub mod window;
 
 mod backend;
+mod blur;
 mod buffer;
+mod opacity;
 mod quad;
 mod text;
 mod triangle;
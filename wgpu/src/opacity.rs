@@ -0,0 +1,391 @@
+//! Composite groups of layers with some opacity and, optionally, a mask.
+use iced_native::{Rectangle, Size};
+
+use bytemuck::{Pod, Zeroable};
+use std::mem;
+
+#[cfg(feature = "tracing")]
+use tracing::info_span;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    format: wgpu::TextureFormat,
+    pipeline: wgpu::RenderPipeline,
+    masked_pipeline: wgpu::RenderPipeline,
+    constants: wgpu::BindGroup,
+    target_layout: wgpu::BindGroupLayout,
+    targets: Vec<Target>,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Pipeline {
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let constant_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::opacity constants layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(
+                        wgpu::SamplerBindingType::NonFiltering,
+                    ),
+                    count: None,
+                }],
+            });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::opacity constants bind group"),
+            layout: &constant_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Sampler(&sampler),
+            }],
+        });
+
+        let target_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::opacity target layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float {
+                                filterable: false,
+                            },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: wgpu::BufferSize::new(
+                                mem::size_of::<Uniforms>() as u64,
+                            ),
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::opacity pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&constant_layout, &target_layout],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu opacity shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("shader/opacity.wgsl"),
+                )),
+            });
+
+        let masked_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::opacity masked pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[
+                    &constant_layout,
+                    &target_layout,
+                    &target_layout,
+                ],
+            });
+
+        let create_pipeline = |layout, entry_point| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::opacity pipeline"),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point,
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(
+                            wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+                        ),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+            })
+        };
+
+        let pipeline = create_pipeline(&layout, "fs_main");
+        let masked_pipeline = create_pipeline(&masked_layout, "fs_masked");
+
+        Pipeline {
+            format,
+            pipeline,
+            masked_pipeline,
+            constants,
+            target_layout,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Returns a cleared [`Target`] of the given size to render a group into.
+    ///
+    /// The [`Target`] must be given back with [`Pipeline::composite`],
+    /// [`Pipeline::composite_masked`] or [`Pipeline::recycle`].
+    pub fn target(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        size: Size<u32>,
+    ) -> Target {
+        let target = match self.targets.pop() {
+            Some(target) if target.size == size => target,
+            _ => Target::new(device, self.format, &self.target_layout, size),
+        };
+
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("iced_wgpu::opacity clear render pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &target.view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    store: true,
+                },
+            })],
+            depth_stencil_attachment: None,
+        });
+
+        target
+    }
+
+    /// Blends the contents of the given [`Target`] into the `frame` with the
+    /// given opacity.
+    pub fn composite(
+        &mut self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        target: Target,
+        opacity: f32,
+        bounds: Rectangle<u32>,
+        frame: &wgpu::TextureView,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Wgpu::Opacity", "COMPOSITE").entered();
+
+        self.draw(
+            device,
+            staging_belt,
+            encoder,
+            &target,
+            None,
+            opacity,
+            bounds,
+            frame,
+        );
+
+        self.recycle(target);
+    }
+
+    /// Blends the contents of the given [`Target`] into the `frame` with the
+    /// given opacity, multiplied by the alpha of the `mask`.
+    pub fn composite_masked(
+        &mut self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        target: Target,
+        mask: Target,
+        opacity: f32,
+        bounds: Rectangle<u32>,
+        frame: &wgpu::TextureView,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Wgpu::Opacity", "COMPOSITE_MASKED").entered();
+
+        self.draw(
+            device,
+            staging_belt,
+            encoder,
+            &target,
+            Some(&mask),
+            opacity,
+            bounds,
+            frame,
+        );
+
+        self.recycle(mask);
+        self.recycle(target);
+    }
+
+    fn draw(
+        &self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &Target,
+        mask: Option<&Target>,
+        opacity: f32,
+        bounds: Rectangle<u32>,
+        frame: &wgpu::TextureView,
+    ) {
+        let uniforms = Uniforms {
+            opacity,
+            _padding: [0.0; 3],
+        };
+
+        staging_belt
+            .write_buffer(
+                encoder,
+                &target.uniforms,
+                0,
+                wgpu::BufferSize::new(mem::size_of::<Uniforms>() as u64)
+                    .unwrap(),
+                device,
+            )
+            .copy_from_slice(bytemuck::bytes_of(&uniforms));
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("iced_wgpu::opacity render pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: frame,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+            });
+
+        if let Some(mask) = mask {
+            render_pass.set_pipeline(&self.masked_pipeline);
+            render_pass.set_bind_group(2, &mask.bind_group, &[]);
+        } else {
+            render_pass.set_pipeline(&self.pipeline);
+        }
+
+        render_pass.set_bind_group(0, &self.constants, &[]);
+        render_pass.set_bind_group(1, &target.bind_group, &[]);
+        render_pass.set_scissor_rect(
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+        );
+        render_pass.draw(0..6, 0..1);
+    }
+
+    /// Gives back a [`Target`] that is no longer needed, so it can be reused.
+    pub fn recycle(&mut self, target: Target) {
+        self.targets.push(target);
+    }
+}
+
+/// An offscreen texture where a group of layers is rendered.
+#[derive(Debug)]
+pub struct Target {
+    view: wgpu::TextureView,
+    uniforms: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    size: Size<u32>,
+}
+
+impl Target {
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        layout: &wgpu::BindGroupLayout,
+        size: Size<u32>,
+    ) -> Target {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::opacity target"),
+            size: wgpu::Extent3d {
+                width: size.width,
+                height: size.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            view_formats: &[],
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::opacity uniforms buffer"),
+            size: mem::size_of::<Uniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::opacity target bind group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: uniforms.as_entire_binding(),
+                },
+            ],
+        });
+
+        Target {
+            view,
+            uniforms,
+            bind_group,
+            size,
+        }
+    }
+
+    /// Returns the [`wgpu::TextureView`] to render into.
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Uniforms {
+    opacity: f32,
+    // Uniform buffers must be at least 16 bytes long in some backends
+    _padding: [f32; 3],
+}
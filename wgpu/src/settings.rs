@@ -2,6 +2,7 @@
 use std::fmt;
 
 pub use crate::Antialiasing;
+pub use iced_graphics::image::Budget;
 
 /// The settings of a [`Backend`].
 ///
@@ -36,6 +37,12 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The memory budget of the image caches.
+    ///
+    /// Each of the raster and vector image caches keeps images that are no
+    /// longer drawn until it exceeds this budget.
+    pub image_cache_budget: Budget,
 }
 
 impl fmt::Debug for Settings {
@@ -48,6 +55,7 @@ impl fmt::Debug for Settings {
             .field("default_text_size", &self.default_text_size)
             .field("text_multithreading", &self.text_multithreading)
             .field("antialiasing", &self.antialiasing)
+            .field("image_cache_budget", &self.image_cache_budget)
             .finish()
     }
 }
@@ -85,6 +93,7 @@ impl Default for Settings {
             default_text_size: 20.0,
             text_multithreading: false,
             antialiasing: None,
+            image_cache_budget: Budget::default(),
         }
     }
 }
@@ -0,0 +1,114 @@
+var<private> positions: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(-1.0, -1.0),
+    vec2<f32>(1.0, -1.0),
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(1.0, -1.0)
+);
+
+var<private> uvs: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(0.0, 0.0),
+    vec2<f32>(0.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(0.0, 0.0),
+    vec2<f32>(1.0, 0.0),
+    vec2<f32>(1.0, 1.0)
+);
+
+struct Uniforms {
+    // The distance between two samples, in texture coordinates
+    step: vec2<f32>,
+    // The standard deviation of the blur, in samples
+    sigma: f32,
+    // The amount of samples on each side of a fragment
+    samples: f32,
+    // The region of the backdrop, in physical pixels
+    bounds: vec4<f32>,
+    border_radius: vec4<f32>,
+    opacity: f32,
+    _padding_0: f32,
+    _padding_1: f32,
+    _padding_2: f32,
+}
+
+@group(0) @binding(0) var u_sampler: sampler;
+@group(1) @binding(0) var u_texture: texture_2d<f32>;
+@group(1) @binding(1) var<uniform> uniforms: Uniforms;
+
+struct VertexInput {
+    @builtin(vertex_index) vertex_index: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.uv = uvs[input.vertex_index];
+    out.position = vec4<f32>(positions[input.vertex_index], 0.0, 1.0);
+
+    return out;
+}
+
+// A one-dimensional gaussian blur along the step of the uniforms
+fn blur(uv: vec2<f32>) -> vec4<f32> {
+    var color: vec4<f32> = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    var total: f32 = 0.0;
+
+    let samples: i32 = i32(uniforms.samples);
+
+    for (var i: i32 = -samples; i <= samples; i = i + 1) {
+        let offset: f32 = f32(i);
+        let weight: f32 = exp(-(offset * offset) / (2.0 * uniforms.sigma * uniforms.sigma));
+
+        color = color + textureSampleLevel(u_texture, u_sampler, uv + uniforms.step * offset, 0.0) * weight;
+        total = total + weight;
+    }
+
+    return color / total;
+}
+
+// Based on the fragment position and the center of the region, select one of the 4 radi.
+fn select_border_radius(radi: vec4<f32>, position: vec2<f32>, center: vec2<f32>) -> f32 {
+    var rx = radi.x;
+    var ry = radi.y;
+    rx = select(radi.x, radi.y, position.x > center.x);
+    ry = select(radi.w, radi.z, position.x > center.x);
+    rx = select(rx, ry, position.y > center.y);
+    return rx;
+}
+
+// The coverage of the fragment by the rounded region of the backdrop
+fn mask(position: vec2<f32>) -> f32 {
+    let half_size: vec2<f32> = uniforms.bounds.zw * 0.5;
+    let center: vec2<f32> = uniforms.bounds.xy + half_size;
+
+    let radius: f32 = min(
+        select_border_radius(uniforms.border_radius, position, center),
+        min(half_size.x, half_size.y)
+    );
+
+    let q: vec2<f32> = abs(position - center) - half_size + vec2<f32>(radius, radius);
+    let distance: f32 = length(max(q, vec2<f32>(0.0, 0.0))) + min(max(q.x, q.y), 0.0) - radius;
+
+    return 1.0 - smoothstep(-0.5, 0.5, distance);
+}
+
+@fragment
+fn fs_blur(input: VertexOutput) -> @location(0) vec4<f32> {
+    return blur(input.uv) * uniforms.opacity;
+}
+
+@fragment
+fn fs_erase(input: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(0.0, 0.0, 0.0, mask(input.position.xy));
+}
+
+@fragment
+fn fs_backdrop(input: VertexOutput) -> @location(0) vec4<f32> {
+    return blur(input.uv) * mask(input.position.xy);
+}
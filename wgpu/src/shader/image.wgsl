@@ -13,12 +13,16 @@ struct VertexInput {
     @location(3) atlas_pos: vec2<f32>,
     @location(4) atlas_scale: vec2<f32>,
     @location(5) layer: i32,
+    @location(6) center: vec2<f32>,
+    @location(7) rotation: f32,
+    @location(8) opacity: f32,
 }
 
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) uv: vec2<f32>,
     @location(1) layer: f32, // this should be an i32, but naga currently reads that as requiring interpolation.
+    @location(2) opacity: f32,
 }
 
 @vertex
@@ -27,20 +31,26 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 
     out.uv = vec2<f32>(input.v_pos * input.atlas_scale + input.atlas_pos);
     out.layer = f32(input.layer);
+    out.opacity = input.opacity;
 
-    var transform: mat4x4<f32> = mat4x4<f32>(
-        vec4<f32>(input.scale.x, 0.0, 0.0, 0.0),
-        vec4<f32>(0.0, input.scale.y, 0.0, 0.0),
-        vec4<f32>(0.0, 0.0, 1.0, 0.0),
-        vec4<f32>(input.pos, 0.0, 1.0)
+    // Rotate clockwise around the center of the image
+    var offset: vec2<f32> = input.pos + input.v_pos * input.scale - input.center;
+    var cos_rotation: f32 = cos(input.rotation);
+    var sin_rotation: f32 = sin(input.rotation);
+
+    var position: vec2<f32> = input.center + vec2<f32>(
+        offset.x * cos_rotation - offset.y * sin_rotation,
+        offset.x * sin_rotation + offset.y * cos_rotation
     );
 
-    out.position = globals.transform * transform * vec4<f32>(input.v_pos, 0.0, 1.0);
+    out.position = globals.transform * vec4<f32>(position, 0.0, 1.0);
 
     return out;
 }
 
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+    var color: vec4<f32> = textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+
+    return vec4<f32>(color.rgb, color.a * input.opacity);
 }
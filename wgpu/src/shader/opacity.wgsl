@@ -0,0 +1,62 @@
+var<private> positions: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(-1.0, -1.0),
+    vec2<f32>(1.0, -1.0),
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(1.0, -1.0)
+);
+
+var<private> uvs: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(0.0, 0.0),
+    vec2<f32>(0.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(0.0, 0.0),
+    vec2<f32>(1.0, 0.0),
+    vec2<f32>(1.0, 1.0)
+);
+
+struct Uniforms {
+    opacity: f32,
+    _padding_0: f32,
+    _padding_1: f32,
+    _padding_2: f32,
+}
+
+@group(0) @binding(0) var u_sampler: sampler;
+@group(1) @binding(0) var u_texture: texture_2d<f32>;
+@group(1) @binding(1) var<uniform> uniforms: Uniforms;
+@group(2) @binding(0) var u_mask: texture_2d<f32>;
+
+struct VertexInput {
+    @builtin(vertex_index) vertex_index: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.uv = uvs[input.vertex_index];
+    out.position = vec4<f32>(positions[input.vertex_index], 0.0, 1.0);
+
+    return out;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    // The group is rendered with premultiplied alpha
+    return textureSample(u_texture, u_sampler, input.uv) * uniforms.opacity;
+}
+
+@fragment
+fn fs_masked(input: VertexOutput) -> @location(0) vec4<f32> {
+    let mask = textureSample(u_mask, u_sampler, input.uv).a;
+
+    return textureSample(u_texture, u_sampler, input.uv)
+        * uniforms.opacity
+        * mask;
+}
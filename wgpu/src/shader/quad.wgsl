@@ -13,6 +13,9 @@ struct VertexInput {
     @location(4) border_color: vec4<f32>,
     @location(5) border_radius: vec4<f32>,
     @location(6) border_width: f32,
+    @location(7) shadow_color: vec4<f32>,
+    @location(8) shadow_offset: vec2<f32>,
+    @location(9) shadow_blur_radius: f32,
 }
 
 struct VertexOutput {
@@ -23,6 +26,10 @@ struct VertexOutput {
     @location(3) scale: vec2<f32>,
     @location(4) border_radius: vec4<f32>,
     @location(5) border_width: f32,
+    @location(6) shadow_color: vec4<f32>,
+    @location(7) shadow_offset: vec2<f32>,
+    @location(8) shadow_blur_radius: f32,
+    @location(9) frag_pos: vec2<f32>,
 }
 
 @vertex
@@ -40,11 +47,27 @@ fn vs_main(input: VertexInput) -> VertexOutput {
         min(input.border_radius.w, min_border_radius)
     );
 
+    var shadow_offset: vec2<f32> = input.shadow_offset * globals.scale;
+    var shadow_blur_radius: f32 = input.shadow_blur_radius * globals.scale;
+
+    // Grow the quad to make room for its shadow, if any
+    var min_pos: vec2<f32> = pos;
+    var max_pos: vec2<f32> = pos + scale;
+
+    if (input.shadow_color.a > 0.0) {
+        var spread: vec2<f32> = vec2<f32>(shadow_blur_radius, shadow_blur_radius);
+
+        min_pos = min(min_pos, pos + shadow_offset - spread);
+        max_pos = max(max_pos, pos + scale + shadow_offset + spread);
+    }
+
+    var size: vec2<f32> = max_pos - min_pos;
+
     var transform: mat4x4<f32> = mat4x4<f32>(
-        vec4<f32>(scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4<f32>(0.0, scale.y + 1.0, 0.0, 0.0),
+        vec4<f32>(size.x + 1.0, 0.0, 0.0, 0.0),
+        vec4<f32>(0.0, size.y + 1.0, 0.0, 0.0),
         vec4<f32>(0.0, 0.0, 1.0, 0.0),
-        vec4<f32>(pos - vec2<f32>(0.5, 0.5), 0.0, 1.0)
+        vec4<f32>(min_pos - vec2<f32>(0.5, 0.5), 0.0, 1.0)
     );
 
     out.color = input.color;
@@ -53,7 +76,16 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     out.scale = scale;
     out.border_radius = border_radius * globals.scale;
     out.border_width = input.border_width * globals.scale;
-    out.position = globals.transform * transform * vec4<f32>(input.v_pos, 0.0, 1.0);
+    out.shadow_color = input.shadow_color;
+    out.shadow_offset = shadow_offset;
+    out.shadow_blur_radius = shadow_blur_radius;
+
+    // Keep track of the untransformed position, so the shape of the quad
+    // is computed in its own space
+    var frag_pos: vec4<f32> = transform * vec4<f32>(input.v_pos, 0.0, 1.0);
+
+    out.frag_pos = frag_pos.xy;
+    out.position = globals.transform * frag_pos;
 
     return out;
 }
@@ -79,6 +111,13 @@ fn distance_alg(
     return sqrt(dist.x * dist.x + dist.y * dist.y);
 }
 
+// Signed distance from a point to a rounded box centered at the origin
+fn rounded_box_sdf(to_center: vec2<f32>, half_size: vec2<f32>, radius: f32) -> f32 {
+    var q: vec2<f32> = abs(to_center) - half_size + vec2<f32>(radius, radius);
+
+    return length(max(q, vec2<f32>(0.0, 0.0))) + min(max(q.x, q.y), 0.0) - radius;
+}
+
 // Based on the fragement position and the center of the quad, select one of the 4 radi.
 // Order matches CSS border radius attribute:
 // radi.x = top-left, radi.y = top-right, radi.z = bottom-right, radi.w = bottom-left
@@ -100,7 +139,7 @@ fn fs_main(
 
     var border_radius = select_border_radius(
         input.border_radius,
-        input.position.xy,
+        input.frag_pos,
         (input.pos + input.scale * 0.5).xy
     );
 
@@ -108,7 +147,7 @@ fn fs_main(
         var internal_border: f32 = max(border_radius - input.border_width, 0.0);
 
         var internal_distance: f32 = distance_alg(
-            input.position.xy,
+            input.frag_pos,
             input.pos + vec2<f32>(input.border_width, input.border_width),
             input.scale - vec2<f32>(input.border_width * 2.0, input.border_width * 2.0),
             internal_border
@@ -124,7 +163,7 @@ fn fs_main(
     }
 
     var dist: f32 = distance_alg(
-        vec2<f32>(input.position.x, input.position.y),
+        input.frag_pos,
         input.pos,
         input.scale,
         border_radius
@@ -136,5 +175,42 @@ fn fs_main(
         dist
     );
 
-    return vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+    var quad_alpha: f32 = mixed_color.w * radius_alpha;
+
+    if (input.shadow_color.a > 0.0) {
+        var shadow_center: vec2<f32> = input.pos + input.shadow_offset + input.scale * 0.5;
+
+        var shadow_radius: f32 = select_border_radius(
+            input.border_radius,
+            input.frag_pos,
+            shadow_center
+        );
+
+        var shadow_distance: f32 = rounded_box_sdf(
+            input.frag_pos - shadow_center,
+            input.scale * 0.5,
+            shadow_radius
+        );
+
+        var shadow_edge: f32 = max(input.shadow_blur_radius * 0.5, 0.5);
+
+        // The shadow is only visible outside of the quad
+        var shadow_alpha: f32 = input.shadow_color.a
+            * (1.0 - smoothstep(-shadow_edge, shadow_edge, shadow_distance))
+            * (1.0 - radius_alpha);
+
+        var alpha: f32 = quad_alpha + shadow_alpha;
+
+        if (alpha <= 0.0) {
+            return vec4<f32>(0.0, 0.0, 0.0, 0.0);
+        }
+
+        var color: vec3<f32> = (
+            mixed_color.xyz * quad_alpha + input.shadow_color.xyz * shadow_alpha
+        ) / alpha;
+
+        return vec4<f32>(color, alpha);
+    }
+
+    return vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, quad_alpha);
 }
@@ -0,0 +1,4 @@
+//! Use the widgets that are specific to `iced_wgpu`.
+pub mod shader;
+
+pub use shader::Shader;
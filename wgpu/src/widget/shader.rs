@@ -0,0 +1,212 @@
+//! Draw custom GPU content, like 3D scenes, with your own [`wgpu`] pipelines.
+//!
+//! A [`Shader`] widget is driven by a [`Program`], which produces a
+//! [`Primitive`] every frame. The [`Primitive`] prepares its GPU resources
+//! and records its commands into the encoder of the frame, inside the bounds
+//! of the widget.
+pub mod event;
+
+mod primitive;
+mod program;
+mod storage;
+
+pub use event::Event;
+pub use primitive::Primitive;
+pub use program::Program;
+pub use storage::Storage;
+
+use crate::Renderer;
+
+use iced_native::layout::{self, Layout};
+use iced_native::mouse;
+use iced_native::renderer;
+use iced_native::widget::tree::{self, Tree};
+use iced_native::{
+    Clipboard, Element, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// A widget that draws custom GPU content with a [`Program`].
+#[derive(Debug)]
+pub struct Shader<Message, P>
+where
+    P: Program<Message>,
+{
+    width: Length,
+    height: Length,
+    program: P,
+    message_: PhantomData<Message>,
+}
+
+impl<Message, P> Shader<Message, P>
+where
+    P: Program<Message>,
+{
+    const DEFAULT_SIZE: f32 = 100.0;
+
+    /// Creates a new [`Shader`].
+    pub fn new(program: P) -> Self {
+        Shader {
+            width: Length::Fixed(Self::DEFAULT_SIZE),
+            height: Length::Fixed(Self::DEFAULT_SIZE),
+            program,
+            message_: PhantomData,
+        }
+    }
+
+    /// Sets the width of the [`Shader`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Shader`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl<Message, P, T> Widget<Message, Renderer<T>> for Shader<Message, P>
+where
+    P: Program<Message>,
+{
+    fn tag(&self) -> tree::Tag {
+        struct Tag<T>(T);
+        tree::Tag::of::<Tag<P::State>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(P::State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer<T>,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced_native::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer<T>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        let shader_event = match event {
+            iced_native::Event::Mouse(mouse_event) => {
+                Some(Event::Mouse(mouse_event))
+            }
+            iced_native::Event::Touch(touch_event) => {
+                Some(Event::Touch(touch_event))
+            }
+            iced_native::Event::Keyboard(keyboard_event) => {
+                Some(Event::Keyboard(keyboard_event))
+            }
+            _ => None,
+        };
+
+        if let Some(shader_event) = shader_event {
+            let state = tree.state.downcast_mut::<P::State>();
+
+            let (event_status, message) = self.program.update(
+                state,
+                shader_event,
+                bounds,
+                cursor_position,
+            );
+
+            if let Some(message) = message {
+                shell.publish(message);
+            }
+
+            return event_status;
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<T>,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<P::State>();
+
+        self.program
+            .mouse_interaction(state, bounds, cursor_position)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<T>,
+        _theme: &T,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if bounds.width < 1.0 || bounds.height < 1.0 {
+            return;
+        }
+
+        let state = tree.state.downcast_ref::<P::State>();
+        let primitive: Box<dyn Primitive> =
+            Box::new(self.program.draw(state, bounds, cursor_position));
+
+        renderer.draw_primitive(iced_graphics::Primitive::Custom {
+            bounds,
+            primitive: Arc::new(primitive),
+        });
+    }
+}
+
+impl<'a, Message, P, T> From<Shader<Message, P>>
+    for Element<'a, Message, Renderer<T>>
+where
+    Message: 'a,
+    P: Program<Message> + 'a,
+    T: 'a,
+{
+    fn from(shader: Shader<Message, P>) -> Element<'a, Message, Renderer<T>> {
+        Element::new(shader)
+    }
+}
+
+/// Returns the [`Primitive`] of the given custom primitive, if it was
+/// produced by a [`Shader`].
+pub(crate) fn primitive(
+    custom: &iced_graphics::layer::Custom,
+) -> Option<&dyn Primitive> {
+    custom
+        .primitive
+        .downcast_ref::<Box<dyn Primitive>>()
+        .map(Box::as_ref)
+}
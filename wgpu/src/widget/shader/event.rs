@@ -0,0 +1,21 @@
+//! Handle events of a shader.
+use iced_native::keyboard;
+use iced_native::mouse;
+use iced_native::touch;
+
+pub use iced_native::event::Status;
+
+/// A [`Shader`] event.
+///
+/// [`Shader`]: crate::widget::Shader
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// A mouse event.
+    Mouse(mouse::Event),
+
+    /// A touch event.
+    Touch(touch::Event),
+
+    /// A keyboard event.
+    Keyboard(keyboard::Event),
+}
@@ -0,0 +1,43 @@
+use crate::widget::shader::Storage;
+
+use iced_native::{Rectangle, Size};
+
+use std::fmt::Debug;
+
+/// A set of GPU commands produced by a [`Program`] that can be recorded
+/// into the frame.
+///
+/// [`Program`]: crate::widget::shader::Program
+pub trait Primitive: Debug + Send + Sync + 'static {
+    /// Prepares the GPU resources needed to render the [`Primitive`].
+    ///
+    /// Resources that outlive the frame, like pipelines, should be created
+    /// once and kept in the [`Storage`].
+    ///
+    /// The `bounds` of the [`Primitive`] are in logical coordinates, while the
+    /// `target_size` is in physical pixels.
+    fn prepare(
+        &self,
+        format: wgpu::TextureFormat,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        bounds: Rectangle,
+        target_size: Size<u32>,
+        scale_factor: f32,
+        storage: &mut Storage,
+    );
+
+    /// Records the render commands of the [`Primitive`] into the given
+    /// encoder.
+    ///
+    /// Any render pass should load the contents of the `target` and use the
+    /// `clip_bounds` as its scissor rectangle, in physical pixels.
+    fn render(
+        &self,
+        storage: &Storage,
+        target: &wgpu::TextureView,
+        target_size: Size<u32>,
+        clip_bounds: Rectangle<u32>,
+        encoder: &mut wgpu::CommandEncoder,
+    );
+}
@@ -0,0 +1,99 @@
+use crate::widget::shader::event::{self, Event};
+use crate::widget::shader::Primitive;
+
+use iced_native::mouse;
+use iced_native::{Point, Rectangle};
+
+/// The state and logic of a [`Shader`].
+///
+/// A [`Program`] can mutate internal state and produce messages for an
+/// application.
+///
+/// [`Shader`]: crate::widget::Shader
+pub trait Program<Message> {
+    /// The internal state mutated by the [`Program`].
+    type State: Default + 'static;
+
+    /// The [`Primitive`] drawn by the [`Program`].
+    type Primitive: Primitive;
+
+    /// Updates the [`State`](Self::State) of the [`Program`].
+    ///
+    /// When a [`Program`] is used in a [`Shader`], the runtime will call this
+    /// method for each [`Event`].
+    ///
+    /// This method can optionally return a `Message` to notify an application
+    /// of any meaningful interactions.
+    ///
+    /// By default, this method does and returns nothing.
+    ///
+    /// [`Shader`]: crate::widget::Shader
+    fn update(
+        &self,
+        _state: &mut Self::State,
+        _event: Event,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+    ) -> (event::Status, Option<Message>) {
+        (event::Status::Ignored, None)
+    }
+
+    /// Draws the state of the [`Program`], producing a [`Primitive`].
+    fn draw(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Self::Primitive;
+
+    /// Returns the current mouse interaction of the [`Program`].
+    ///
+    /// The interaction returned will be in effect even if the cursor position
+    /// is out of bounds of the program's [`Shader`].
+    ///
+    /// [`Shader`]: crate::widget::Shader
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+}
+
+impl<Message, T> Program<Message> for &T
+where
+    T: Program<Message>,
+{
+    type State = T::State;
+    type Primitive = T::Primitive;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: Event,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> (event::Status, Option<Message>) {
+        T::update(self, state, event, bounds, cursor_position)
+    }
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Self::Primitive {
+        T::draw(self, state, bounds, cursor_position)
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> mouse::Interaction {
+        T::mouse_interaction(self, state, bounds, cursor_position)
+    }
+}
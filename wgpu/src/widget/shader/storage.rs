@@ -0,0 +1,73 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+
+/// A type map that keeps the resources of custom primitives, like pipelines
+/// and buffers, across frames.
+#[derive(Default)]
+pub struct Storage {
+    resources: HashMap<TypeId, Box<dyn Any + Send>>,
+}
+
+impl Storage {
+    /// Returns true if [`Storage`] contains a value of type `T`.
+    pub fn has<T: 'static>(&self) -> bool {
+        self.resources.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Inserts a value of type `T` into the [`Storage`], replacing the
+    /// previous one, if any.
+    pub fn store<T: 'static + Send>(&mut self, value: T) {
+        let _ = self.resources.insert(TypeId::of::<T>(), Box::new(value));
+    }
+
+    /// Returns a reference to the value of type `T` in the [`Storage`], if
+    /// any.
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.resources
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>())
+    }
+
+    /// Returns a mutable reference to the value of type `T` in the
+    /// [`Storage`], if any.
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.resources
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_mut::<T>())
+    }
+}
+
+impl fmt::Debug for Storage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Storage")
+            .field("resources", &self.resources.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stores_a_value_per_type() {
+        let mut storage = Storage::default();
+
+        assert!(!storage.has::<u32>());
+
+        storage.store(1u32);
+        storage.store(2u64);
+        storage.store(3u32);
+
+        assert_eq!(storage.get::<u32>(), Some(&3));
+        assert_eq!(storage.get::<u64>(), Some(&2));
+        assert_eq!(storage.get::<u8>(), None);
+
+        if let Some(value) = storage.get_mut::<u64>() {
+            *value += 1;
+        }
+
+        assert_eq!(storage.get::<u64>(), Some(&3));
+    }
+}
@@ -193,6 +193,13 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
         }
     }
 
+    fn image_statistics(
+        &self,
+        renderer: &Self::Renderer,
+    ) -> iced_graphics::image::Statistics {
+        iced_graphics::Backend::image_statistics(renderer.backend())
+    }
+
     fn present<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -244,6 +251,7 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
                 renderer.with_primitives(|backend, primitives| {
                     backend.present(
                         &self.device,
+                        &self.queue,
                         &mut self.staging_belt,
                         &mut encoder,
                         view,
@@ -26,12 +26,16 @@ use iced_native::user_interface::{self, UserInterface};
 pub use iced_native::application::{Appearance, StyleSheet};
 
 use std::mem::ManuallyDrop;
+use std::time::Duration;
 
 #[cfg(feature = "trace")]
 pub use profiler::Profiler;
 #[cfg(feature = "trace")]
 use tracing::{info_span, instrument::Instrument};
 
+/// How often a window drawing images that are still being decoded is redrawn.
+const IMAGE_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
 /// An interactive, native cross-platform application.
 ///
 /// This trait is the main entrypoint of Iced. Once implemented, you can run
@@ -42,7 +46,9 @@ use tracing::{info_span, instrument::Instrument};
 /// [`Command`] in some of its methods.
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`. Pressing `Shift+F12` toggles a widget
+/// inspector that highlights the widget under the cursor, and `Ctrl+F12`
+/// outlines the bounds of every widget.
 pub trait Application: Program
 where
     <Self::Renderer as crate::Renderer>::Theme: StyleSheet,
@@ -337,6 +343,7 @@ async fn run_instance<A, E, C>(
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut redraw_pending = false;
+    let mut control_flow = ControlFlow::Wait;
 
     debug.startup_finished();
 
@@ -440,6 +447,14 @@ async fn run_instance<A, E, C>(
                 );
                 debug.draw_finished();
 
+                if debug.is_inspecting() {
+                    debug.log_inspection(user_interface.inspect(
+                        &mut renderer,
+                        state.cursor_position(),
+                        debug.shows_outlines(),
+                    ));
+                }
+
                 if new_mouse_interaction != mouse_interaction {
                     window.set_cursor_icon(conversion::mouse_interaction(
                         new_mouse_interaction,
@@ -452,7 +467,7 @@ async fn run_instance<A, E, C>(
                 runtime
                     .broadcast((redraw_event, crate::event::Status::Ignored));
 
-                let _ = control_sender.start_send(match interface_state {
+                control_flow = match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
                     } => match redraw_request {
@@ -464,7 +479,9 @@ async fn run_instance<A, E, C>(
                         }
                     },
                     _ => ControlFlow::Wait,
-                });
+                };
+
+                let _ = control_sender.start_send(control_flow);
 
                 redraw_pending = false;
             }
@@ -524,6 +541,14 @@ async fn run_instance<A, E, C>(
                     }
                     debug.draw_finished();
 
+                    if debug.is_inspecting() {
+                        debug.log_inspection(user_interface.inspect(
+                            &mut renderer,
+                            state.cursor_position(),
+                            debug.shows_outlines(),
+                        ));
+                    }
+
                     compositor.configure_surface(
                         &mut surface,
                         physical_size.width,
@@ -533,6 +558,10 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
+                debug.log_image_statistics(
+                    compositor.image_statistics(&renderer),
+                );
+
                 match compositor.present(
                     &mut renderer,
                     &mut surface,
@@ -543,6 +572,11 @@ async fn run_instance<A, E, C>(
                     Ok(()) => {
                         debug.render_finished();
 
+                        let _ = control_sender.start_send(wait_for_images(
+                            control_flow,
+                            compositor.image_statistics(&renderer),
+                        ));
+
                         // TODO: Handle animations!
                         // Maybe we can use `ControlFlow::WaitUntil` for this.
                     }
@@ -572,6 +606,12 @@ async fn run_instance<A, E, C>(
 
                 state.update(&window, &window_event, &mut debug);
 
+                if let winit::event::WindowEvent::ThemeChanged(_) =
+                    &window_event
+                {
+                    state.synchronize(&application, &window);
+                }
+
                 if let Some(event) = conversion::window_event(
                     &window_event,
                     state.scale_factor(),
@@ -612,6 +652,34 @@ pub fn requests_exit(
     }
 }
 
+/// Returns the [`ControlFlow`] to use after presenting a frame, given the
+/// current one and the image [`Statistics`] of the frame.
+///
+/// Images are decoded in the background, so the window is redrawn
+/// periodically until the ones it draws are ready.
+///
+/// [`ControlFlow`]: winit::event_loop::ControlFlow
+/// [`Statistics`]: iced_native::image::Statistics
+pub fn wait_for_images(
+    control_flow: winit::event_loop::ControlFlow,
+    statistics: iced_native::image::Statistics,
+) -> winit::event_loop::ControlFlow {
+    use winit::event_loop::ControlFlow;
+
+    if statistics.loading == 0 {
+        return control_flow;
+    }
+
+    let retry = Instant::now() + IMAGE_POLL_INTERVAL;
+
+    match control_flow {
+        ControlFlow::Poll => ControlFlow::Poll,
+        ControlFlow::WaitUntil(at) if at < retry => ControlFlow::WaitUntil(at),
+        ControlFlow::ExitWithCode(code) => ControlFlow::ExitWithCode(code),
+        _ => ControlFlow::WaitUntil(retry),
+    }
+}
+
 /// Builds a [`UserInterface`] for the provided [`Application`], logging
 /// [`struct@Debug`] information accordingly.
 pub fn build_user_interface<'a, A: Application>(
@@ -718,6 +786,7 @@ pub fn run_command<A, E>(
     <A::Renderer as crate::Renderer>::Theme: StyleSheet,
 {
     use iced_native::command;
+    use iced_native::metrics;
     use iced_native::system;
     use iced_native::window;
 
@@ -826,6 +895,34 @@ pub fn run_command<A, E>(
                         });
                     }
                 }
+                system::Action::QueryTheme(tag) => {
+                    if let Some(mode) = crate::system::window_theme_mode(window)
+                    {
+                        proxy
+                            .send_event(tag(mode))
+                            .expect("Send message to event loop");
+
+                        continue;
+                    }
+
+                    let query = {
+                        let proxy = proxy.clone();
+
+                        move || {
+                            let mode = crate::system::theme_mode();
+
+                            proxy
+                                .send_event(tag(mode))
+                                .expect("Send message to event loop")
+                        }
+                    };
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    let _ = std::thread::spawn(query);
+
+                    #[cfg(target_arch = "wasm32")]
+                    query();
+                }
             },
             command::Action::Widget(action) => {
                 let mut current_cache = std::mem::take(cache);
@@ -858,6 +955,13 @@ pub fn run_command<A, E>(
                 current_cache = user_interface.into_cache();
                 *cache = current_cache;
             }
+            command::Action::Metrics(action) => match action {
+                metrics::Action::Fetch(tag) => {
+                    proxy
+                        .send_event(tag(debug.metrics()))
+                        .expect("Send message to event loop");
+                }
+            },
         }
     }
 }
@@ -901,3 +1005,52 @@ mod platform {
         event_loop.run(event_handler)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::image;
+    use winit::event_loop::ControlFlow;
+
+    fn loading(amount: usize) -> image::Statistics {
+        image::Statistics {
+            loading: amount,
+            ..image::Statistics::default()
+        }
+    }
+
+    #[test]
+    fn windows_wait_for_loading_images() {
+        assert_eq!(
+            wait_for_images(ControlFlow::Wait, loading(0)),
+            ControlFlow::Wait
+        );
+
+        assert!(matches!(
+            wait_for_images(ControlFlow::Wait, loading(1)),
+            ControlFlow::WaitUntil(at) if at > Instant::now()
+        ));
+    }
+
+    #[test]
+    fn earlier_redraws_are_kept_while_images_load() {
+        let soon = Instant::now();
+
+        assert_eq!(
+            wait_for_images(ControlFlow::Poll, loading(1)),
+            ControlFlow::Poll
+        );
+
+        assert_eq!(
+            wait_for_images(ControlFlow::WaitUntil(soon), loading(1)),
+            ControlFlow::WaitUntil(soon)
+        );
+
+        let later = Instant::now() + IMAGE_POLL_INTERVAL * 10;
+
+        assert!(matches!(
+            wait_for_images(ControlFlow::WaitUntil(later), loading(1)),
+            ControlFlow::WaitUntil(at) if at < later
+        ));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::application::{self, StyleSheet as _};
 use crate::conversion;
+use crate::theme;
 use crate::{Application, Color, Debug, Point, Size, Viewport};
 
 use std::marker::PhantomData;
@@ -19,6 +20,7 @@ where
     cursor_position: winit::dpi::PhysicalPosition<f64>,
     modifiers: winit::event::ModifiersState,
     theme: <A::Renderer as crate::Renderer>::Theme,
+    system_mode: Option<theme::Mode>,
     appearance: application::Appearance,
     application: PhantomData<A>,
 }
@@ -31,7 +33,9 @@ where
     pub fn new(application: &A, window: &Window) -> Self {
         let title = application.title();
         let scale_factor = application.scale_factor();
-        let theme = application.theme();
+        let mut system_mode = None;
+        let theme =
+            follow_system(application.theme(), &mut system_mode, window);
         let appearance = theme.appearance(&application.style());
 
         let viewport = {
@@ -52,6 +56,7 @@ where
             cursor_position: winit::dpi::PhysicalPosition::new(-1.0, -1.0),
             modifiers: winit::event::ModifiersState::default(),
             theme,
+            system_mode,
             appearance,
             application: PhantomData,
         }
@@ -159,6 +164,9 @@ where
             WindowEvent::ModifiersChanged(new_modifiers) => {
                 self.modifiers = *new_modifiers;
             }
+            WindowEvent::ThemeChanged(theme) => {
+                self.system_mode = Some(conversion::theme_mode(*theme));
+            }
             #[cfg(feature = "debug")]
             WindowEvent::KeyboardInput {
                 input:
@@ -168,7 +176,15 @@ where
                         ..
                     },
                 ..
-            } => _debug.toggle(),
+            } => {
+                if self.modifiers.shift() {
+                    _debug.toggle_inspector();
+                } else if self.modifiers.ctrl() {
+                    _debug.toggle_outlines();
+                } else {
+                    _debug.toggle();
+                }
+            }
             _ => {}
         }
     }
@@ -209,7 +225,31 @@ where
         }
 
         // Update theme and appearance
-        self.theme = application.theme();
+        self.theme =
+            follow_system(application.theme(), &mut self.system_mode, window);
         self.appearance = self.theme.appearance(&application.style());
     }
 }
+
+/// Replaces a theme that follows the preference of the system with the
+/// variant for the current preference, detecting it the first time.
+///
+/// The theme reported by the window is preferred, and the system is only
+/// probed when the platform does not report it. Later changes are received
+/// as `ThemeChanged` window events.
+fn follow_system<Theme: application::StyleSheet>(
+    theme: Theme,
+    system_mode: &mut Option<theme::Mode>,
+    window: &Window,
+) -> Theme {
+    if !theme.follows_system() {
+        return theme;
+    }
+
+    let mode = *system_mode.get_or_insert_with(|| {
+        crate::system::window_theme_mode(window)
+            .unwrap_or_else(crate::system::theme_mode)
+    });
+
+    theme.with_system_mode(mode).unwrap_or(theme)
+}
@@ -4,6 +4,7 @@
 //! [`iced_native`]: https://github.com/iced-rs/iced/tree/0.9/native
 use crate::keyboard;
 use crate::mouse;
+use crate::theme;
 use crate::touch;
 use crate::window;
 use crate::{Event, Point, Position};
@@ -135,6 +136,9 @@ pub fn window_event(
 
             Some(Event::Window(window::Event::Moved { x, y }))
         }
+        WindowEvent::ThemeChanged(theme) => Some(Event::Window(
+            window::Event::ThemeChanged(theme_mode(*theme)),
+        )),
         _ => None,
     }
 }
@@ -215,6 +219,16 @@ pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
     }
 }
 
+/// Converts a [`winit`] theme to a [`theme::Mode`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn theme_mode(theme: winit::window::Theme) -> theme::Mode {
+    match theme {
+        winit::window::Theme::Light => theme::Mode::Light,
+        winit::window::Theme::Dark => theme::Mode::Dark,
+    }
+}
+
 /// Converts a `MouseCursor` from [`iced_native`] to a [`winit`] cursor icon.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -40,10 +40,8 @@ pub mod application;
 pub mod clipboard;
 pub mod conversion;
 pub mod settings;
-pub mod window;
-
-#[cfg(feature = "system")]
 pub mod system;
+pub mod window;
 
 mod error;
 mod position;
@@ -1,10 +1,22 @@
 //! Access the native system.
+mod appearance;
+
 use crate::command::{self, Command};
+use crate::theme;
 pub use iced_native::system::*;
 
+#[cfg(feature = "system")]
 use iced_graphics::compositor;
 
+/// Query for the light or dark preference of the system.
+pub fn theme<Message>(
+    f: impl Fn(theme::Mode) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::QueryTheme(Box::new(f))))
+}
+
 /// Query for available system information.
+#[cfg(feature = "system")]
 pub fn fetch_information<Message>(
     f: impl Fn(Information) -> Message + Send + 'static,
 ) -> Command<Message> {
@@ -13,6 +25,7 @@ pub fn fetch_information<Message>(
     )))
 }
 
+#[cfg(feature = "system")]
 pub(crate) fn information(
     graphics_info: compositor::Information,
 ) -> Information {
@@ -40,3 +53,30 @@ pub(crate) fn information(
         graphics_backend: graphics_info.backend,
     }
 }
+
+/// Detects the light or dark preference of the system.
+///
+/// It defaults to [`theme::Mode::Light`] when the preference is unknown.
+pub fn theme_mode() -> theme::Mode {
+    appearance::detect().unwrap_or_default()
+}
+
+/// Returns the light or dark preference of the given window, if the platform
+/// reports it.
+pub fn window_theme_mode(
+    window: &winit::window::Window,
+) -> Option<theme::Mode> {
+    #[cfg(target_os = "windows")]
+    {
+        use winit::platform::windows::WindowExtWindows;
+
+        Some(crate::conversion::theme_mode(window.theme()))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = window;
+
+        None
+    }
+}
@@ -0,0 +1,208 @@
+//! Detect the light or dark preference of the system.
+use crate::theme::Mode;
+
+/// Detects the [`Mode`] preferred by the system, if any.
+#[cfg(target_os = "linux")]
+pub fn detect() -> Option<Mode> {
+    linux::detect(&run, &|name| std::env::var(name).ok())
+}
+
+/// Detects the [`Mode`] preferred by the system, if any.
+#[cfg(target_os = "macos")]
+pub fn detect() -> Option<Mode> {
+    // The key is missing altogether when the light appearance is in use.
+    let style = run("defaults", &["read", "-g", "AppleInterfaceStyle"]);
+
+    Some(match style {
+        Some(style) if style.trim().eq_ignore_ascii_case("dark") => Mode::Dark,
+        _ => Mode::Light,
+    })
+}
+
+/// Detects the [`Mode`] preferred by the system, if any.
+#[cfg(target_os = "windows")]
+pub fn detect() -> Option<Mode> {
+    let output = run(
+        "reg",
+        &[
+            "query",
+            r"HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize",
+            "/v",
+            "AppsUseLightTheme",
+        ],
+    )?;
+
+    let value = output.split_whitespace().last()?;
+
+    Some(if value == "0x0" {
+        Mode::Dark
+    } else {
+        Mode::Light
+    })
+}
+
+/// Detects the [`Mode`] preferred by the system, if any.
+#[cfg(target_arch = "wasm32")]
+pub fn detect() -> Option<Mode> {
+    let query = web_sys::window()?
+        .match_media("(prefers-color-scheme: dark)")
+        .ok()??;
+
+    Some(if query.matches() {
+        Mode::Dark
+    } else {
+        Mode::Light
+    })
+}
+
+/// Detects the [`Mode`] preferred by the system, if any.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "windows",
+    target_arch = "wasm32"
+)))]
+pub fn detect() -> Option<Mode> {
+    None
+}
+
+/// Runs the given program and returns its standard output, if it succeeds.
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    let mut command = std::process::Command::new(program);
+    let _ = command.args(args).stdin(std::process::Stdio::null());
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+
+        // Avoid flashing a console window.
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+        let _ = command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = command.output().ok()?;
+
+    if output.status.success() {
+        String::from_utf8(output.stdout).ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(any(target_os = "linux", test))]
+mod linux {
+    use crate::theme::Mode;
+
+    /// Detects the [`Mode`] preferred by the system using the given functions
+    /// to run programs and read environment variables.
+    ///
+    /// The freedesktop appearance setting is read first, through the XDG
+    /// desktop portal. GNOME settings and the `GTK_THEME` variable are used as
+    /// fallbacks.
+    pub fn detect(
+        run: &dyn Fn(&str, &[&str]) -> Option<String>,
+        var: &dyn Fn(&str) -> Option<String>,
+    ) -> Option<Mode> {
+        let portal = || {
+            run(
+                "dbus-send",
+                &[
+                    "--session",
+                    "--print-reply=literal",
+                    "--reply-timeout=100",
+                    "--dest=org.freedesktop.portal.Desktop",
+                    "/org/freedesktop/portal/desktop",
+                    "org.freedesktop.portal.Settings.Read",
+                    "string:org.freedesktop.appearance",
+                    "string:color-scheme",
+                ],
+            )
+            .as_deref()
+            .and_then(color_scheme)
+        };
+
+        let gsettings = || {
+            run(
+                "gsettings",
+                &["get", "org.gnome.desktop.interface", "color-scheme"],
+            )
+            .as_deref()
+            .and_then(gnome_color_scheme)
+        };
+
+        let environment = || var("GTK_THEME").as_deref().and_then(gtk_theme);
+
+        portal().or_else(gsettings).or_else(environment)
+    }
+
+    /// Parses the reply of the portal, which ends with a `uint32` where `1`
+    /// means dark, `2` means light and `0` means no preference.
+    fn color_scheme(reply: &str) -> Option<Mode> {
+        let (_, value) = reply.rsplit_once("uint32")?;
+
+        match value.trim().parse::<u32>().ok()? {
+            1 => Some(Mode::Dark),
+            2 => Some(Mode::Light),
+            _ => None,
+        }
+    }
+
+    fn gnome_color_scheme(value: &str) -> Option<Mode> {
+        match value.trim().trim_matches('\'') {
+            "prefer-dark" => Some(Mode::Dark),
+            "prefer-light" => Some(Mode::Light),
+            _ => None,
+        }
+    }
+
+    fn gtk_theme(name: &str) -> Option<Mode> {
+        let name = name.to_ascii_lowercase();
+
+        if name.ends_with(":dark") || name.ends_with("-dark") {
+            Some(Mode::Dark)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn stub<'a>(
+            portal: Option<&'a str>,
+            gsettings: Option<&'a str>,
+        ) -> impl Fn(&str, &[&str]) -> Option<String> + 'a {
+            move |program, _| match program {
+                "dbus-send" => portal.map(String::from),
+                "gsettings" => gsettings.map(String::from),
+                _ => None,
+            }
+        }
+
+        #[test]
+        fn portal_is_preferred() {
+            let run = stub(
+                Some("   variant       variant          uint32 1\n"),
+                Some("'prefer-light'\n"),
+            );
+
+            assert_eq!(detect(&run, &|_| None), Some(Mode::Dark));
+        }
+
+        #[test]
+        fn falls_back_without_portal_preference() {
+            let run = stub(Some("variant variant uint32 0"), None);
+            let var = |_: &str| Some(String::from("Adwaita:dark"));
+
+            assert_eq!(detect(&run, &var), Some(Mode::Dark));
+
+            let run = stub(None, Some("'prefer-light'"));
+
+            assert_eq!(detect(&run, &var), Some(Mode::Light));
+            assert_eq!(detect(&stub(None, None), &|_| None), None);
+        }
+    }
+}